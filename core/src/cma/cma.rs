@@ -0,0 +1,361 @@
+use crate::cma::eigen::symmetric_eigen;
+use crate::common::{make_vec, standard_normal};
+use crate::search::{evaluate, local_search, run};
+use crate::{
+    compare_costs, ChildOutcome, CmaSettings, Failure, Genetic, Observer, Progress,
+    ProgressSnapshot, SearchSettings, Strategy, Success,
+};
+use std::time::Duration;
+
+/// Search for a solution to a real-valued problem using the covariance matrix adaptation
+/// evolution strategy (Hansen and Ostermeier).
+///
+/// The search distribution is centred on a genome created by
+/// [`initialize()`](crate::Genetic#initialize()).
+/// Sampled genomes are passed through [`repair()`](crate::Genetic#repair()) and costed by
+/// [`evaluate()`](crate::Genetic#evaluate()).
+/// The crossover and mutation operations of `genetic` are not used.
+/// Neither are the mutation probability and restart settings of `settings`.
+/// Each sampled genome is reported to observers as a mutated child of the best genome of the
+/// previous epoch.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the problem.
+/// * `cma` - Configuration of CMA-ES.
+/// * `progress` - Define the progress reporting callback.
+/// * `settings` - Configuration of the search. The population size is the number of genomes
+///   sampled in each epoch.
+///
+pub fn cma_es(
+    genetic: Box<dyn Genetic<Vec<f64>> + Send + Sync>,
+    cma: &CmaSettings,
+    mut progress: Option<Progress<Vec<f64>>>,
+    settings: &SearchSettings,
+) -> Result<Success<Vec<f64>>, Failure> {
+    run(
+        genetic,
+        &mut CmaEs::new(*cma),
+        &mut progress,
+        None,
+        settings,
+    )
+}
+
+/// The CMA-ES strategy used by [`cma_es`].
+pub struct CmaEs {
+    settings: CmaSettings,
+    distribution: Option<Distribution>,
+    population: Vec<Vec<f64>>,
+    steps: Vec<Vec<f64>>,
+    costs: Vec<f64>,
+    violations: Vec<f64>,
+    local_search_time: Duration,
+}
+
+impl CmaEs {
+    /// Create a CMA-ES strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Configuration of CMA-ES.
+    pub fn new(settings: CmaSettings) -> Self {
+        Self {
+            settings,
+            distribution: None,
+            population: Vec::new(),
+            steps: Vec::new(),
+            costs: Vec::new(),
+            violations: Vec::new(),
+            local_search_time: Duration::ZERO,
+        }
+    }
+
+    /// Sample a new population from the distribution.
+    /// The number of genomes sampled is fixed when the strategy is initialized.
+    fn sample(&mut self, genetic: &(dyn Genetic<Vec<f64>> + Send + Sync)) {
+        let distribution = self.distribution.as_ref().unwrap();
+        self.population.clear();
+        for _ in 0..distribution.lambda {
+            self.population.push(genetic.repair(distribution.sample()));
+        }
+    }
+
+    /// Evaluate the sampled population.
+    fn evaluate(
+        &mut self,
+        genetic: &(dyn Genetic<Vec<f64>> + Send + Sync),
+        settings: &SearchSettings,
+        epoch: usize,
+    ) {
+        self.local_search_time += evaluate(
+            genetic,
+            settings.concurrency(),
+            local_search(genetic, settings, epoch),
+            &mut self.population,
+            &mut self.costs,
+            &mut self.violations,
+        );
+    }
+}
+
+impl Strategy<Vec<f64>> for CmaEs {
+    fn initialize(
+        &mut self,
+        genetic: &(dyn Genetic<Vec<f64>> + Send + Sync),
+        settings: &SearchSettings,
+    ) -> Result<(), Failure> {
+        let lambda = settings.population_size();
+        if lambda < 2 {
+            return Err(Failure::cma_setting());
+        }
+
+        self.distribution = Some(Distribution::new(
+            genetic.initialize(),
+            &self.settings,
+            lambda,
+        ));
+        self.costs = make_vec(lambda, || 0.0);
+        self.violations = make_vec(lambda, || 0.0);
+        self.sample(genetic);
+        self.evaluate(genetic, settings, 1);
+        Ok(())
+    }
+
+    fn step(
+        &mut self,
+        genetic: &(dyn Genetic<Vec<f64>> + Send + Sync),
+        settings: &SearchSettings,
+        snapshot: &ProgressSnapshot<Vec<f64>>,
+        observer: &mut dyn Observer<Vec<f64>>,
+    ) {
+        let epoch = snapshot.epoch() + 1;
+        let mut order: Vec<usize> = (0..self.population.len()).collect();
+        order.sort_by(|a, b| {
+            self.violations[*a]
+                .max(0.0)
+                .total_cmp(&self.violations[*b].max(0.0))
+                .then(compare_costs(self.costs[*a], self.costs[*b]))
+        });
+
+        let distribution = self.distribution.as_mut().unwrap();
+        self.steps.clear();
+        self.steps.extend(
+            order
+                .iter()
+                .take(distribution.weights.len())
+                .map(|i| distribution.step_to(&self.population[*i])),
+        );
+        distribution.update(&self.steps);
+
+        let (best, parent_cost) = (order[0], self.costs[order[0]]);
+        self.sample(genetic);
+        for child in &self.population {
+            observer.child_created(epoch, child, (best, best), true);
+        }
+        self.evaluate(genetic, settings, epoch);
+        for (i, child) in self.population.iter().enumerate() {
+            observer.child_evaluated(
+                epoch,
+                child,
+                &ChildOutcome::new(false, true, self.costs[i], parent_cost),
+            );
+        }
+    }
+
+    fn population(&self) -> &[Vec<f64>] {
+        &self.population
+    }
+
+    fn costs(&self) -> &[f64] {
+        &self.costs
+    }
+
+    fn violations(&self) -> &[f64] {
+        &self.violations
+    }
+
+    fn replace(&mut self, index: usize, genome: Vec<f64>, cost: f64, violation: f64) {
+        self.population[index] = genome;
+        self.costs[index] = cost;
+        self.violations[index] = violation;
+    }
+
+    fn local_search_time(&self) -> Duration {
+        self.local_search_time
+    }
+}
+
+/// The multivariate normal search distribution and the state used to adapt it.
+struct Distribution {
+    mean: Vec<f64>,
+    sigma: f64,
+    covariance: Vec<Vec<f64>>,
+    /// The eigenvectors of the covariance matrix, as columns.
+    basis: Vec<Vec<f64>>,
+    /// The square roots of the eigenvalues of the covariance matrix.
+    scales: Vec<f64>,
+    evolution_path: Vec<f64>,
+    conjugate_path: Vec<f64>,
+    weights: Vec<f64>,
+    mueff: f64,
+    cc: f64,
+    cs: f64,
+    c1: f64,
+    cmu: f64,
+    damps: f64,
+    chi_n: f64,
+    lambda: usize,
+    generation: usize,
+    decomposed: usize,
+}
+
+impl Distribution {
+    fn new(mean: Vec<f64>, cma: &CmaSettings, lambda: usize) -> Self {
+        let n = mean.len();
+        let nf = n as f64;
+        let mu = ((lambda as f64 * cma.parent_fraction()).floor() as usize).clamp(1, lambda);
+        let raw: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+        let total: f64 = raw.iter().sum();
+        let weights: Vec<f64> = raw.iter().map(|w| w / total).collect();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+        let cc = (4.0 + mueff / nf) / (nf + 4.0 + 2.0 * mueff / nf);
+        let cs = (mueff + 2.0) / (nf + mueff + 5.0);
+        let c1 = 2.0 / ((nf + 1.3).powi(2) + mueff);
+        let cmu = (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((nf + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (nf + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = nf.sqrt() * (1.0 - 1.0 / (4.0 * nf) + 1.0 / (21.0 * nf * nf));
+        let identity: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        Self {
+            mean,
+            sigma: cma.initial_step_size(),
+            covariance: identity.clone(),
+            basis: identity,
+            scales: vec![1.0; n],
+            evolution_path: vec![0.0; n],
+            conjugate_path: vec![0.0; n],
+            weights,
+            mueff,
+            cc,
+            cs,
+            c1,
+            cmu,
+            damps,
+            chi_n,
+            lambda,
+            generation: 0,
+            decomposed: 0,
+        }
+    }
+
+    /// Sample a genome from the distribution.
+    fn sample(&self) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        let z: Vec<f64> = self
+            .scales
+            .iter()
+            .map(|d| d * standard_normal(&mut rng))
+            .collect();
+        let y = multiply(&self.basis, &z);
+        std::iter::zip(&self.mean, y)
+            .map(|(m, y)| m + self.sigma * y)
+            .collect()
+    }
+
+    /// The step from the mean to a genome, in units of the step size.
+    fn step_to(&self, genome: &[f64]) -> Vec<f64> {
+        std::iter::zip(genome, &self.mean)
+            .map(|(x, m)| (x - m) / self.sigma)
+            .collect()
+    }
+
+    /// Adapt the distribution to the steps to the best genomes of an epoch, best first.
+    fn update(&mut self, steps: &[Vec<f64>]) {
+        let n = self.mean.len();
+        let nf = n as f64;
+        self.generation += 1;
+
+        let mut step = vec![0.0; n];
+        for (w, y) in std::iter::zip(&self.weights, steps) {
+            for (s, y) in std::iter::zip(step.iter_mut(), y) {
+                *s += w * y;
+            }
+        }
+        for (m, s) in std::iter::zip(self.mean.iter_mut(), &step) {
+            *m += self.sigma * s;
+        }
+
+        // C^(-1/2) * step = B * diag(1 / D) * Bᵀ * step
+        let rotated = multiply_transpose(&self.basis, &step);
+        let scaled: Vec<f64> = std::iter::zip(rotated, &self.scales)
+            .map(|(r, d)| r / d)
+            .collect();
+        let whitened = multiply(&self.basis, &scaled);
+
+        let cs_factor = (self.cs * (2.0 - self.cs) * self.mueff).sqrt();
+        for (p, w) in std::iter::zip(self.conjugate_path.iter_mut(), &whitened) {
+            *p = (1.0 - self.cs) * *p + cs_factor * w;
+        }
+        let ps_norm = norm(&self.conjugate_path);
+        let hsig =
+            ps_norm / (1.0 - (1.0 - self.cs).powi(2 * self.generation as i32)).sqrt() / self.chi_n
+                < 1.4 + 2.0 / (nf + 1.0);
+        let hsig = if hsig { 1.0 } else { 0.0 };
+
+        let cc_factor = (self.cc * (2.0 - self.cc) * self.mueff).sqrt();
+        for (p, s) in std::iter::zip(self.evolution_path.iter_mut(), &step) {
+            *p = (1.0 - self.cc) * *p + hsig * cc_factor * s;
+        }
+
+        let correction = (1.0 - hsig) * self.cc * (2.0 - self.cc);
+        for i in 0..n {
+            for j in 0..=i {
+                let rank_mu: f64 = std::iter::zip(&self.weights, steps)
+                    .map(|(w, y)| w * y[i] * y[j])
+                    .sum();
+                let value = (1.0 - self.c1 - self.cmu) * self.covariance[i][j]
+                    + self.c1
+                        * (self.evolution_path[i] * self.evolution_path[j]
+                            + correction * self.covariance[i][j])
+                    + self.cmu * rank_mu;
+                self.covariance[i][j] = value;
+                self.covariance[j][i] = value;
+            }
+        }
+
+        self.sigma *= ((self.cs / self.damps) * (ps_norm / self.chi_n - 1.0)).exp();
+
+        // Decompose the covariance matrix only every few epochs, because it takes O(n³) time.
+        let interval = (self.lambda as f64 / (self.c1 + self.cmu) / nf / 10.0).max(1.0);
+        if (self.generation - self.decomposed) as f64 >= interval {
+            self.decomposed = self.generation;
+            let (values, basis) = symmetric_eigen(&self.covariance);
+            self.scales = values
+                .iter()
+                .map(|v| v.max(f64::MIN_POSITIVE).sqrt())
+                .collect();
+            self.basis = basis;
+        }
+    }
+}
+
+fn multiply(matrix: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    matrix
+        .iter()
+        .map(|row| std::iter::zip(row, vector).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+fn multiply_transpose(matrix: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    (0..vector.len())
+        .map(|j| (0..matrix.len()).map(|i| matrix[i][j] * vector[i]).sum())
+        .collect()
+}
+
+fn norm(vector: &[f64]) -> f64 {
+    vector.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod cma;
+mod eigen;
+
+pub use cma::{cma_es, CmaEs};
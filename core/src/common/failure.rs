@@ -71,4 +71,175 @@ impl Failure {
             message: Box::from("time limit must be non-zero"),
         }
     }
+
+    /// Return a failure for the case when the tree depth limits are inconsistent.
+    pub fn tree_depth() -> Self {
+        Failure {
+            error: 8,
+            message: Box::from(
+                "tree depths must satisfy min_init_depth <= max_init_depth <= max_depth",
+            ),
+        }
+    }
+
+    /// Return a failure for the case when the tree size limit is invalid.
+    pub fn tree_size() -> Self {
+        Failure {
+            error: 9,
+            message: Box::from("tree size limit must be at least 1"),
+        }
+    }
+
+    /// Return a failure for the case when the parsimony coefficient is invalid.
+    pub fn parsimony_coefficient() -> Self {
+        Failure {
+            error: 10,
+            message: Box::from("parsimony coefficient must be non-negative"),
+        }
+    }
+
+    /// Return a failure for the case when the mutation operator weights are invalid.
+    pub fn mutation_weights() -> Self {
+        Failure {
+            error: 11,
+            message: Box::from("mutation weights must be non-negative and not all zero"),
+        }
+    }
+
+    /// Return a failure for the case when a primitive set cannot build complete trees.
+    pub fn primitive_set() -> Self {
+        Failure {
+            error: 12,
+            message: Box::from("primitive set must have a terminal for every kind it uses"),
+        }
+    }
+
+    /// Return a failure for the case when CSV data cannot be read or parsed.
+    pub fn csv() -> Self {
+        Failure {
+            error: 13,
+            message: Box::from("CSV data must have a header row and numeric values in every field"),
+        }
+    }
+
+    /// Return a failure for the case when a dataset is empty or has no output column.
+    pub fn dataset() -> Self {
+        Failure {
+            error: 14,
+            message: Box::from("dataset must have at least one row and the named output column"),
+        }
+    }
+
+    /// Return a failure for the case when a constraint handling setting is invalid.
+    pub fn constraint_setting() -> Self {
+        Failure {
+            error: 15,
+            message: Box::from("a constraint handling setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a niching setting is invalid.
+    pub fn niching_setting() -> Self {
+        Failure {
+            error: 16,
+            message: Box::from("a niching setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a restart setting is invalid.
+    pub fn restart_setting() -> Self {
+        Failure {
+            error: 17,
+            message: Box::from("a restart setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a memetic search setting is invalid.
+    pub fn memetic_setting() -> Self {
+        Failure {
+            error: 18,
+            message: Box::from("a memetic search setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a differential evolution setting is invalid.
+    pub fn differential_setting() -> Self {
+        Failure {
+            error: 19,
+            message: Box::from("a differential evolution setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a CMA-ES setting is invalid.
+    pub fn cma_setting() -> Self {
+        Failure {
+            error: 20,
+            message: Box::from("a CMA-ES setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when an evolution strategy setting is invalid.
+    pub fn evolution_setting() -> Self {
+        Failure {
+            error: 21,
+            message: Box::from("an evolution strategy setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a termination condition setting is invalid.
+    pub fn terminator_setting() -> Self {
+        Failure {
+            error: 22,
+            message: Box::from("a termination condition setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when the evaluation limit is invalid.
+    pub fn evaluation_limit() -> Self {
+        Failure {
+            error: 23,
+            message: Box::from("evaluation limit must be at least 1"),
+        }
+    }
+
+    /// Return a failure for the case when a cost or constraint violation is NaN and the
+    /// [`crate::NanPolicy`] rejects it.
+    pub fn nan_cost() -> Self {
+        Failure {
+            error: 24,
+            message: Box::from("a cost or constraint violation is NaN"),
+        }
+    }
+
+    /// Return a failure for the case when a hall of fame setting is invalid.
+    pub fn hall_of_fame_setting() -> Self {
+        Failure {
+            error: 25,
+            message: Box::from("a hall of fame setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a genealogy setting is invalid.
+    pub fn genealogy_setting() -> Self {
+        Failure {
+            error: 26,
+            message: Box::from("a genealogy setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when an operator setting is invalid.
+    pub fn operator_setting() -> Self {
+        Failure {
+            error: 27,
+            message: Box::from("an operator setting is invalid"),
+        }
+    }
+
+    /// Return a failure for the case when a genetic combinator setting is invalid.
+    pub fn combinator_setting() -> Self {
+        Failure {
+            error: 28,
+            message: Box::from("a genetic combinator setting is invalid"),
+        }
+    }
 }
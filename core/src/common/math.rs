@@ -1,3 +1,20 @@
+use rand::Rng;
+use std::cmp::Ordering;
+
+/// Compare two costs, where lower costs are better.
+///
+/// This is a total ordering: negative and infinite costs compare by value and NaN compares
+/// greater than every other cost, so it is always the worst.
+/// Searches, selectors and statistics all order costs this way.
+pub fn compare_costs(lhs: f64, rhs: f64) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => lhs.total_cmp(&rhs),
+    }
+}
+
 pub(crate) fn mean(values: &[f64]) -> f64 {
     values.iter().fold(0.0, |acc, x| acc + x) / values.len() as f64
 }
@@ -5,5 +22,14 @@ pub(crate) fn mean(values: &[f64]) -> f64 {
 pub(crate) fn largest(values: &[f64]) -> f64 {
     values
         .iter()
-        .fold(0.0, |acc, x| if *x > acc { *x } else { acc })
+        .copied()
+        .max_by(|lhs, rhs| compare_costs(*lhs, *rhs))
+        .unwrap_or(f64::NEG_INFINITY)
+}
+
+/// Sample the standard normal distribution, using the Box-Muller transform.
+pub(crate) fn standard_normal(rng: &mut impl Rng) -> f64 {
+    let u: f64 = 1.0 - rng.gen::<f64>();
+    let v: f64 = rng.gen();
+    (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
 }
@@ -4,5 +4,7 @@ mod math;
 
 pub use collections::*;
 pub use failure::*;
+pub use math::compare_costs;
 pub(crate) use math::largest;
 pub(crate) use math::mean;
+pub(crate) use math::standard_normal;
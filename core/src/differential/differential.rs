@@ -0,0 +1,254 @@
+use crate::common::make_vec;
+use crate::search::{evaluate, local_search, run};
+use crate::{
+    compare_costs, ChildOutcome, DifferentialSettings, DifferentialVariant, Failure, Genetic,
+    Observer, ParameterAdaptation, Progress, ProgressSnapshot, SearchSettings, Strategy, Success,
+};
+use rand::Rng;
+use std::time::Duration;
+
+/// The probability that jDE resamples the scale factor or crossover rate of a genome.
+const JDE_TAU: f64 = 0.1;
+
+/// The range of scale factors sampled by jDE.
+const JDE_SCALE_FACTOR: std::ops::Range<f64> = 0.1..1.0;
+
+/// The smallest population from which three distinct partners can be chosen for each genome.
+const MIN_POPULATION_SIZE: usize = 4;
+
+/// Search for a solution to a real-valued problem using differential evolution.
+///
+/// Genomes are created by [`initialize()`](crate::Genetic#initialize()), costed by
+/// [`evaluate()`](crate::Genetic#evaluate()) and, after each new genome is built,
+/// [`repair()`](crate::Genetic#repair()) can be used to keep it within bounds.
+/// The crossover and mutation operations of `genetic` are not used.
+/// Neither are the mutation probability and restart settings of `settings`.
+/// Each trial genome is reported to observers as a mutated child of the genome it competes
+/// with.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the problem.
+/// * `differential` - Configuration of differential evolution.
+/// * `progress` - Define the progress reporting callback.
+/// * `settings` - Configuration of the search.
+///
+pub fn differential_evolution(
+    genetic: Box<dyn Genetic<Vec<f64>> + Send + Sync>,
+    differential: &DifferentialSettings,
+    mut progress: Option<Progress<Vec<f64>>>,
+    settings: &SearchSettings,
+) -> Result<Success<Vec<f64>>, Failure> {
+    run(
+        genetic,
+        &mut DifferentialEvolution::new(*differential),
+        &mut progress,
+        None,
+        settings,
+    )
+}
+
+/// The differential evolution strategy used by [`differential_evolution`].
+pub struct DifferentialEvolution {
+    settings: DifferentialSettings,
+    population: Vec<Vec<f64>>,
+    costs: Vec<f64>,
+    violations: Vec<f64>,
+    scale_factors: Vec<f64>,
+    crossover_rates: Vec<f64>,
+    trials: Vec<Vec<f64>>,
+    trial_scale_factors: Vec<f64>,
+    trial_crossover_rates: Vec<f64>,
+    trial_costs: Vec<f64>,
+    trial_violations: Vec<f64>,
+    local_search_time: Duration,
+}
+
+impl DifferentialEvolution {
+    /// Create a differential evolution strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Configuration of differential evolution.
+    pub fn new(settings: DifferentialSettings) -> Self {
+        Self {
+            settings,
+            population: Vec::new(),
+            costs: Vec::new(),
+            violations: Vec::new(),
+            scale_factors: Vec::new(),
+            crossover_rates: Vec::new(),
+            trials: Vec::new(),
+            trial_scale_factors: Vec::new(),
+            trial_crossover_rates: Vec::new(),
+            trial_costs: Vec::new(),
+            trial_violations: Vec::new(),
+            local_search_time: Duration::ZERO,
+        }
+    }
+}
+
+impl Strategy<Vec<f64>> for DifferentialEvolution {
+    fn initialize(
+        &mut self,
+        genetic: &(dyn Genetic<Vec<f64>> + Send + Sync),
+        settings: &SearchSettings,
+    ) -> Result<(), Failure> {
+        let n = settings.population_size();
+        if n < MIN_POPULATION_SIZE {
+            return Err(Failure::differential_setting());
+        }
+
+        self.population = make_vec(n, || genetic.initialize());
+        self.costs = make_vec(n, || 0.0);
+        self.violations = make_vec(n, || 0.0);
+        self.scale_factors = make_vec(n, || self.settings.scale_factor());
+        self.crossover_rates = make_vec(n, || self.settings.crossover_rate());
+        self.trial_costs = make_vec(n, || 0.0);
+        self.trial_violations = make_vec(n, || 0.0);
+        self.local_search_time += evaluate(
+            genetic,
+            settings.concurrency(),
+            local_search(genetic, settings, 1),
+            &mut self.population,
+            &mut self.costs,
+            &mut self.violations,
+        );
+        Ok(())
+    }
+
+    fn step(
+        &mut self,
+        genetic: &(dyn Genetic<Vec<f64>> + Send + Sync),
+        settings: &SearchSettings,
+        snapshot: &ProgressSnapshot<Vec<f64>>,
+        observer: &mut dyn Observer<Vec<f64>>,
+    ) {
+        let n = self.population.len();
+        let epoch = snapshot.epoch() + 1;
+        let best = (0..n)
+            .min_by(|a, b| {
+                self.violations[*a]
+                    .max(0.0)
+                    .total_cmp(&self.violations[*b].max(0.0))
+                    .then(compare_costs(self.costs[*a], self.costs[*b]))
+            })
+            .unwrap();
+
+        let mut rng = rand::thread_rng();
+        self.trials.clear();
+        self.trial_scale_factors.clear();
+        self.trial_crossover_rates.clear();
+        for i in 0..n {
+            let (mut f, mut cr) = (self.scale_factors[i], self.crossover_rates[i]);
+            if self.settings.adaptation() == ParameterAdaptation::Jde {
+                if rng.gen_bool(JDE_TAU) {
+                    f = rng.gen_range(JDE_SCALE_FACTOR);
+                }
+                if rng.gen_bool(JDE_TAU) {
+                    cr = rng.gen_range(0.0..=1.0);
+                }
+            }
+
+            let [r1, r2, r3] = distinct_partners(&mut rng, n, i);
+            let population = &self.population;
+            let (x, a, b, c) = (
+                &population[i],
+                &population[r1],
+                &population[r2],
+                &population[r3],
+            );
+            let base = &population[best];
+            let forced = rng.gen_range(0..x.len().max(1));
+            let trial: Vec<f64> = (0..x.len())
+                .map(|j| {
+                    if j != forced && !rng.gen_bool(cr) {
+                        return x[j];
+                    }
+                    match self.settings.variant() {
+                        DifferentialVariant::Rand1Bin => a[j] + f * (b[j] - c[j]),
+                        DifferentialVariant::Best1Bin => base[j] + f * (a[j] - b[j]),
+                        DifferentialVariant::CurrentToBest1Bin => {
+                            x[j] + f * (base[j] - x[j]) + f * (a[j] - b[j])
+                        }
+                    }
+                })
+                .collect();
+            let trial = genetic.repair(trial);
+            observer.child_created(epoch, &trial, (i, i), true);
+            self.trials.push(trial);
+            self.trial_scale_factors.push(f);
+            self.trial_crossover_rates.push(cr);
+        }
+
+        self.local_search_time += evaluate(
+            genetic,
+            settings.concurrency(),
+            local_search(genetic, settings, epoch),
+            &mut self.trials,
+            &mut self.trial_costs,
+            &mut self.trial_violations,
+        );
+        for (i, trial) in self.trials.iter().enumerate() {
+            observer.child_evaluated(
+                epoch,
+                trial,
+                &ChildOutcome::new(false, true, self.trial_costs[i], self.costs[i]),
+            );
+        }
+
+        for (i, trial) in self.trials.drain(..).enumerate() {
+            let trial_violation = self.trial_violations[i].max(0.0);
+            let violation = self.violations[i].max(0.0);
+            let survives = if trial_violation != violation {
+                trial_violation < violation
+            } else {
+                self.trial_costs[i] <= self.costs[i]
+            };
+            if survives {
+                self.population[i] = trial;
+                self.costs[i] = self.trial_costs[i];
+                self.violations[i] = self.trial_violations[i];
+                self.scale_factors[i] = self.trial_scale_factors[i];
+                self.crossover_rates[i] = self.trial_crossover_rates[i];
+            }
+        }
+    }
+
+    fn population(&self) -> &[Vec<f64>] {
+        &self.population
+    }
+
+    fn costs(&self) -> &[f64] {
+        &self.costs
+    }
+
+    fn violations(&self) -> &[f64] {
+        &self.violations
+    }
+
+    fn replace(&mut self, index: usize, genome: Vec<f64>, cost: f64, violation: f64) {
+        self.population[index] = genome;
+        self.costs[index] = cost;
+        self.violations[index] = violation;
+    }
+
+    fn local_search_time(&self) -> Duration {
+        self.local_search_time
+    }
+}
+
+/// Choose three distinct indices, all different from `exclude`.
+fn distinct_partners(rng: &mut impl Rng, n: usize, exclude: usize) -> [usize; 3] {
+    let mut result = [exclude; 3];
+    for k in 0..3 {
+        loop {
+            let candidate = rng.gen_range(0..n);
+            if candidate != exclude && !result[..k].contains(&candidate) {
+                result[k] = candidate;
+                break;
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod differential;
+
+pub use differential::{differential_evolution, DifferentialEvolution};
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Measure how different two genomes are.
+///
+/// Implementations must return a non-negative value that is 0 for identical genomes.
+/// Any function or closure with the signature `Fn(&G, &G) -> f64` is a distance.
+pub trait Distance<G: ?Sized> {
+    /// The distance between two genomes.
+    fn distance(&self, lhs: &G, rhs: &G) -> f64;
+}
+
+impl<G: ?Sized, F> Distance<G> for F
+where
+    F: Fn(&G, &G) -> f64,
+{
+    fn distance(&self, lhs: &G, rhs: &G) -> f64 {
+        self(lhs, rhs)
+    }
+}
+
+/// The number of positions at which two sequences differ.
+/// Each element of the longer sequence beyond the end of the shorter one counts as a difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HammingDistance;
+
+impl<T: PartialEq> Distance<[T]> for HammingDistance {
+    fn distance(&self, lhs: &[T], rhs: &[T]) -> f64 {
+        let different = std::iter::zip(lhs, rhs).filter(|(l, r)| l != r).count();
+        (different + lhs.len().abs_diff(rhs.len())) as f64
+    }
+}
+
+impl<T: PartialEq> Distance<Vec<T>> for HammingDistance {
+    fn distance(&self, lhs: &Vec<T>, rhs: &Vec<T>) -> f64 {
+        self.distance(lhs.as_slice(), rhs.as_slice())
+    }
+}
+
+impl Distance<str> for HammingDistance {
+    fn distance(&self, lhs: &str, rhs: &str) -> f64 {
+        let lhs: Vec<char> = lhs.chars().collect();
+        let rhs: Vec<char> = rhs.chars().collect();
+        self.distance(lhs.as_slice(), rhs.as_slice())
+    }
+}
+
+impl Distance<String> for HammingDistance {
+    fn distance(&self, lhs: &String, rhs: &String) -> f64 {
+        self.distance(lhs.as_str(), rhs.as_str())
+    }
+}
+
+/// The straight line distance between two vectors of real numbers.
+/// Missing elements of the shorter vector are treated as 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EuclideanDistance;
+
+impl Distance<[f64]> for EuclideanDistance {
+    fn distance(&self, lhs: &[f64], rhs: &[f64]) -> f64 {
+        let (longer, shorter) = if lhs.len() >= rhs.len() {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        longer
+            .iter()
+            .enumerate()
+            .map(|(i, l)| l - shorter.get(i).unwrap_or(&0.0))
+            .map(|d| d * d)
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl Distance<Vec<f64>> for EuclideanDistance {
+    fn distance(&self, lhs: &Vec<f64>, rhs: &Vec<f64>) -> f64 {
+        self.distance(lhs.as_slice(), rhs.as_slice())
+    }
+}
+
+/// The number of adjacencies in the first permutation that are missing from the second,
+/// treating each permutation as a cycle and ignoring direction.
+///
+/// This suits ordering problems such as the travelling salesman problem, where a tour and its
+/// rotations or reversal are equivalent and have distance 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PermutationDistance;
+
+impl<T: Eq + Hash> Distance<[T]> for PermutationDistance {
+    fn distance(&self, lhs: &[T], rhs: &[T]) -> f64 {
+        let edges: HashSet<(&T, &T)> = adjacencies(rhs)
+            .flat_map(|(a, b)| [(a, b), (b, a)])
+            .collect();
+        adjacencies(lhs)
+            .filter(|edge| !edges.contains(edge))
+            .count() as f64
+    }
+}
+
+impl<T: Eq + Hash> Distance<Vec<T>> for PermutationDistance {
+    fn distance(&self, lhs: &Vec<T>, rhs: &Vec<T>) -> f64 {
+        self.distance(lhs.as_slice(), rhs.as_slice())
+    }
+}
+
+fn adjacencies<T>(permutation: &[T]) -> impl Iterator<Item = (&T, &T)> {
+    let wrap = if permutation.len() > 2 {
+        permutation.last().zip(permutation.first())
+    } else {
+        None
+    };
+    permutation
+        .windows(2)
+        .map(|pair| (&pair[0], &pair[1]))
+        .chain(wrap)
+}
@@ -0,0 +1,125 @@
+use crate::Distance;
+use rand::Rng;
+
+/// The largest population for which every pair of genomes is compared.
+/// Larger populations use the sampled estimate for the mean distance, and count distinct genomes
+/// in a random sample of this many genomes.
+const EXACT_LIMIT: usize = 256;
+
+/// Measures of how different the genomes in a population are from each other.
+/// A population that loses diversity early in a search has probably converged prematurely.
+///
+/// See [`crate::Genetic::distance`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Diversity {
+    mean_distance: f64,
+    sampled_distance: f64,
+    entropy: f64,
+    unique: usize,
+}
+
+impl Diversity {
+    pub fn new(mean_distance: f64, sampled_distance: f64, entropy: f64, unique: usize) -> Self {
+        Self {
+            mean_distance,
+            sampled_distance,
+            entropy,
+            unique,
+        }
+    }
+
+    /// Measure the diversity of a population.
+    /// Every pair of genomes is compared for populations of up to 256 genomes. Larger
+    /// populations would take time proportional to the square of the population size, so their
+    /// mean distance is the sampled estimate instead, and their entropy and number of distinct
+    /// genomes are measured over a random sample of 256 genomes.
+    ///
+    /// # Arguments
+    ///
+    /// * `population` - The genomes to measure.
+    /// * `distance` - Measure how different two genomes are.
+    pub fn measure<G: PartialEq>(population: &[G], distance: &dyn Distance<G>) -> Self {
+        let n = population.len();
+        let mut sampled = 0.0;
+        if n > 1 {
+            let mut rng = rand::thread_rng();
+            for _ in 0..n {
+                let i = rng.gen_range(0..n);
+                let j = (i + rng.gen_range(1..n)) % n;
+                sampled += distance.distance(&population[i], &population[j]);
+            }
+            sampled /= n as f64;
+        }
+
+        let mean_distance = if n > EXACT_LIMIT {
+            sampled
+        } else {
+            let mut total = 0.0;
+            for i in 0..n {
+                for j in i + 1..n {
+                    total += distance.distance(&population[i], &population[j]);
+                }
+            }
+            let pairs = n * n.saturating_sub(1) / 2;
+            if pairs == 0 {
+                0.0
+            } else {
+                total / pairs as f64
+            }
+        };
+
+        let sample: Vec<&G> = if n > EXACT_LIMIT {
+            rand::seq::index::sample(&mut rand::thread_rng(), n, EXACT_LIMIT)
+                .into_iter()
+                .map(|i| &population[i])
+                .collect()
+        } else {
+            population.iter().collect()
+        };
+        let mut counts: Vec<(&G, usize)> = Vec::new();
+        for genome in sample.iter().copied() {
+            match counts.iter_mut().find(|(g, _)| *g == genome) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((genome, 1)),
+            }
+        }
+        let entropy = counts
+            .iter()
+            .map(|(_, count)| *count as f64 / sample.len() as f64)
+            .map(|p| -p * p.log2())
+            .sum::<f64>()
+            .max(0.0);
+
+        Self::new(mean_distance, sampled, entropy, counts.len())
+    }
+
+    /// The mean distance between every pair of genomes in the population.
+    /// For populations of more than 256 genomes this is the same as
+    /// [`Diversity::sampled_distance`].
+    pub fn mean_distance(&self) -> f64 {
+        self.mean_distance
+    }
+
+    /// The mean distance between randomly sampled pairs of different genomes, one pair for each
+    /// genome in the population.
+    /// This is a cheaper estimate of [`Diversity::mean_distance`].
+    pub fn sampled_distance(&self) -> f64 {
+        self.sampled_distance
+    }
+
+    /// The Shannon entropy, in bits, of the frequencies of the distinct genomes in the
+    /// population.
+    /// This is 0 when every genome is the same and `log2(population size)` when all differ.
+    /// For populations of more than 256 genomes it is measured over a random sample of 256
+    /// genomes.
+    pub fn entropy(&self) -> f64 {
+        self.entropy
+    }
+
+    /// The number of distinct genomes in the population.
+    /// For populations of more than 256 genomes this is the number of distinct genomes in a
+    /// random sample of 256 genomes.
+    pub fn unique(&self) -> usize {
+        self.unique
+    }
+}
@@ -0,0 +1,6 @@
+mod distance;
+#[allow(clippy::module_inception)]
+mod diversity;
+
+pub use distance::{Distance, EuclideanDistance, HammingDistance, PermutationDistance};
+pub use diversity::Diversity;
@@ -0,0 +1,237 @@
+use crate::common::{make_vec, standard_normal};
+use crate::search::{better_cost, evaluate, local_search, run};
+use crate::{
+    compare_costs, ChildOutcome, EvolutionSettings, Failure, Genetic, Observer, Progress,
+    ProgressSnapshot, SearchSettings, Strategy, Success, Survivor,
+};
+use rand::Rng;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Search for a solution using a (μ, λ) or (μ + λ) evolution strategy.
+///
+/// In each epoch λ offspring are generated from μ parents, by optional
+/// [`crossover()`](crate::Genetic#crossover()) followed by mutation, and the best μ genomes
+/// survive as the next parents.
+/// With self-adaptive step sizes, each genome carries a step size that is mutated along with
+/// it and passed to [`mutate_scaled()`](crate::Genetic#mutate_scaled()).
+/// Every offspring is mutated, so the mutation probability of `settings` is not used.
+/// Neither are its restart settings.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `evolution` - Configuration of the evolution strategy.
+/// * `progress` - Define the progress reporting callback.
+/// * `settings` - Configuration of the search. The population size is the number of parents.
+///
+pub fn evolution_strategy<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    evolution: &EvolutionSettings,
+    mut progress: Option<Progress<G>>,
+    settings: &SearchSettings,
+) -> Result<Success<G>, Failure>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    run(
+        genetic,
+        &mut EvolutionStrategy::new(*evolution),
+        &mut progress,
+        None,
+        settings,
+    )
+}
+
+/// The evolution strategy used by [`evolution_strategy`].
+pub struct EvolutionStrategy<G> {
+    settings: EvolutionSettings,
+    parents: Vec<G>,
+    steps: Vec<f64>,
+    costs: Vec<f64>,
+    violations: Vec<f64>,
+    offspring: Vec<G>,
+    offspring_steps: Vec<f64>,
+    offspring_costs: Vec<f64>,
+    offspring_violations: Vec<f64>,
+    offspring_origins: Vec<(bool, f64)>,
+    local_search_time: Duration,
+}
+
+impl<G> EvolutionStrategy<G> {
+    /// Create an evolution strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Configuration of the evolution strategy.
+    pub fn new(settings: EvolutionSettings) -> Self {
+        Self {
+            settings,
+            parents: Vec::new(),
+            steps: Vec::new(),
+            costs: Vec::new(),
+            violations: Vec::new(),
+            offspring: Vec::new(),
+            offspring_steps: Vec::new(),
+            offspring_costs: Vec::new(),
+            offspring_violations: Vec::new(),
+            offspring_origins: Vec::new(),
+            local_search_time: Duration::ZERO,
+        }
+    }
+}
+
+impl<G> Strategy<G> for EvolutionStrategy<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+    ) -> Result<(), Failure> {
+        let mu = settings.population_size();
+        let lambda = self.settings.offspring();
+        if self.settings.survivor() == Survivor::Comma && lambda < mu {
+            return Err(Failure::evolution_setting());
+        }
+
+        self.parents = make_vec(mu, || genetic.initialize());
+        self.steps = make_vec(mu, || self.settings.initial_step_size());
+        self.costs = make_vec(mu, || 0.0);
+        self.violations = make_vec(mu, || 0.0);
+        self.offspring_costs = make_vec(lambda, || 0.0);
+        self.offspring_violations = make_vec(lambda, || 0.0);
+        self.local_search_time += evaluate(
+            genetic,
+            settings.concurrency(),
+            local_search(genetic, settings, 1),
+            &mut self.parents,
+            &mut self.costs,
+            &mut self.violations,
+        );
+        Ok(())
+    }
+
+    fn step(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+        snapshot: &ProgressSnapshot<G>,
+        observer: &mut dyn Observer<G>,
+    ) {
+        let mu = self.parents.len();
+        let mut rng = rand::thread_rng();
+        self.offspring.clear();
+        self.offspring_steps.clear();
+        self.offspring_origins.clear();
+        for _ in 0..self.settings.offspring() {
+            let p = rng.gen_range(0..mu);
+            let recombined = rng.gen_bool(self.settings.recombination_probability());
+            let (child, step, q) = if recombined {
+                let q = rng.gen_range(0..mu);
+                (
+                    genetic.crossover(&self.parents[p], &self.parents[q]),
+                    (self.steps[p] * self.steps[q]).sqrt(),
+                    q,
+                )
+            } else {
+                (self.parents[p].clone(), self.steps[p], p)
+            };
+            let mutant = if self.settings.self_adaptive() {
+                let step = step * (self.settings.learning_rate() * standard_normal(&mut rng)).exp();
+                self.offspring_steps.push(step);
+                genetic.mutate_scaled(&child, step)
+            } else {
+                self.offspring_steps.push(step);
+                genetic.mutate(&child)
+            };
+            let child = genetic.repair(mutant);
+            observer.child_created(snapshot.epoch() + 1, &child, (p, q), true);
+            self.offspring.push(child);
+            self.offspring_origins
+                .push((recombined, better_cost(self.costs[p], self.costs[q])));
+        }
+
+        self.local_search_time += evaluate(
+            genetic,
+            settings.concurrency(),
+            local_search(genetic, settings, snapshot.epoch() + 1),
+            &mut self.offspring,
+            &mut self.offspring_costs,
+            &mut self.offspring_violations,
+        );
+        for (i, (recombined, parent_cost)) in self.offspring_origins.iter().enumerate() {
+            observer.child_evaluated(
+                snapshot.epoch() + 1,
+                &self.offspring[i],
+                &ChildOutcome::new(*recombined, true, self.offspring_costs[i], *parent_cost),
+            );
+        }
+
+        if self.settings.survivor() == Survivor::Comma {
+            self.parents.clear();
+            self.steps.clear();
+            self.costs.clear();
+            self.violations.clear();
+        }
+        self.parents.append(&mut self.offspring);
+        self.steps.append(&mut self.offspring_steps);
+        self.costs.extend_from_slice(&self.offspring_costs);
+        self.violations
+            .extend_from_slice(&self.offspring_violations);
+        survive(
+            mu,
+            &mut self.parents,
+            &mut self.steps,
+            &mut self.costs,
+            &mut self.violations,
+        );
+    }
+
+    fn population(&self) -> &[G] {
+        &self.parents
+    }
+
+    fn costs(&self) -> &[f64] {
+        &self.costs
+    }
+
+    fn violations(&self) -> &[f64] {
+        &self.violations
+    }
+
+    fn replace(&mut self, index: usize, genome: G, cost: f64, violation: f64) {
+        self.parents[index] = genome;
+        self.costs[index] = cost;
+        self.violations[index] = violation;
+    }
+
+    fn local_search_time(&self) -> Duration {
+        self.local_search_time
+    }
+}
+
+/// Keep the best `mu` genomes, together with their step sizes, costs and violations.
+fn survive<G>(
+    mu: usize,
+    genomes: &mut Vec<G>,
+    steps: &mut Vec<f64>,
+    costs: &mut Vec<f64>,
+    violations: &mut Vec<f64>,
+) {
+    let mut order: Vec<usize> = (0..genomes.len()).collect();
+    order.sort_by(|a, b| {
+        violations[*a]
+            .max(0.0)
+            .total_cmp(&violations[*b].max(0.0))
+            .then(compare_costs(costs[*a], costs[*b]))
+    });
+    order.truncate(mu);
+
+    let mut candidates: Vec<Option<G>> = genomes.drain(..).map(Some).collect();
+    genomes.extend(order.iter().map(|i| candidates[*i].take().unwrap()));
+    *steps = order.iter().map(|i| steps[*i]).collect();
+    *costs = order.iter().map(|i| costs[*i]).collect();
+    *violations = order.iter().map(|i| violations[*i]).collect();
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod evolution;
+
+pub use evolution::{evolution_strategy, EvolutionStrategy};
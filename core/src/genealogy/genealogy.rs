@@ -0,0 +1,288 @@
+use crate::{Birth, ChildOutcome, Failure, Lineage, Observer, ProgressSnapshot};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Track the lineage of every genome in a search: its parents, whether it was mutated and the
+/// epoch it was born.
+///
+/// A genealogy is an [`Observer`], so it is attached with [`crate::search_observed`] or
+/// [`crate::Search::with_observers`].
+/// Clones share the same records, so keep a clone to read the genealogy during or after the
+/// search.
+/// Parents are only known for strategies that report the children they create, see
+/// [`Observer::child_created`].
+/// Genomes are matched to children and survivors of the previous epoch by equality, so a child
+/// changed by Lamarckian local search is recorded without parents.
+/// Only candidates at the same position or with the same cost are compared, using the costs
+/// reported to [`Observer::child_evaluated`] and [`Observer::evaluated`].
+///
+/// Records are kept for a bounded number of epochs, so the lineage of a genome only reaches
+/// back as far as the retention window.
+/// The records of the current population and of the best genome are always kept.
+pub struct Genealogy<G> {
+    inner: Arc<Mutex<Inner<G>>>,
+}
+
+struct Inner<G> {
+    retention: usize,
+    next_id: usize,
+    epoch: usize,
+    ids: Vec<usize>,
+    genomes: Vec<G>,
+    costs: Vec<f64>,
+    children: Vec<Child<G>>,
+    evaluated_children: usize,
+    births: HashMap<usize, Birth>,
+    best: Option<usize>,
+}
+
+impl<G> Genealogy<G> {
+    /// Create a genealogy that keeps records for `retention` epochs.
+    ///
+    /// # Arguments
+    ///
+    /// * `retention` - The number of epochs for which records are kept. Must be at least 1.
+    ///
+    pub fn new(retention: usize) -> Result<Self, Failure> {
+        if retention < 1 {
+            return Err(Failure::genealogy_setting());
+        }
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(Inner {
+                retention,
+                next_id: 0,
+                epoch: 0,
+                ids: Vec::new(),
+                genomes: Vec::new(),
+                costs: Vec::new(),
+                children: Vec::new(),
+                evaluated_children: 0,
+                births: HashMap::new(),
+                best: None,
+            })),
+        })
+    }
+
+    /// The number of epochs for which records are kept.
+    pub fn retention(&self) -> usize {
+        self.inner.lock().unwrap().retention
+    }
+
+    /// The record of the genome with identifier `id`, unless it is no longer retained.
+    pub fn birth(&self, id: usize) -> Option<Birth> {
+        self.inner.lock().unwrap().births.get(&id).cloned()
+    }
+
+    /// The identifiers of the genomes in the current population, in population order.
+    pub fn population_ids(&self) -> Vec<usize> {
+        self.inner.lock().unwrap().ids.clone()
+    }
+
+    /// The identifier of the best genome found so far, or `None` before the first epoch.
+    pub fn best_id(&self) -> Option<usize> {
+        self.inner.lock().unwrap().best
+    }
+
+    /// The genome with identifier `id` and its retained ancestors.
+    /// This is empty if the genome is no longer retained.
+    pub fn lineage(&self, id: usize) -> Lineage {
+        let inner = self.inner.lock().unwrap();
+        let mut visited = HashSet::new();
+        let mut pending = vec![id];
+        let mut births = Vec::new();
+        while let Some(id) = pending.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(birth) = inner.births.get(&id) {
+                pending.extend_from_slice(birth.parents());
+                births.push(birth.clone());
+            }
+        }
+        births.sort_by_key(|birth| birth.id());
+        Lineage::new(births)
+    }
+
+    /// The best genome found so far and its retained ancestors.
+    /// Export it with [`Lineage::to_dot`] or [`Lineage::to_json`].
+    pub fn best_lineage(&self) -> Lineage {
+        match self.best_id() {
+            Some(id) => self.lineage(id),
+            None => Lineage::default(),
+        }
+    }
+}
+
+impl<G> Clone for Genealogy<G> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<G> Inner<G>
+where
+    G: Clone + PartialEq,
+{
+    fn next_id(&mut self) -> usize {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// Identify each genome of `population` as a child of this epoch, a survivor of the
+    /// previous epoch or a new genome.
+    fn identify(&mut self, epoch: usize, population: &[G], costs: &[f64]) {
+        let children = std::mem::take(&mut self.children);
+        self.evaluated_children = 0;
+        let mut unborn = Candidates::new(children.iter().map(|child| child.cost));
+        let mut previous = Candidates::new(self.costs.iter().copied().map(Some));
+        let mut ids = Vec::with_capacity(population.len());
+
+        for (i, (genome, cost)) in std::iter::zip(population, costs).enumerate() {
+            if let Some(j) = unborn.take(i, *cost, |j| children[j].genome == *genome) {
+                let birth = children[j].birth.clone().with_cost(*cost);
+                ids.push(birth.id());
+                self.births.insert(birth.id(), birth);
+                continue;
+            }
+
+            if let Some(j) = previous.take(i, *cost, |j| self.genomes[j] == *genome) {
+                ids.push(self.ids[j]);
+                continue;
+            }
+
+            let id = self.next_id();
+            ids.push(id);
+            self.births
+                .insert(id, Birth::new(id, vec![], false, epoch).with_cost(*cost));
+        }
+
+        self.ids = ids;
+        self.genomes = population.to_vec();
+        self.costs = costs.to_vec();
+        self.epoch = epoch;
+    }
+
+    /// Discard records born before the retention window, except those of the current population
+    /// and the best genome.
+    fn prune(&mut self) {
+        let oldest = self.epoch.saturating_sub(self.retention - 1);
+        let keep: HashSet<usize> = self.ids.iter().copied().chain(self.best).collect();
+        self.births
+            .retain(|id, birth| birth.epoch() >= oldest || keep.contains(id));
+    }
+}
+
+impl<G> Observer<G> for Genealogy<G>
+where
+    G: Clone + PartialEq,
+{
+    fn evaluated(&mut self, epoch: usize, population: &[G], costs: &[f64], _violations: &[f64]) {
+        self.inner
+            .lock()
+            .unwrap()
+            .identify(epoch, population, costs);
+    }
+
+    fn child_created(&mut self, epoch: usize, child: &G, parents: (usize, usize), mutated: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        let mut parent_ids: Vec<usize> = [parents.0, parents.1]
+            .iter()
+            .filter_map(|i| inner.ids.get(*i).copied())
+            .collect();
+        parent_ids.dedup();
+        let id = inner.next_id();
+        inner.children.push(Child {
+            genome: child.clone(),
+            birth: Birth::new(id, parent_ids, mutated, epoch),
+            cost: None,
+        });
+    }
+
+    fn child_evaluated(&mut self, _epoch: usize, child: &G, outcome: &ChildOutcome) {
+        let mut inner = self.inner.lock().unwrap();
+        let index = inner.evaluated_children;
+        if let Some(created) = inner.children.get_mut(index) {
+            if created.genome == *child {
+                created.cost = Some(outcome.cost());
+            }
+        }
+        inner.evaluated_children += 1;
+    }
+
+    fn epoch_ended(&mut self, snapshot: &ProgressSnapshot<G>) {
+        let mut inner = self.inner.lock().unwrap();
+        if snapshot.stagnation() == 0 || inner.best.is_none() {
+            let best = std::iter::zip(&inner.genomes, &inner.ids)
+                .find(|(genome, _)| *genome == snapshot.best_genome())
+                .map(|(_, id)| *id);
+            if best.is_some() {
+                inner.best = best;
+            }
+        }
+        inner.prune();
+    }
+}
+
+/// A child created in the current epoch, with its cost once it has been evaluated.
+struct Child<G> {
+    genome: G,
+    birth: Birth,
+    cost: Option<f64>,
+}
+
+/// The genomes that may be matched to the population, indexed by cost so each genome is only
+/// compared with the candidates at the same position or with the same cost.
+struct Candidates {
+    by_cost: HashMap<Option<u64>, VecDeque<usize>>,
+    taken: Vec<bool>,
+}
+
+impl Candidates {
+    fn new(costs: impl Iterator<Item = Option<f64>>) -> Self {
+        let mut by_cost: HashMap<Option<u64>, VecDeque<usize>> = HashMap::new();
+        let mut count = 0;
+        for (i, cost) in costs.enumerate() {
+            by_cost
+                .entry(cost.map(f64::to_bits))
+                .or_default()
+                .push_back(i);
+            count += 1;
+        }
+        Self {
+            by_cost,
+            taken: vec![false; count],
+        }
+    }
+
+    /// Take the first candidate that `matches`, trying `position` first, then the candidates
+    /// with `cost` and then those without a cost.
+    fn take(
+        &mut self,
+        position: usize,
+        cost: f64,
+        matches: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let found = Some(position)
+            .filter(|j| *j < self.taken.len() && !self.taken[*j] && matches(*j))
+            .or_else(|| self.find(Some(cost.to_bits()), &matches))
+            .or_else(|| self.find(None, &matches));
+        if let Some(j) = found {
+            self.taken[j] = true;
+        }
+        found
+    }
+
+    fn find(&mut self, key: Option<u64>, matches: &impl Fn(usize) -> bool) -> Option<usize> {
+        let candidates = self.by_cost.get_mut(&key)?;
+        while candidates.front().is_some_and(|j| self.taken[*j]) {
+            candidates.pop_front();
+        }
+        candidates
+            .iter()
+            .copied()
+            .find(|j| !self.taken[*j] && matches(*j))
+    }
+}
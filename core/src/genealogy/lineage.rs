@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// The record of how a genome was created.
+/// The cost is reported in the orientation of [`crate::SearchSettings::objective`].
+///
+/// See [`crate::Genealogy`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Birth {
+    id: usize,
+    parents: Vec<usize>,
+    mutated: bool,
+    epoch: usize,
+    cost: f64,
+}
+
+impl Birth {
+    pub(crate) fn new(id: usize, parents: Vec<usize>, mutated: bool, epoch: usize) -> Self {
+        Self {
+            id,
+            parents,
+            mutated,
+            epoch,
+            cost: f64::NAN,
+        }
+    }
+
+    pub(crate) fn with_cost(mut self, cost: f64) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    /// The identifier of the genome, unique within a search.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The identifiers of the parents, in the order they were passed to
+    /// [`crate::Genetic::crossover`].
+    /// This has one parent when the genome was not created by crossover, and is empty for
+    /// genomes created by [`crate::Genetic::initialize`] or by strategies that do not report
+    /// their children.
+    pub fn parents(&self) -> &[usize] {
+        &self.parents
+    }
+
+    /// True if the genome was mutated after crossover.
+    pub fn mutated(&self) -> bool {
+        self.mutated
+    }
+
+    /// The epoch in which the genome was born.
+    pub fn epoch(&self) -> usize {
+        self.epoch
+    }
+
+    /// The cost of the genome when it was first evaluated.
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+}
+
+/// A genome and those of its ancestors still retained by a [`crate::Genealogy`], oldest first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lineage {
+    births: Vec<Birth>,
+}
+
+impl Lineage {
+    pub(crate) fn new(births: Vec<Birth>) -> Self {
+        Self { births }
+    }
+
+    /// The genome and its ancestors, oldest first.
+    pub fn births(&self) -> &[Birth] {
+        &self.births
+    }
+
+    /// Return the lineage as a Graphviz DOT graph, with an edge from each parent to its child.
+    /// Edges are labelled with the crossover order of the parent and mutated genomes are
+    /// drawn as boxes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph lineage {\n");
+        for birth in &self.births {
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}\\nepoch {}\\ncost {}\", shape={}];",
+                birth.id,
+                birth.id,
+                birth.epoch,
+                birth.cost,
+                if birth.mutated { "box" } else { "ellipse" }
+            );
+        }
+        let ids: HashSet<usize> = self.births.iter().map(|birth| birth.id).collect();
+        for birth in &self.births {
+            for (order, parent) in birth.parents.iter().enumerate() {
+                if ids.contains(parent) {
+                    let _ = writeln!(
+                        dot,
+                        "    {} -> {} [label=\"{}\"];",
+                        parent,
+                        birth.id,
+                        order + 1
+                    );
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Return the lineage as a JSON object with a `nodes` array, oldest first.
+    /// Each node has an `id`, `parents`, `mutated`, `epoch` and `cost`, which is `null` when it
+    /// is not finite.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"nodes\":[");
+        for (i, birth) in self.births.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let parents: Vec<String> = birth.parents.iter().map(|p| p.to_string()).collect();
+            let cost = if birth.cost.is_finite() {
+                birth.cost.to_string()
+            } else {
+                "null".to_string()
+            };
+            let _ = write!(
+                json,
+                "{{\"id\":{},\"parents\":[{}],\"mutated\":{},\"epoch\":{},\"cost\":{}}}",
+                birth.id,
+                parents.join(","),
+                birth.mutated,
+                birth.epoch,
+                cost
+            );
+        }
+        json.push_str("]}");
+        json
+    }
+}
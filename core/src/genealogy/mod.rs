@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod genealogy;
+mod lineage;
+
+pub use genealogy::Genealogy;
+pub use lineage::{Birth, Lineage};
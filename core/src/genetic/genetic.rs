@@ -1,3 +1,4 @@
+use crate::{ChildOutcome, Distance, LocalSearch};
 use std::fmt::Debug;
 
 /// Define the genetic operations on a chromosome `G`.
@@ -16,4 +17,69 @@ where
 
     /// Return a randomly mutated copy of a genome.
     fn mutate(&self, genome: &G) -> G;
+
+    /// Return a randomly mutated copy of a genome, where `step_size` scales the size of the
+    /// mutation.
+    /// This is used by evolution strategies with self-adaptive step sizes.
+    /// The default implementation ignores the step size and calls
+    /// [`mutate()`](crate::Genetic#mutate()).
+    ///
+    /// See [`crate::EvolutionSettings::self_adaptive`].
+    fn mutate_scaled(&self, genome: &G, _step_size: f64) -> G {
+        self.mutate(genome)
+    }
+
+    /// Evaluate how much the genome violates the constraints of the problem, separately from
+    /// its cost.
+    /// Return 0 for a feasible genome and a positive amount otherwise, typically the sum of the
+    /// amounts by which each constraint is violated.
+    /// The default implementation has no constraints.
+    ///
+    /// See [`crate::ConstraintSelector`].
+    fn violation(&self, _genome: &G) -> f64 {
+        0.0
+    }
+
+    /// Return a genome with its constraint violations repaired, where possible.
+    /// This is applied to every new genome after crossover and mutation.
+    /// The default implementation returns the genome unchanged.
+    fn repair(&self, genome: G) -> G {
+        genome
+    }
+
+    /// Return the measure of how different two genomes are, if there is one.
+    /// When present, the diversity of the population is measured every epoch and reported in
+    /// [`crate::ProgressSnapshot::diversity`] and [`crate::Success::diversity`].
+    /// The default implementation returns `None`, so diversity is not measured.
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        None
+    }
+
+    /// Return the local search used to improve new genomes, if there is one.
+    /// It is applied when the search settings enable memetic search.
+    /// The default implementation returns `None`.
+    ///
+    /// See [`crate::MemeticSettings`].
+    fn local_search(&self) -> Option<&dyn LocalSearch<G>> {
+        None
+    }
+
+    /// Called for every child once the crossover, mutation and repair that created it are done,
+    /// before the next child is created.
+    /// Children are passed to [`Genetic::child_evaluated`] later, in the same order.
+    /// Only strategies that report their children call it, see
+    /// [`crate::Observer::child_created`].
+    /// The default implementation does nothing.
+    ///
+    /// See [`crate::Operators`].
+    fn child_created(&self, _child: &G) {}
+
+    /// Called for every child after it has been evaluated, so that genetic operations can adapt
+    /// to how well earlier children turned out.
+    /// Only strategies that report their children call it, see
+    /// [`crate::Observer::child_evaluated`].
+    /// The default implementation does nothing.
+    ///
+    /// See [`crate::Operators`].
+    fn child_evaluated(&self, _child: &G, _outcome: &ChildOutcome) {}
 }
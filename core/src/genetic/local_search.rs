@@ -0,0 +1,8 @@
+/// Define a local search that improves individual genomes, for memetic algorithms.
+///
+/// See [`crate::Genetic::local_search`] and [`crate::MemeticSettings`].
+pub trait LocalSearch<G>: Send + Sync {
+    /// Return an improved copy of the genome, for example the result of hill climbing from it.
+    /// The returned genome should cost no more than the original.
+    fn improve(&self, genome: &G) -> G;
+}
@@ -0,0 +1,141 @@
+use crate::{ChildOutcome, Distance, Genetic, LocalSearch};
+use std::fmt::Debug;
+
+/// Define the genetic operations on a chromosome `G` through the genetic operations on another
+/// representation `H`, converting genomes back and forth.
+///
+/// Every operation converts its arguments to `H`, applies the wrapped genetic operations and
+/// converts the result back to `G`, so the cost and violation are those of the converted
+/// genome.
+/// The distance is measured between converted genomes, and local search improves the converted
+/// genome with the local search of the wrapped genetic operations.
+///
+/// See [`crate::PairGenetic`].
+pub struct MappedGenetic<G, H> {
+    mapping: Mapping<G, H>,
+    from: Box<dyn Fn(H) -> G + Send + Sync>,
+}
+
+/// The genetic operations on `H` and the conversion to it.
+struct Mapping<G, H> {
+    genetic: Box<dyn Genetic<H> + Send + Sync>,
+    into: Box<dyn Fn(&G) -> H + Send + Sync>,
+}
+
+impl<G, H> MappedGenetic<G, H> {
+    /// Create genetic operations on `G` from those on `H`.
+    ///
+    /// # Arguments
+    ///
+    /// * `genetic` - Define the genetic operations on the representation `H`.
+    /// * `into` - Convert a genome to the representation `H`.
+    /// * `from` - Convert a genome from the representation `H`.
+    ///
+    pub fn new(
+        genetic: Box<dyn Genetic<H> + Send + Sync>,
+        into: impl Fn(&G) -> H + Send + Sync + 'static,
+        from: impl Fn(H) -> G + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            mapping: Mapping {
+                genetic,
+                into: Box::new(into),
+            },
+            from: Box::new(from),
+        }
+    }
+
+    fn to_inner(&self, genome: &G) -> H {
+        (self.mapping.into)(genome)
+    }
+
+    fn genetic(&self) -> &(dyn Genetic<H> + Send + Sync) {
+        self.mapping.genetic.as_ref()
+    }
+}
+
+impl<G, H> Genetic<G> for MappedGenetic<G, H>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+    H: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> G {
+        (self.from)(self.genetic().initialize())
+    }
+
+    fn evaluate(&self, genome: &G) -> f64 {
+        self.genetic().evaluate(&self.to_inner(genome))
+    }
+
+    fn crossover(&self, lhs: &G, rhs: &G) -> G {
+        (self.from)(
+            self.genetic()
+                .crossover(&self.to_inner(lhs), &self.to_inner(rhs)),
+        )
+    }
+
+    fn mutate(&self, genome: &G) -> G {
+        (self.from)(self.genetic().mutate(&self.to_inner(genome)))
+    }
+
+    fn mutate_scaled(&self, genome: &G, step_size: f64) -> G {
+        (self.from)(
+            self.genetic()
+                .mutate_scaled(&self.to_inner(genome), step_size),
+        )
+    }
+
+    fn violation(&self, genome: &G) -> f64 {
+        self.genetic().violation(&self.to_inner(genome))
+    }
+
+    fn repair(&self, genome: G) -> G {
+        (self.from)(self.genetic().repair(self.to_inner(&genome)))
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        self.genetic()
+            .distance()
+            .map(|_| &self.mapping as &dyn Distance<G>)
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<G>> {
+        self.genetic()
+            .local_search()
+            .map(|_| self as &dyn LocalSearch<G>)
+    }
+
+    fn child_created(&self, child: &G) {
+        self.genetic().child_created(&self.to_inner(child))
+    }
+
+    fn child_evaluated(&self, child: &G, outcome: &ChildOutcome) {
+        self.genetic()
+            .child_evaluated(&self.to_inner(child), outcome)
+    }
+}
+
+impl<G, H> LocalSearch<G> for MappedGenetic<G, H>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+    H: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn improve(&self, genome: &G) -> G {
+        match self.genetic().local_search() {
+            Some(local_search) => (self.from)(local_search.improve(&self.to_inner(genome))),
+            None => genome.clone(),
+        }
+    }
+}
+
+impl<G, H> Distance<G> for Mapping<G, H>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+    H: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn distance(&self, lhs: &G, rhs: &G) -> f64 {
+        self.genetic.distance().map_or(0.0, |distance| {
+            distance.distance(&(self.into)(lhs), &(self.into)(rhs))
+        })
+    }
+}
@@ -1,3 +1,14 @@
+#[allow(clippy::module_inception)]
 mod genetic;
+mod local_search;
+mod mapped;
+mod pair;
+mod seeded;
+mod vector;
 
 pub use genetic::Genetic;
+pub use local_search::LocalSearch;
+pub use mapped::MappedGenetic;
+pub use pair::PairGenetic;
+pub use seeded::SeededGenetic;
+pub use vector::VecGenetic;
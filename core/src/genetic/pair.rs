@@ -0,0 +1,146 @@
+use crate::{ChildOutcome, Distance, Genetic, LocalSearch};
+use rand::Rng;
+use std::fmt::Debug;
+
+type Evaluate<A, B> = Box<dyn Fn(&(A, B)) -> f64 + Send + Sync>;
+
+/// Combine the genetic operations on two independent parts `A` and `B` into genetic operations
+/// on the pair `(A, B)`, so that only the cost of the combined genome has to be supplied.
+///
+/// Initialization, crossover and repair are applied to each part separately.
+/// Mutation changes one part, chosen at random, and keeps the other.
+/// The violation of a pair is the sum of the violations of its parts, and its distance is the
+/// sum of the distances of its parts when both parts have one.
+/// The costs of the parts are never evaluated.
+/// Local search is not supported, because the local search of a part would improve the cost of
+/// that part rather than the cost of the pair.
+///
+/// Nest pairs for more than two parts, and use [`crate::MappedGenetic`] to convert a pair to
+/// and from a struct.
+pub struct PairGenetic<A, B> {
+    parts: Parts<A, B>,
+    evaluate: Evaluate<A, B>,
+}
+
+/// The genetic operations on each part of a pair.
+struct Parts<A, B> {
+    first: Box<dyn Genetic<A> + Send + Sync>,
+    second: Box<dyn Genetic<B> + Send + Sync>,
+}
+
+impl<A, B> PairGenetic<A, B> {
+    /// Create genetic operations on a pair of genomes.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - Define the genetic operations on the first part of the pair.
+    /// * `second` - Define the genetic operations on the second part of the pair.
+    /// * `evaluate` - Evaluate the cost of the pair.
+    ///
+    pub fn new(
+        first: Box<dyn Genetic<A> + Send + Sync>,
+        second: Box<dyn Genetic<B> + Send + Sync>,
+        evaluate: impl Fn(&(A, B)) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            parts: Parts { first, second },
+            evaluate: Box::new(evaluate),
+        }
+    }
+}
+
+impl<A, B> Genetic<(A, B)> for PairGenetic<A, B>
+where
+    A: Clone + Debug + PartialEq + Send + Sync,
+    B: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> (A, B) {
+        (
+            self.parts.first.initialize(),
+            self.parts.second.initialize(),
+        )
+    }
+
+    fn evaluate(&self, genome: &(A, B)) -> f64 {
+        (self.evaluate)(genome)
+    }
+
+    fn crossover(&self, lhs: &(A, B), rhs: &(A, B)) -> (A, B) {
+        (
+            self.parts.first.crossover(&lhs.0, &rhs.0),
+            self.parts.second.crossover(&lhs.1, &rhs.1),
+        )
+    }
+
+    fn mutate(&self, genome: &(A, B)) -> (A, B) {
+        if rand::thread_rng().gen_bool(0.5) {
+            (self.parts.first.mutate(&genome.0), genome.1.clone())
+        } else {
+            (genome.0.clone(), self.parts.second.mutate(&genome.1))
+        }
+    }
+
+    fn mutate_scaled(&self, genome: &(A, B), step_size: f64) -> (A, B) {
+        if rand::thread_rng().gen_bool(0.5) {
+            (
+                self.parts.first.mutate_scaled(&genome.0, step_size),
+                genome.1.clone(),
+            )
+        } else {
+            (
+                genome.0.clone(),
+                self.parts.second.mutate_scaled(&genome.1, step_size),
+            )
+        }
+    }
+
+    fn violation(&self, genome: &(A, B)) -> f64 {
+        self.parts.first.violation(&genome.0) + self.parts.second.violation(&genome.1)
+    }
+
+    fn repair(&self, genome: (A, B)) -> (A, B) {
+        (
+            self.parts.first.repair(genome.0),
+            self.parts.second.repair(genome.1),
+        )
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<(A, B)>> {
+        match (self.parts.first.distance(), self.parts.second.distance()) {
+            (Some(_), Some(_)) => Some(&self.parts),
+            _ => None,
+        }
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<(A, B)>> {
+        None
+    }
+
+    fn child_created(&self, child: &(A, B)) {
+        self.parts.first.child_created(&child.0);
+        self.parts.second.child_created(&child.1);
+    }
+
+    fn child_evaluated(&self, child: &(A, B), outcome: &ChildOutcome) {
+        self.parts.first.child_evaluated(&child.0, outcome);
+        self.parts.second.child_evaluated(&child.1, outcome);
+    }
+}
+
+impl<A, B> Distance<(A, B)> for Parts<A, B>
+where
+    A: Clone + Debug + PartialEq + Send + Sync,
+    B: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn distance(&self, lhs: &(A, B), rhs: &(A, B)) -> f64 {
+        let first = self
+            .first
+            .distance()
+            .map_or(0.0, |d| d.distance(&lhs.0, &rhs.0));
+        let second = self
+            .second
+            .distance()
+            .map_or(0.0, |d| d.distance(&lhs.1, &rhs.1));
+        first + second
+    }
+}
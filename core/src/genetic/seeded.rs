@@ -0,0 +1,86 @@
+use crate::{ChildOutcome, Distance, Genetic, LocalSearch};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Wrap the genetic operations on a chromosome `G` so that a search starts from given genomes,
+/// for example the final population of an earlier search.
+///
+/// [`initialize()`](crate::Genetic#initialize()) returns each seed genome in turn and then
+/// falls back to the wrapped genetic operations.
+/// Every other operation is delegated.
+///
+/// See [`crate::Success::into_population`].
+pub struct SeededGenetic<G> {
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    seeds: Vec<G>,
+    next: AtomicUsize,
+}
+
+impl<G> SeededGenetic<G> {
+    /// Create genetic operations that initialize genomes from `seeds` first.
+    ///
+    /// # Arguments
+    ///
+    /// * `genetic` - Define the genetic operations on a chromosome `G`.
+    /// * `seeds` - The genomes returned first by [`initialize()`](crate::Genetic#initialize()).
+    ///
+    pub fn new(genetic: Box<dyn Genetic<G> + Send + Sync>, seeds: Vec<G>) -> Self {
+        Self {
+            genetic,
+            seeds,
+            next: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<G> Genetic<G> for SeededGenetic<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> G {
+        match self.seeds.get(self.next.fetch_add(1, Ordering::Relaxed)) {
+            Some(seed) => seed.clone(),
+            None => self.genetic.initialize(),
+        }
+    }
+
+    fn evaluate(&self, genome: &G) -> f64 {
+        self.genetic.evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &G, rhs: &G) -> G {
+        self.genetic.crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, genome: &G) -> G {
+        self.genetic.mutate(genome)
+    }
+
+    fn mutate_scaled(&self, genome: &G, step_size: f64) -> G {
+        self.genetic.mutate_scaled(genome, step_size)
+    }
+
+    fn violation(&self, genome: &G) -> f64 {
+        self.genetic.violation(genome)
+    }
+
+    fn repair(&self, genome: G) -> G {
+        self.genetic.repair(genome)
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        self.genetic.distance()
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<G>> {
+        self.genetic.local_search()
+    }
+
+    fn child_created(&self, child: &G) {
+        self.genetic.child_created(child)
+    }
+
+    fn child_evaluated(&self, child: &G, outcome: &ChildOutcome) {
+        self.genetic.child_evaluated(child, outcome)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{ChildOutcome, Distance, Failure, Genetic, LocalSearch};
+use rand::Rng;
+use std::fmt::Debug;
+
+type Evaluate<T> = Box<dyn Fn(&[T]) -> f64 + Send + Sync>;
+
+/// Lift the genetic operations on a single gene `T` into genetic operations on a fixed-length
+/// vector of genes, so that only the cost of the whole vector has to be supplied.
+///
+/// Crossover combines the genes at each position with the gene crossover, so a gene crossover
+/// that returns either argument at random gives uniform crossover.
+/// Mutation changes each gene with a given probability, and always at least one gene.
+/// Repair is applied to each gene, the violation is the sum of the gene violations and the
+/// distance is the sum of the gene distances, when the gene has one.
+/// The costs of single genes are never evaluated.
+/// Local search is not supported, because the local search of a gene would improve the cost of
+/// that gene rather than the cost of the vector.
+/// Each gene of a child is reported to the gene operations as a child with the outcome of the
+/// whole vector, so that they can adapt, see [`crate::Operators`].
+pub struct VecGenetic<T> {
+    gene: Gene<T>,
+    length: usize,
+    mutation_rate: f64,
+    evaluate: Evaluate<T>,
+}
+
+/// The genetic operations on a single gene.
+struct Gene<T> {
+    genetic: Box<dyn Genetic<T> + Send + Sync>,
+}
+
+impl<T> VecGenetic<T> {
+    /// Create genetic operations on vectors of genes.
+    ///
+    /// # Arguments
+    ///
+    /// * `gene` - Define the genetic operations on a single gene.
+    /// * `length` - The number of genes in a vector. Must be at least 1.
+    /// * `mutation_rate` - The probability that each gene is mutated, in [0, 1].
+    /// * `evaluate` - Evaluate the cost of a vector of genes.
+    ///
+    pub fn new(
+        gene: Box<dyn Genetic<T> + Send + Sync>,
+        length: usize,
+        mutation_rate: f64,
+        evaluate: impl Fn(&[T]) -> f64 + Send + Sync + 'static,
+    ) -> Result<Self, Failure> {
+        if length < 1 || !(0.0..=1.0).contains(&mutation_rate) {
+            return Err(Failure::combinator_setting());
+        }
+
+        Ok(Self {
+            gene: Gene { genetic: gene },
+            length,
+            mutation_rate,
+            evaluate: Box::new(evaluate),
+        })
+    }
+
+    /// The number of genes in a vector.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The probability that each gene is mutated.
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+}
+
+impl<T> VecGenetic<T>
+where
+    T: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn mutate_with(&self, genome: &[T], mutate: impl Fn(&T) -> T) -> Vec<T> {
+        let mut rng = rand::thread_rng();
+        let mut mutated = genome.to_vec();
+        let mut changed = false;
+        for gene in mutated.iter_mut() {
+            if rng.gen_bool(self.mutation_rate) {
+                *gene = mutate(gene);
+                changed = true;
+            }
+        }
+        if !changed && !mutated.is_empty() {
+            let index = rng.gen_range(0..mutated.len());
+            mutated[index] = mutate(&mutated[index]);
+        }
+        mutated
+    }
+}
+
+impl<T> Genetic<Vec<T>> for VecGenetic<T>
+where
+    T: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> Vec<T> {
+        (0..self.length)
+            .map(|_| self.gene.genetic.initialize())
+            .collect()
+    }
+
+    fn evaluate(&self, genome: &Vec<T>) -> f64 {
+        (self.evaluate)(genome)
+    }
+
+    fn crossover(&self, lhs: &Vec<T>, rhs: &Vec<T>) -> Vec<T> {
+        lhs.iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| self.gene.genetic.crossover(lhs, rhs))
+            .collect()
+    }
+
+    fn mutate(&self, genome: &Vec<T>) -> Vec<T> {
+        self.mutate_with(genome, |gene| self.gene.genetic.mutate(gene))
+    }
+
+    fn mutate_scaled(&self, genome: &Vec<T>, step_size: f64) -> Vec<T> {
+        self.mutate_with(genome, |gene| {
+            self.gene.genetic.mutate_scaled(gene, step_size)
+        })
+    }
+
+    fn violation(&self, genome: &Vec<T>) -> f64 {
+        genome
+            .iter()
+            .map(|gene| self.gene.genetic.violation(gene))
+            .sum()
+    }
+
+    fn repair(&self, genome: Vec<T>) -> Vec<T> {
+        genome
+            .into_iter()
+            .map(|gene| self.gene.genetic.repair(gene))
+            .collect()
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<Vec<T>>> {
+        self.gene
+            .genetic
+            .distance()
+            .map(|_| &self.gene as &dyn Distance<Vec<T>>)
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<Vec<T>>> {
+        None
+    }
+
+    fn child_created(&self, child: &Vec<T>) {
+        for gene in child {
+            self.gene.genetic.child_created(gene);
+        }
+    }
+
+    fn child_evaluated(&self, child: &Vec<T>, outcome: &ChildOutcome) {
+        for gene in child {
+            self.gene.genetic.child_evaluated(gene, outcome);
+        }
+    }
+}
+
+impl<T> Distance<Vec<T>> for Gene<T>
+where
+    T: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn distance(&self, lhs: &Vec<T>, rhs: &Vec<T>) -> f64 {
+        self.genetic.distance().map_or(0.0, |distance| {
+            lhs.iter()
+                .zip(rhs)
+                .map(|(lhs, rhs)| distance.distance(lhs, rhs))
+                .sum()
+        })
+    }
+}
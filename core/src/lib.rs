@@ -2,14 +2,36 @@
 // #![doc = include_str!("../README.md")]
 
 //! See [README.md](https://github.com/thomasbratt/watchmaker/blob/main/core/README.md) for a description
+mod cma;
 mod common;
+mod differential;
+mod diversity;
+mod evolution;
+mod genealogy;
 mod genetic;
+mod niching;
+mod operators;
+mod regression;
 mod search;
 mod selector;
 mod settings;
+mod strategy;
+mod terminator;
+mod tree;
 
+pub use cma::*;
 pub use common::*;
+pub use differential::*;
+pub use diversity::*;
+pub use evolution::*;
+pub use genealogy::*;
 pub use genetic::*;
+pub use niching::*;
+pub use operators::*;
+pub use regression::*;
 pub use search::*;
 pub use selector::*;
 pub use settings::*;
+pub use strategy::*;
+pub use terminator::*;
+pub use tree::*;
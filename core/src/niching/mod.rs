@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod niching;
+
+pub use niching::{Niching, NichingMethod};
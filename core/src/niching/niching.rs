@@ -0,0 +1,222 @@
+use crate::{compare_costs, Distance, Failure};
+use rand::Rng;
+use std::sync::Arc;
+
+type DistanceFn<G> = Arc<dyn Distance<G> + Send + Sync>;
+
+/// Define the niching method used to maintain several distinct solutions in the population.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NichingMethod {
+    /// Fitness sharing (Goldberg and Richardson).
+    /// The fitness of each genome is divided by the number of genomes within the radius,
+    /// weighted by `1 - (distance / radius) ^ alpha`, before partners are selected.
+    Sharing { alpha: f64 },
+
+    /// Clearing (Pétrowski).
+    /// Within each niche of the given radius only the best `capacity` genomes keep their cost
+    /// when partners are selected; the rest are ranked after every genome that was not cleared.
+    Clearing { capacity: usize },
+
+    /// Deterministic crowding (Mahfoud).
+    /// Each new genome competes with the more similar of its parents and replaces it only if
+    /// it is better.
+    DeterministicCrowding,
+
+    /// Probabilistic crowding (Mengshoel and Goldberg).
+    /// Each new genome competes with the more similar of its parents and replaces it with
+    /// probability `p / (p + c)`, where `p` and `c` are the costs of the parent and new genome
+    /// shifted so that the lower of the two is 1.
+    ProbabilisticCrowding,
+}
+
+/// Define how the population is divided into niches, for multimodal problems.
+///
+/// See [`crate::search_niching`].
+pub struct Niching<G> {
+    method: NichingMethod,
+    radius: f64,
+    representatives: bool,
+    distance: DistanceFn<G>,
+}
+
+impl<G> Niching<G> {
+    /// Create new niching settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The niching method.
+    /// * `radius` - Genomes closer than this distance are in the same niche.
+    /// * `representatives` - When true, the result of the search includes the best genome of
+    ///   each niche in the final population.
+    /// * `distance` - Measure how different two genomes are.
+    ///   This may be a [`Distance`] implementation or a closure.
+    pub fn new(
+        method: NichingMethod,
+        radius: f64,
+        representatives: bool,
+        distance: impl Distance<G> + Send + Sync + 'static,
+    ) -> Result<Self, Failure> {
+        let valid = radius > 0.0
+            && match method {
+                NichingMethod::Sharing { alpha } => alpha > 0.0,
+                NichingMethod::Clearing { capacity } => capacity >= 1,
+                NichingMethod::DeterministicCrowding | NichingMethod::ProbabilisticCrowding => true,
+            };
+        if !valid {
+            return Err(Failure::niching_setting());
+        }
+        Ok(Self {
+            method,
+            radius,
+            representatives,
+            distance: Arc::new(distance),
+        })
+    }
+
+    /// The niching method.
+    pub fn method(&self) -> NichingMethod {
+        self.method
+    }
+
+    /// Genomes closer than this distance are in the same niche.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// True when the result of the search includes the best genome of each niche.
+    pub fn representatives(&self) -> bool {
+        self.representatives
+    }
+
+    /// Measure how different two genomes are.
+    pub fn distance(&self, lhs: &G, rhs: &G) -> f64 {
+        self.distance.distance(lhs, rhs)
+    }
+
+    /// The measure of how different two genomes are.
+    pub(crate) fn measure(&self) -> &dyn Distance<G> {
+        self.distance.as_ref()
+    }
+
+    /// True when the method replaces parents with their offspring, rather than adjusting the
+    /// costs used for selection.
+    pub(crate) fn is_crowding(&self) -> bool {
+        matches!(
+            self.method,
+            NichingMethod::DeterministicCrowding | NichingMethod::ProbabilisticCrowding
+        )
+    }
+
+    /// Adjust costs for partner selection, according to the sharing or clearing method.
+    /// The range of costs is taken over finite costs only, so that an infinite or NaN cost does
+    /// not make every adjusted cost infinite or NaN.
+    pub(crate) fn adjust(&self, population: &[G], costs: &[f64], adjusted: &mut Vec<f64>) {
+        adjusted.clear();
+        adjusted.extend_from_slice(costs);
+        let finite = || costs.iter().copied().filter(|cost| cost.is_finite());
+        let lowest = finite()
+            .min_by(|lhs, rhs| compare_costs(*lhs, *rhs))
+            .unwrap_or(0.0);
+        let highest = finite()
+            .max_by(|lhs, rhs| compare_costs(*lhs, *rhs))
+            .unwrap_or(0.0);
+        match self.method {
+            NichingMethod::Sharing { alpha } => {
+                for (i, lhs) in population.iter().enumerate() {
+                    if !costs[i].is_finite() {
+                        adjusted[i] = adjusted_non_finite(costs[i]);
+                        continue;
+                    }
+                    let niche_count: f64 = population
+                        .iter()
+                        .map(|rhs| self.distance(lhs, rhs))
+                        .filter(|d| *d < self.radius)
+                        .map(|d| 1.0 - (d / self.radius).powf(alpha))
+                        .sum();
+                    let fitness = highest - costs[i];
+                    adjusted[i] = -fitness / niche_count.max(1.0);
+                }
+            }
+            NichingMethod::Clearing { capacity } => {
+                let offset = highest - lowest + 1.0;
+                let order = sorted_by_cost(costs);
+                let mut cleared = vec![false; costs.len()];
+                for (position, i) in order.iter().enumerate() {
+                    if cleared[*i] {
+                        continue;
+                    }
+                    if !costs[*i].is_finite() {
+                        adjusted[*i] = adjusted_non_finite(costs[*i]);
+                    }
+                    let mut winners = 1;
+                    for j in &order[position + 1..] {
+                        if !cleared[*j]
+                            && self.distance(&population[*i], &population[*j]) < self.radius
+                        {
+                            if winners < capacity {
+                                winners += 1;
+                            } else {
+                                cleared[*j] = true;
+                                adjusted[*j] = if costs[*j].is_finite() {
+                                    costs[*j] + offset
+                                } else {
+                                    f64::INFINITY
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+            NichingMethod::DeterministicCrowding | NichingMethod::ProbabilisticCrowding => {}
+        }
+    }
+
+    /// True when `child` should replace `parent` under the crowding method.
+    pub(crate) fn replaces(&self, child_cost: f64, parent_cost: f64) -> bool {
+        match self.method {
+            NichingMethod::ProbabilisticCrowding => {
+                let lowest = child_cost.min(parent_cost);
+                let child = child_cost - lowest + 1.0;
+                let parent = parent_cost - lowest + 1.0;
+                let probability = parent / (parent + child);
+                if probability.is_finite() {
+                    rand::thread_rng().gen_bool(probability)
+                } else {
+                    child_cost < parent_cost
+                }
+            }
+            _ => child_cost < parent_cost,
+        }
+    }
+
+    /// Return the indices of the best genome in each niche, best first.
+    /// Each representative is at least `radius` from every better representative.
+    pub(crate) fn representatives_of(&self, population: &[G], costs: &[f64]) -> Vec<usize> {
+        let mut result: Vec<usize> = Vec::new();
+        for i in sorted_by_cost(costs) {
+            if result
+                .iter()
+                .all(|r| self.distance(&population[*r], &population[i]) >= self.radius)
+            {
+                result.push(i);
+            }
+        }
+        result
+    }
+}
+
+/// The adjusted cost of a genome with a non-finite cost: the worst value, unless the cost is
+/// negative infinity, which remains the best.
+fn adjusted_non_finite(cost: f64) -> f64 {
+    if cost == f64::NEG_INFINITY {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    }
+}
+
+fn sorted_by_cost(costs: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..costs.len()).collect();
+    order.sort_by(|a, b| compare_costs(costs[*a], costs[*b]));
+    order
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod operators;
+mod selection;
+
+pub use operators::{OperatorMonitor, OperatorReport, Operators};
+pub use selection::OperatorSelection;
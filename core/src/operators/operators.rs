@@ -0,0 +1,350 @@
+use crate::operators::selection::Pool;
+use crate::{
+    ChildOutcome, Distance, Failure, Genetic, LocalSearch, OperatorSelection, OperatorStatistics,
+};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+/// The most operator choices remembered while waiting for children to be created or evaluated.
+/// Strategies that do not report their children would otherwise grow the records forever.
+/// Older choices are discarded and counted, see [`OperatorMonitor::discarded`].
+const PENDING_LIMIT: usize = 4096;
+
+type Crossover<G> = Box<dyn Fn(&G, &G) -> G + Send + Sync>;
+type Mutation<G> = Box<dyn Fn(&G) -> G + Send + Sync>;
+
+/// Wrap the genetic operations on a chromosome `G` with several named crossover and mutation
+/// operators, choosing one of each kind for every child.
+///
+/// Operators are chosen according to an [`OperatorSelection`], which can shift the weights
+/// towards the operators that produce improvements.
+/// Outcomes are received through [`crate::Genetic::child_evaluated`], so adaptation only
+/// happens with strategies that report their children, see
+/// [`crate::Observer::child_evaluated`].
+/// The operators applied since the previous child was reported to
+/// [`crate::Genetic::child_created`] are credited with the outcome of the next child reported,
+/// so strategies must create their children one at a time.
+///
+/// When no operator of a kind is registered, the wrapped crossover or mutation is used.
+/// Every other operation is delegated.
+/// Use [`monitor()`](Operators::monitor) to read the probabilities and statistics of the
+/// operators during or after the search.
+pub struct Operators<G> {
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    selection: OperatorSelection,
+    crossovers: Vec<Crossover<G>>,
+    crossover_weights: Vec<f64>,
+    mutations: Vec<Mutation<G>>,
+    mutation_weights: Vec<f64>,
+    state: Arc<Mutex<State>>,
+    pending: Mutex<Pending>,
+}
+
+/// The operators applied to the child being created and to the children that have not been
+/// evaluated yet.
+#[derive(Debug, Default)]
+struct Pending {
+    applied: Applied,
+    created: VecDeque<Applied>,
+}
+
+/// The indices of the operators applied to a child.
+#[derive(Debug, Default)]
+struct Applied {
+    crossovers: Vec<usize>,
+    mutations: Vec<usize>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    crossover_names: Vec<String>,
+    crossovers: Pool,
+    mutation_names: Vec<String>,
+    mutations: Pool,
+    discarded: usize,
+}
+
+impl<G> Operators<G> {
+    /// Create genetic operations without any registered operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `genetic` - Define the genetic operations on a chromosome `G`.
+    /// * `selection` - How the registered operators are chosen.
+    ///
+    pub fn new(
+        genetic: Box<dyn Genetic<G> + Send + Sync>,
+        selection: OperatorSelection,
+    ) -> Result<Self, Failure> {
+        selection.validate()?;
+        Ok(Self {
+            genetic,
+            selection,
+            crossovers: Vec::new(),
+            crossover_weights: Vec::new(),
+            mutations: Vec::new(),
+            mutation_weights: Vec::new(),
+            state: Arc::new(Mutex::new(State::default())),
+            pending: Mutex::new(Pending::default()),
+        })
+    }
+
+    /// Register a named crossover operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name the operator is reported under.
+    /// * `weight` - The initial weight of the operator. Must be finite and non-negative, and the
+    ///   weights of the crossover operators must not all be zero.
+    /// * `crossover` - Return a genome that is the combination of the two supplied as arguments.
+    ///
+    pub fn with_crossover(
+        mut self,
+        name: &str,
+        weight: f64,
+        crossover: impl Fn(&G, &G) -> G + Send + Sync + 'static,
+    ) -> Result<Self, Failure> {
+        validate_weight(&self.crossover_weights, weight)?;
+        self.crossover_weights.push(weight);
+        self.crossovers.push(Box::new(crossover));
+        let mut state = self.state.lock().unwrap();
+        state.crossover_names.push(name.to_string());
+        state.crossovers.push(&self.crossover_weights);
+        drop(state);
+        Ok(self)
+    }
+
+    /// Register a named mutation operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name the operator is reported under.
+    /// * `weight` - The initial weight of the operator. Must be finite and non-negative, and the
+    ///   weights of the mutation operators must not all be zero.
+    /// * `mutation` - Return a mutated copy of a genome.
+    ///
+    pub fn with_mutation(
+        mut self,
+        name: &str,
+        weight: f64,
+        mutation: impl Fn(&G) -> G + Send + Sync + 'static,
+    ) -> Result<Self, Failure> {
+        validate_weight(&self.mutation_weights, weight)?;
+        self.mutation_weights.push(weight);
+        self.mutations.push(Box::new(mutation));
+        let mut state = self.state.lock().unwrap();
+        state.mutation_names.push(name.to_string());
+        state.mutations.push(&self.mutation_weights);
+        drop(state);
+        Ok(self)
+    }
+
+    /// How the registered operators are chosen.
+    pub fn selection(&self) -> OperatorSelection {
+        self.selection
+    }
+
+    /// Return a handle that reads the probabilities and statistics of the registered operators.
+    pub fn monitor(&self) -> OperatorMonitor {
+        OperatorMonitor {
+            state: self.state.clone(),
+        }
+    }
+}
+
+fn validate_weight(weights: &[f64], weight: f64) -> Result<(), Failure> {
+    let positive = weight > 0.0 || weights.iter().any(|weight| *weight > 0.0);
+    if weight >= 0.0 && weight.is_finite() && positive {
+        Ok(())
+    } else {
+        Err(Failure::operator_setting())
+    }
+}
+
+impl<G> Operators<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn mutate_with(&self, genome: &G, mutate: impl FnOnce(&G) -> G) -> G {
+        if self.mutations.is_empty() {
+            return mutate(genome);
+        }
+        let index = self.state.lock().unwrap().mutations.choose(&self.selection);
+        let discarded = record(&mut self.pending.lock().unwrap().applied.mutations, index);
+        self.discard(discarded);
+        (self.mutations[index])(genome)
+    }
+
+    /// Count operator choices that were discarded before their child was evaluated.
+    fn discard(&self, count: usize) {
+        if count > 0 {
+            self.state.lock().unwrap().discarded += count;
+        }
+    }
+}
+
+/// Remember that the operator at `index` was applied to the child being created.
+/// Return the number of earlier choices discarded to stay within [`PENDING_LIMIT`].
+fn record(applied: &mut Vec<usize>, index: usize) -> usize {
+    let discarded = if applied.len() == PENDING_LIMIT {
+        applied.clear();
+        PENDING_LIMIT
+    } else {
+        0
+    };
+    applied.push(index);
+    discarded
+}
+
+impl<G> Genetic<G> for Operators<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> G {
+        self.genetic.initialize()
+    }
+
+    fn evaluate(&self, genome: &G) -> f64 {
+        self.genetic.evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &G, rhs: &G) -> G {
+        if self.crossovers.is_empty() {
+            return self.genetic.crossover(lhs, rhs);
+        }
+        let index = self
+            .state
+            .lock()
+            .unwrap()
+            .crossovers
+            .choose(&self.selection);
+        let discarded = record(&mut self.pending.lock().unwrap().applied.crossovers, index);
+        self.discard(discarded);
+        (self.crossovers[index])(lhs, rhs)
+    }
+
+    fn mutate(&self, genome: &G) -> G {
+        self.mutate_with(genome, |genome| self.genetic.mutate(genome))
+    }
+
+    fn mutate_scaled(&self, genome: &G, step_size: f64) -> G {
+        self.mutate_with(genome, |genome| {
+            self.genetic.mutate_scaled(genome, step_size)
+        })
+    }
+
+    fn violation(&self, genome: &G) -> f64 {
+        self.genetic.violation(genome)
+    }
+
+    fn repair(&self, genome: G) -> G {
+        self.genetic.repair(genome)
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        self.genetic.distance()
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<G>> {
+        self.genetic.local_search()
+    }
+
+    fn child_created(&self, child: &G) {
+        let mut pending = self.pending.lock().unwrap();
+        let applied = std::mem::take(&mut pending.applied);
+        let discarded = if pending.created.len() == PENDING_LIMIT {
+            pending
+                .created
+                .pop_front()
+                .map_or(0, |oldest| oldest.crossovers.len() + oldest.mutations.len())
+        } else {
+            0
+        };
+        pending.created.push_back(applied);
+        drop(pending);
+        self.discard(discarded);
+        self.genetic.child_created(child)
+    }
+
+    fn child_evaluated(&self, child: &G, outcome: &ChildOutcome) {
+        let applied = self.pending.lock().unwrap().created.pop_front();
+        if let Some(applied) = applied {
+            let mut state = self.state.lock().unwrap();
+            for index in applied.crossovers {
+                state.crossovers.reward(index, outcome, &self.selection);
+            }
+            for index in applied.mutations {
+                state.mutations.reward(index, outcome, &self.selection);
+            }
+        }
+        self.genetic.child_evaluated(child, outcome)
+    }
+}
+
+/// Read the probabilities and statistics of the operators registered with [`Operators`].
+/// Clones share the same state.
+#[derive(Clone, Debug)]
+pub struct OperatorMonitor {
+    state: Arc<Mutex<State>>,
+}
+
+impl OperatorMonitor {
+    /// The registered crossover operators, in registration order.
+    pub fn crossovers(&self) -> Vec<OperatorReport> {
+        let state = self.state.lock().unwrap();
+        report(&state.crossover_names, &state.crossovers)
+    }
+
+    /// The registered mutation operators, in registration order.
+    pub fn mutations(&self) -> Vec<OperatorReport> {
+        let state = self.state.lock().unwrap();
+        report(&state.mutation_names, &state.mutations)
+    }
+
+    /// The number of operator choices discarded without being credited with an outcome,
+    /// because too many children were waiting to be created or evaluated.
+    /// This grows when the strategy does not report its children.
+    pub fn discarded(&self) -> usize {
+        self.state.lock().unwrap().discarded
+    }
+}
+
+fn report(names: &[String], pool: &Pool) -> Vec<OperatorReport> {
+    names
+        .iter()
+        .zip(pool.probabilities())
+        .zip(pool.statistics())
+        .map(|((name, probability), statistics)| OperatorReport {
+            name: name.clone(),
+            probability: *probability,
+            statistics: *statistics,
+        })
+        .collect()
+}
+
+/// The current state of a named operator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperatorReport {
+    name: String,
+    probability: f64,
+    statistics: OperatorStatistics,
+}
+
+impl OperatorReport {
+    /// The name the operator was registered with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The probability with which the operator is chosen, when operators are chosen at random.
+    /// Not used by [`OperatorSelection::UpperConfidenceBound`].
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// The outcomes of the children the operator was applied to.
+    pub fn statistics(&self) -> OperatorStatistics {
+        self.statistics
+    }
+}
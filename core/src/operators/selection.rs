@@ -0,0 +1,213 @@
+use crate::{ChildOutcome, Failure, OperatorStatistics};
+use rand::Rng;
+
+/// How [`crate::Operators`] chooses which of its registered operators to apply.
+///
+/// The adaptive modes reward an operator each time it produces a child that is better than both
+/// its parents, see [`crate::ChildOutcome::improved`].
+/// Crossover and mutation operators are adapted separately.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OperatorSelection {
+    /// Choose operators in proportion to their registered weights, which never change.
+    Weighted,
+
+    /// Probability matching: choose operators in proportion to a moving average of their
+    /// rewards, starting from their registered weights.
+    ProbabilityMatching {
+        /// How quickly the average reward of an operator follows recent rewards, in (0, 1].
+        adaptation_rate: f64,
+        /// The smallest probability with which any operator is chosen, in [0, 1).
+        /// It is limited to one over the number of operators.
+        min_probability: f64,
+    },
+
+    /// Adaptive pursuit: move the probability of the operator with the best moving average of
+    /// rewards towards a maximum and the others towards `min_probability`, starting from their
+    /// registered weights.
+    AdaptivePursuit {
+        /// How quickly the average reward of an operator follows recent rewards, in (0, 1].
+        adaptation_rate: f64,
+        /// How quickly the probabilities move towards their targets, in (0, 1].
+        learning_rate: f64,
+        /// The smallest probability with which any operator is chosen, in [0, 1).
+        /// It is limited to one over the number of operators.
+        min_probability: f64,
+    },
+
+    /// Upper confidence bound (UCB1) bandit: try each operator once, then choose the operator
+    /// with the best mean reward plus an exploration bonus that shrinks as it is chosen.
+    /// Choices are counted when they are made, so the children of a generation, which are all
+    /// created before any of them is evaluated, still try every operator.
+    /// Registered weights are ignored.
+    UpperConfidenceBound {
+        /// Scale the exploration bonus. Must be non-negative.
+        exploration: f64,
+    },
+}
+
+impl OperatorSelection {
+    pub(crate) fn validate(&self) -> Result<(), Failure> {
+        let rate = |value: f64| value > 0.0 && value <= 1.0;
+        let probability = |value: f64| (0.0..1.0).contains(&value);
+        let valid = match *self {
+            OperatorSelection::Weighted => true,
+            OperatorSelection::ProbabilityMatching {
+                adaptation_rate,
+                min_probability,
+            } => rate(adaptation_rate) && probability(min_probability),
+            OperatorSelection::AdaptivePursuit {
+                adaptation_rate,
+                learning_rate,
+                min_probability,
+            } => rate(adaptation_rate) && rate(learning_rate) && probability(min_probability),
+            OperatorSelection::UpperConfidenceBound { exploration } => {
+                exploration >= 0.0 && exploration.is_finite()
+            }
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Failure::operator_setting())
+        }
+    }
+}
+
+/// The adaptive state of a group of operators of the same kind.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Pool {
+    probabilities: Vec<f64>,
+    qualities: Vec<f64>,
+    rewards: Vec<f64>,
+    selections: Vec<usize>,
+    statistics: Vec<OperatorStatistics>,
+}
+
+impl Pool {
+    /// Add an operator with a weight and reset the probabilities to the normalized weights.
+    pub(crate) fn push(&mut self, weights: &[f64]) {
+        let total: f64 = weights.iter().sum();
+        self.probabilities = weights.iter().map(|weight| weight / total).collect();
+        self.qualities = self.probabilities.clone();
+        self.rewards.push(0.0);
+        self.selections.push(0);
+        self.statistics.push(OperatorStatistics::default());
+    }
+
+    pub(crate) fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    pub(crate) fn statistics(&self) -> &[OperatorStatistics] {
+        &self.statistics
+    }
+
+    /// Choose the index of the next operator to apply.
+    pub(crate) fn choose(&mut self, selection: &OperatorSelection) -> usize {
+        let index = match *selection {
+            OperatorSelection::UpperConfidenceBound { exploration } => {
+                self.upper_confidence_bound(exploration)
+            }
+            _ => self.roulette(),
+        };
+        self.selections[index] += 1;
+        index
+    }
+
+    fn roulette(&self) -> usize {
+        let mut target = rand::thread_rng().gen::<f64>();
+        for (index, probability) in self.probabilities.iter().enumerate() {
+            if target < *probability {
+                return index;
+            }
+            target -= probability;
+        }
+        self.probabilities
+            .iter()
+            .rposition(|probability| *probability > 0.0)
+            .unwrap_or(0)
+    }
+
+    /// The mean reward of an operator is over the children evaluated so far, and its bonus
+    /// shrinks with the number of times it has been chosen, including children that have not
+    /// been evaluated yet.
+    fn upper_confidence_bound(&self, exploration: f64) -> usize {
+        if let Some(index) = self.selections.iter().position(|s| *s == 0) {
+            return index;
+        }
+        let total: usize = self.selections.iter().sum();
+        let log_total = (total as f64).ln();
+        let score = |index: usize| {
+            let applications = self.statistics[index].applications();
+            let mean = if applications > 0 {
+                self.rewards[index] / applications as f64
+            } else {
+                0.0
+            };
+            mean + exploration * (2.0 * log_total / self.selections[index] as f64).sqrt()
+        };
+        (0..self.selections.len())
+            .max_by(|lhs, rhs| score(*lhs).total_cmp(&score(*rhs)))
+            .unwrap_or(0)
+    }
+
+    /// Reward the operator at `index` for the outcome of a child it was applied to.
+    pub(crate) fn reward(
+        &mut self,
+        index: usize,
+        outcome: &ChildOutcome,
+        selection: &OperatorSelection,
+    ) {
+        let reward = if outcome.improved() { 1.0 } else { 0.0 };
+        self.statistics[index].record(outcome);
+        self.rewards[index] += reward;
+
+        match *selection {
+            OperatorSelection::ProbabilityMatching {
+                adaptation_rate,
+                min_probability,
+            } => {
+                self.adapt_quality(index, reward, adaptation_rate);
+                let min_probability = self.min_probability(min_probability);
+                let total: f64 = self.qualities.iter().sum();
+                let count = self.qualities.len() as f64;
+                for (probability, quality) in self.probabilities.iter_mut().zip(&self.qualities) {
+                    let share = if total > 0.0 {
+                        quality / total
+                    } else {
+                        1.0 / count
+                    };
+                    *probability = min_probability + (1.0 - count * min_probability) * share;
+                }
+            }
+            OperatorSelection::AdaptivePursuit {
+                adaptation_rate,
+                learning_rate,
+                min_probability,
+            } => {
+                self.adapt_quality(index, reward, adaptation_rate);
+                let min_probability = self.min_probability(min_probability);
+                let max_probability = 1.0 - (self.qualities.len() - 1) as f64 * min_probability;
+                let best = (0..self.qualities.len())
+                    .max_by(|lhs, rhs| self.qualities[*lhs].total_cmp(&self.qualities[*rhs]))
+                    .unwrap_or(0);
+                for (i, probability) in self.probabilities.iter_mut().enumerate() {
+                    let target = if i == best {
+                        max_probability
+                    } else {
+                        min_probability
+                    };
+                    *probability += learning_rate * (target - *probability);
+                }
+            }
+            OperatorSelection::Weighted | OperatorSelection::UpperConfidenceBound { .. } => {}
+        }
+    }
+
+    fn adapt_quality(&mut self, index: usize, reward: f64, adaptation_rate: f64) {
+        self.qualities[index] += adaptation_rate * (reward - self.qualities[index]);
+    }
+
+    fn min_probability(&self, min_probability: f64) -> f64 {
+        min_probability.min(1.0 / self.qualities.len() as f64)
+    }
+}
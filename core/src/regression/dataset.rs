@@ -0,0 +1,119 @@
+use crate::Failure;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Define tabular data for symbolic regression: rows of input values and the output value that
+/// should be predicted from them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dataset {
+    input_names: Vec<String>,
+    output_name: String,
+    inputs: Vec<Vec<f64>>,
+    outputs: Vec<f64>,
+}
+
+impl Dataset {
+    /// Create a dataset from values in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_names` - The name of each input column, used when printing expressions.
+    /// * `output_name` - The name of the output column.
+    /// * `inputs` - The input values of each row.
+    /// * `outputs` - The output value of each row.
+    pub fn new(
+        input_names: Vec<String>,
+        output_name: String,
+        inputs: Vec<Vec<f64>>,
+        outputs: Vec<f64>,
+    ) -> Result<Self, Failure> {
+        if outputs.is_empty()
+            || inputs.len() != outputs.len()
+            || inputs.iter().any(|row| row.len() != input_names.len())
+        {
+            return Err(Failure::dataset());
+        }
+        Ok(Self {
+            input_names,
+            output_name,
+            inputs,
+            outputs,
+        })
+    }
+
+    /// Read a dataset from comma separated values with a header row.
+    /// Every column other than `output_name` is used as an input.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the CSV data.
+    /// * `output_name` - The name of the column to predict.
+    pub fn from_csv(reader: impl Read, output_name: &str) -> Result<Self, Failure> {
+        let mut lines = BufReader::new(reader).lines();
+        let header: Vec<String> = match lines.next() {
+            Some(Ok(line)) => line.split(',').map(|s| String::from(s.trim())).collect(),
+            _ => return Err(Failure::csv()),
+        };
+        let output_column = header
+            .iter()
+            .position(|name| name == output_name)
+            .ok_or_else(Failure::dataset)?;
+
+        let mut inputs = Vec::new();
+        let mut outputs = Vec::new();
+        for line in lines {
+            let line = line.map_err(|_| Failure::csv())?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let values = line
+                .split(',')
+                .map(|s| s.trim().parse::<f64>())
+                .collect::<Result<Vec<f64>, _>>()
+                .map_err(|_| Failure::csv())?;
+            if values.len() != header.len() {
+                return Err(Failure::csv());
+            }
+            outputs.push(values[output_column]);
+            inputs.push(
+                values
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != output_column)
+                    .map(|(_, v)| *v)
+                    .collect(),
+            );
+        }
+
+        let mut input_names = header;
+        let output_name = input_names.remove(output_column);
+        Self::new(input_names, output_name, inputs, outputs)
+    }
+
+    /// Read a dataset from a CSV file with a header row.
+    /// See [`Dataset::from_csv`].
+    pub fn from_csv_file(path: impl AsRef<Path>, output_name: &str) -> Result<Self, Failure> {
+        let file = std::fs::File::open(path).map_err(|_| Failure::csv())?;
+        Self::from_csv(file, output_name)
+    }
+
+    /// The name of each input column.
+    pub fn input_names(&self) -> &[String] {
+        &self.input_names
+    }
+
+    /// The name of the output column.
+    pub fn output_name(&self) -> &str {
+        &self.output_name
+    }
+
+    /// The input values of each row.
+    pub fn inputs(&self) -> &[Vec<f64>] {
+        &self.inputs
+    }
+
+    /// The output value of each row.
+    pub fn outputs(&self) -> &[f64] {
+        &self.outputs
+    }
+}
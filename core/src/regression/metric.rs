@@ -0,0 +1,48 @@
+/// Define how the error between predicted and actual outputs is measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// The mean of the squared errors.
+    MeanSquaredError,
+
+    /// The mean of the absolute errors.
+    MeanAbsoluteError,
+
+    /// The coefficient of determination.
+    /// As a cost, this is reported as `1 - R²` so that lower is better and 0 is a perfect fit.
+    RSquared,
+}
+
+impl Metric {
+    /// Measure the error of `predicted` against `actual`, as a cost where lower is better.
+    /// Return `f64::MAX` when any prediction is not finite.
+    pub fn cost(&self, predicted: &[f64], actual: &[f64]) -> f64 {
+        if predicted.iter().any(|p| !p.is_finite()) {
+            return f64::MAX;
+        }
+        let n = actual.len() as f64;
+        let errors = predicted.iter().zip(actual.iter()).map(|(p, a)| p - a);
+        match self {
+            Metric::MeanSquaredError => errors.map(|e| e * e).sum::<f64>() / n,
+            Metric::MeanAbsoluteError => errors.map(f64::abs).sum::<f64>() / n,
+            Metric::RSquared => {
+                let mean = actual.iter().sum::<f64>() / n;
+                let residual: f64 = errors.map(|e| e * e).sum();
+                let total: f64 = actual.iter().map(|a| (a - mean) * (a - mean)).sum();
+                if total == 0.0 {
+                    residual
+                } else {
+                    residual / total
+                }
+            }
+        }
+    }
+
+    /// Convert a cost returned by [`Metric::cost`] back to the value of the metric itself.
+    /// This only differs from the cost for [`Metric::RSquared`].
+    pub fn value(&self, cost: f64) -> f64 {
+        match self {
+            Metric::RSquared => 1.0 - cost,
+            _ => cost,
+        }
+    }
+}
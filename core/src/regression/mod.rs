@@ -0,0 +1,9 @@
+mod dataset;
+mod metric;
+mod operator;
+mod symbolic_regression;
+
+pub use dataset::Dataset;
+pub use metric::Metric;
+pub use operator::Operator;
+pub use symbolic_regression::SymbolicRegression;
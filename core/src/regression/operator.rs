@@ -0,0 +1,218 @@
+use crate::Function;
+
+/// The precedence of an expression, used to decide where parentheses are needed.
+pub(crate) type Precedence = u8;
+
+pub(crate) const SUM: Precedence = 1;
+pub(crate) const PRODUCT: Precedence = 2;
+pub(crate) const UNARY: Precedence = 3;
+pub(crate) const POWER: Precedence = 4;
+pub(crate) const ATOM: Precedence = 5;
+
+/// The smallest denominator magnitude that protected division will divide by.
+const DIVISION_EPSILON: f64 = 1e-9;
+
+/// Define the mathematical operators available to symbolic regression.
+///
+/// Operators that are undefined for some inputs are protected, so that every expression can be
+/// evaluated:
+/// * `Divide` returns 1 when the denominator is close to zero.
+/// * `Log` and `Sqrt` use the absolute value of their argument, and `Log` returns 0 at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Negate,
+    Square,
+    Sqrt,
+    Sin,
+    Cos,
+    Exp,
+    Log,
+}
+
+impl Operator {
+    /// The four basic arithmetic operators.
+    pub fn arithmetic() -> Vec<Operator> {
+        vec![
+            Operator::Add,
+            Operator::Subtract,
+            Operator::Multiply,
+            Operator::Divide,
+        ]
+    }
+
+    /// The arithmetic operators together with common functions.
+    pub fn scientific() -> Vec<Operator> {
+        let mut result = Self::arithmetic();
+        result.extend([
+            Operator::Square,
+            Operator::Sqrt,
+            Operator::Sin,
+            Operator::Cos,
+            Operator::Exp,
+            Operator::Log,
+        ]);
+        result
+    }
+
+    /// The name used when printing prefix expressions.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Negate => "neg",
+            Operator::Square => "square",
+            Operator::Sqrt => "sqrt",
+            Operator::Sin => "sin",
+            Operator::Cos => "cos",
+            Operator::Exp => "exp",
+            Operator::Log => "log",
+        }
+    }
+
+    /// The number of arguments.
+    pub fn arity(&self) -> usize {
+        match self {
+            Operator::Add | Operator::Subtract | Operator::Multiply | Operator::Divide => 2,
+            _ => 1,
+        }
+    }
+
+    /// Compute the result from the argument values.
+    pub fn apply(&self, arguments: &[f64]) -> f64 {
+        let a = arguments[0];
+        match self {
+            Operator::Add => a + arguments[1],
+            Operator::Subtract => a - arguments[1],
+            Operator::Multiply => a * arguments[1],
+            Operator::Divide => {
+                if arguments[1].abs() < DIVISION_EPSILON {
+                    1.0
+                } else {
+                    a / arguments[1]
+                }
+            }
+            Operator::Negate => -a,
+            Operator::Square => a * a,
+            Operator::Sqrt => a.abs().sqrt(),
+            Operator::Sin => a.sin(),
+            Operator::Cos => a.cos(),
+            Operator::Exp => a.exp(),
+            Operator::Log => {
+                if a == 0.0 {
+                    0.0
+                } else {
+                    a.abs().ln()
+                }
+            }
+        }
+    }
+
+    /// The function used to build expression trees.
+    pub fn function(&self) -> Function<f64> {
+        let operator = *self;
+        Function::new(self.name(), self.arity(), move |a: &[f64]| {
+            operator.apply(a)
+        })
+    }
+
+    /// Format the operator applied to already formatted arguments, in conventional infix notation.
+    pub(crate) fn infix(&self, arguments: &[(String, Precedence)]) -> (String, Precedence) {
+        match self {
+            Operator::Add => binary(arguments, " + ", SUM, false),
+            Operator::Subtract => binary(arguments, " - ", SUM, true),
+            Operator::Multiply => binary(arguments, " * ", PRODUCT, false),
+            Operator::Divide => binary(arguments, " / ", PRODUCT, true),
+            Operator::Negate => (format!("-{}", wrap(&arguments[0], UNARY + 1)), UNARY),
+            Operator::Square => (format!("{}^2", wrap(&arguments[0], POWER + 1)), POWER),
+            _ => (format!("{}({})", self.name(), arguments[0].0), ATOM),
+        }
+    }
+
+    /// Format the operator applied to already formatted arguments, as LaTeX.
+    pub(crate) fn latex(&self, arguments: &[(String, Precedence)]) -> (String, Precedence) {
+        let a = &arguments[0].0;
+        match self {
+            Operator::Add => binary_latex(arguments, " + ", SUM, false),
+            Operator::Subtract => binary_latex(arguments, " - ", SUM, true),
+            Operator::Multiply => binary_latex(arguments, " \\cdot ", PRODUCT, false),
+            // A fraction is visually grouped, but needs parentheses before an exponent.
+            Operator::Divide => (format!("\\frac{{{}}}{{{}}}", a, arguments[1].0), POWER),
+            Operator::Negate => (format!("-{}", wrap_latex(&arguments[0], UNARY + 1)), UNARY),
+            Operator::Square => (
+                format!("{}^{{2}}", wrap_latex(&arguments[0], POWER + 1)),
+                POWER,
+            ),
+            Operator::Sqrt => (format!("\\sqrt{{{}}}", a), ATOM),
+            Operator::Sin => (format!("\\sin\\left({}\\right)", a), ATOM),
+            Operator::Cos => (format!("\\cos\\left({}\\right)", a), ATOM),
+            Operator::Exp => (format!("e^{{{}}}", a), POWER),
+            Operator::Log => (format!("\\ln\\left({}\\right)", a), ATOM),
+        }
+    }
+}
+
+fn binary(
+    arguments: &[(String, Precedence)],
+    symbol: &str,
+    precedence: Precedence,
+    right_strict: bool,
+) -> (String, Precedence) {
+    let right_minimum = if right_strict {
+        precedence + 1
+    } else {
+        precedence
+    };
+    (
+        format!(
+            "{}{}{}",
+            wrap(&arguments[0], precedence),
+            symbol,
+            wrap(&arguments[1], right_minimum)
+        ),
+        precedence,
+    )
+}
+
+fn binary_latex(
+    arguments: &[(String, Precedence)],
+    symbol: &str,
+    precedence: Precedence,
+    right_strict: bool,
+) -> (String, Precedence) {
+    let right_minimum = if right_strict {
+        precedence + 1
+    } else {
+        precedence
+    };
+    (
+        format!(
+            "{}{}{}",
+            wrap_latex(&arguments[0], precedence),
+            symbol,
+            wrap_latex(&arguments[1], right_minimum)
+        ),
+        precedence,
+    )
+}
+
+fn wrap(argument: &(String, Precedence), minimum: Precedence) -> String {
+    if argument.1 < minimum {
+        format!("({})", argument.0)
+    } else {
+        argument.0.clone()
+    }
+}
+
+fn wrap_latex(argument: &(String, Precedence), minimum: Precedence) -> String {
+    if argument.1 < minimum {
+        format!("\\left({}\\right)", argument.0)
+    } else {
+        argument.0.clone()
+    }
+}
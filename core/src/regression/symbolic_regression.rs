@@ -0,0 +1,183 @@
+use crate::regression::dataset::Dataset;
+use crate::regression::metric::Metric;
+use crate::regression::operator::{Operator, Precedence, ATOM, UNARY};
+use crate::selector::Selector;
+use crate::{
+    search, Failure, Node, PrimitiveSet, Progress, SearchSettings, Success, Terminal, Tree,
+    TreeGenetic, TreeSettings,
+};
+use rand::Rng;
+use std::sync::Arc;
+
+/// The range of the ephemeral random constants available to expressions.
+const CONSTANT_RANGE: std::ops::Range<f64> = -1.0..1.0;
+
+/// Fit a formula to tabular data using genetic programming.
+///
+/// The best expression is returned as the [`Success::best_genome`] of a search and can be
+/// printed with [`SymbolicRegression::infix`] or [`SymbolicRegression::latex`].
+#[derive(Clone, Debug)]
+pub struct SymbolicRegression {
+    dataset: Arc<Dataset>,
+    operators: Vec<Operator>,
+    metric: Metric,
+    primitives: PrimitiveSet<f64>,
+    settings: TreeSettings,
+}
+
+impl SymbolicRegression {
+    /// Create a symbolic regression problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The data to fit.
+    /// * `operators` - The operators that expressions may use.
+    /// * `metric` - The error metric to minimize.
+    /// * `settings` - The tree settings, including the parsimony coefficient.
+    pub fn new(
+        dataset: Dataset,
+        operators: &[Operator],
+        metric: Metric,
+        settings: TreeSettings,
+    ) -> Result<Self, Failure> {
+        let mut terminals: Vec<Terminal<f64>> = dataset
+            .input_names()
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Terminal::variable(name, i))
+            .collect();
+        terminals.push(Terminal::ephemeral("c", || {
+            rand::thread_rng().gen_range(CONSTANT_RANGE)
+        }));
+        let primitives = PrimitiveSet::new(
+            operators.iter().map(Operator::function).collect(),
+            terminals,
+        )?;
+        Ok(Self {
+            dataset: Arc::new(dataset),
+            operators: Vec::from(operators),
+            metric,
+            primitives,
+            settings,
+        })
+    }
+
+    /// The data to fit.
+    pub fn dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// The error metric to minimize.
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    /// The functions and terminals from which expressions are built.
+    pub fn primitives(&self) -> &PrimitiveSet<f64> {
+        &self.primitives
+    }
+
+    /// Create the genetic operations used by the search.
+    /// The cost of an expression is its error under the metric plus the parsimony pressure.
+    pub fn genetic(&self) -> TreeGenetic<f64> {
+        let dataset = self.dataset.clone();
+        let metric = self.metric;
+        TreeGenetic::new(
+            self.primitives.clone(),
+            self.settings,
+            move |primitives, tree| cost(primitives, &dataset, metric, tree),
+        )
+    }
+
+    /// Search for the expression that best fits the dataset.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Define the algorithm used to select genome partners for cross over.
+    /// * `progress` - Define the progress reporting callback.
+    /// * `settings` - Configuration of genetic algorithm search.
+    pub fn search(
+        &self,
+        selector: Box<dyn Selector<Tree<f64>> + Send + Sync>,
+        progress: Option<Progress<Tree<f64>>>,
+        settings: &SearchSettings,
+    ) -> Result<Success<Tree<f64>>, Failure> {
+        search(Box::new(self.genetic()), selector, progress, settings)
+    }
+
+    /// Compute the output predicted by an expression for one row of inputs.
+    pub fn predict(&self, expression: &Tree<f64>, inputs: &[f64]) -> f64 {
+        self.primitives.interpret(expression, inputs)
+    }
+
+    /// The value of the metric for an expression over the whole dataset, without parsimony
+    /// pressure.
+    /// For [`Metric::RSquared`] this is R² itself, where higher is better.
+    pub fn score(&self, expression: &Tree<f64>) -> f64 {
+        self.metric.value(cost(
+            &self.primitives,
+            &self.dataset,
+            self.metric,
+            expression,
+        ))
+    }
+
+    /// Format an expression in conventional infix notation, for example `x * (y + 0.5)`.
+    pub fn infix(&self, expression: &Tree<f64>) -> String {
+        self.format(expression, Operator::infix, |name| String::from(name))
+    }
+
+    /// Format an expression as LaTeX, for example `x \cdot \left(y + 0.5\right)`.
+    pub fn latex(&self, expression: &Tree<f64>) -> String {
+        self.format(expression, Operator::latex, |name| {
+            if name.chars().count() == 1 {
+                String::from(name)
+            } else {
+                format!("\\mathit{{{}}}", name.replace('_', "\\_"))
+            }
+        })
+    }
+
+    fn format(
+        &self,
+        expression: &Tree<f64>,
+        operator: impl Fn(&Operator, &[(String, Precedence)]) -> (String, Precedence),
+        variable: impl Fn(&str) -> String,
+    ) -> String {
+        let mut parts: Vec<(String, Precedence)> = Vec::with_capacity(expression.size());
+        for node in expression.nodes().iter().rev() {
+            let part = match node {
+                Node::Function { index, arity, .. } => {
+                    let mut arguments = parts.split_off(parts.len() - arity);
+                    arguments.reverse();
+                    operator(&self.operators[*index], &arguments)
+                }
+                Node::Terminal {
+                    value: Some(value), ..
+                } => (
+                    format!("{}", value),
+                    if *value < 0.0 { UNARY } else { ATOM },
+                ),
+                Node::Terminal { index, .. } => {
+                    (variable(self.primitives.terminals()[*index].name()), ATOM)
+                }
+            };
+            parts.push(part);
+        }
+        parts.pop().unwrap().0
+    }
+}
+
+fn cost(
+    primitives: &PrimitiveSet<f64>,
+    dataset: &Dataset,
+    metric: Metric,
+    tree: &Tree<f64>,
+) -> f64 {
+    let predicted: Vec<f64> = dataset
+        .inputs()
+        .iter()
+        .map(|inputs| primitives.interpret(tree, inputs))
+        .collect();
+    metric.cost(&predicted, dataset.outputs())
+}
@@ -0,0 +1,106 @@
+use crate::{OwnedProgressSnapshot, Progress, ProgressSnapshot};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+/// Define how often progress is sent over a progress channel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProgressRate {
+    /// Send progress every `n` epochs. Zero is treated as one.
+    Epochs(usize),
+
+    /// Send progress at most once per interval. The first epoch is always sent.
+    Interval(Duration),
+}
+
+impl Default for ProgressRate {
+    fn default() -> Self {
+        ProgressRate::Epochs(1)
+    }
+}
+
+/// Create a channel that delivers owned progress snapshots to another thread.
+///
+/// The channel is unbounded, so a slow consumer never stalls the search.
+/// Use `rate` to limit how many snapshots are sent.
+///
+/// # Arguments
+///
+/// * `rate` - Define how often progress is sent.
+///
+pub fn progress_channel<G>(rate: ProgressRate) -> (ProgressSender<G>, ProgressReceiver<G>) {
+    let (sender, receiver) = channel();
+    (
+        ProgressSender {
+            sender,
+            rate,
+            last_sent: None,
+        },
+        ProgressReceiver { receiver },
+    )
+}
+
+/// The sending half of a progress channel.
+///
+/// See [`progress_channel`].
+pub struct ProgressSender<G> {
+    sender: Sender<OwnedProgressSnapshot<G>>,
+    rate: ProgressRate,
+    last_sent: Option<Instant>,
+}
+
+impl<G> ProgressSender<G>
+where
+    G: Clone,
+{
+    /// Send a snapshot, unless the rate limit says it should be skipped.
+    /// Snapshots sent after the receiver has been dropped are discarded.
+    pub fn send(&mut self, snapshot: &ProgressSnapshot<G>) {
+        let due = match self.rate {
+            ProgressRate::Epochs(n) => snapshot.epoch() % n.max(1) == 0,
+            ProgressRate::Interval(interval) => self
+                .last_sent
+                .map_or(true, |last_sent| last_sent.elapsed() >= interval),
+        };
+        if due {
+            self.last_sent = Some(Instant::now());
+            let _ = self.sender.send(snapshot.to_owned_snapshot());
+        }
+    }
+
+    /// Return a progress callback that sends snapshots over the channel.
+    pub fn into_progress(mut self) -> Progress<G>
+    where
+        G: 'static,
+    {
+        Box::new(move |snapshot| self.send(&snapshot))
+    }
+}
+
+/// The receiving half of a progress channel.
+/// Iterating blocks until the next snapshot arrives and ends when the search finishes.
+///
+/// See [`progress_channel`].
+pub struct ProgressReceiver<G> {
+    receiver: Receiver<OwnedProgressSnapshot<G>>,
+}
+
+impl<G> ProgressReceiver<G> {
+    /// Return the next snapshot if one has arrived, without blocking.
+    pub fn try_next(&self) -> Option<OwnedProgressSnapshot<G>> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Return the most recent snapshot that has arrived, discarding any older ones, without
+    /// blocking.
+    pub fn latest(&self) -> Option<OwnedProgressSnapshot<G>> {
+        self.receiver.try_iter().last()
+    }
+}
+
+impl<G> Iterator for ProgressReceiver<G> {
+    type Item = OwnedProgressSnapshot<G>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    ChildOutcome, Distance, Failure, Genetic, LocalSearch, NanPolicy, Objective, SearchSettings,
+};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Wrap the genetic operations of a search to count every call to
+/// [`evaluate()`](crate::Genetic#evaluate()), whichever strategy or local search makes it,
+/// and to turn the value it returns into a cost that is minimised.
+/// NaN costs and violations are replaced by infinity, the worst value, and recorded so that
+/// the search can fail when the [`NanPolicy`] rejects them.
+pub(crate) struct CountingGenetic<G> {
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    objective: Objective,
+    nan_policy: NanPolicy,
+    evaluations: AtomicUsize,
+    nan_found: AtomicBool,
+}
+
+impl<G> CountingGenetic<G> {
+    pub(crate) fn new(
+        genetic: Box<dyn Genetic<G> + Send + Sync>,
+        settings: &SearchSettings,
+    ) -> Self {
+        Self {
+            genetic,
+            objective: settings.objective(),
+            nan_policy: settings.nan_policy(),
+            evaluations: AtomicUsize::new(0),
+            nan_found: AtomicBool::new(false),
+        }
+    }
+
+    /// The number of evaluations so far.
+    pub(crate) fn evaluations(&self) -> usize {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// Fail if a NaN cost or violation has been found and the policy rejects it.
+    pub(crate) fn check(&self) -> Result<(), Failure> {
+        if self.nan_policy == NanPolicy::Reject && self.nan_found.load(Ordering::Relaxed) {
+            Err(Failure::nan_cost())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn worst_if_nan(&self, value: f64) -> f64 {
+        if value.is_nan() {
+            self.nan_found.store(true, Ordering::Relaxed);
+            f64::INFINITY
+        } else {
+            value
+        }
+    }
+}
+
+impl<G> Genetic<G> for CountingGenetic<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> G {
+        self.genetic.initialize()
+    }
+
+    fn evaluate(&self, genome: &G) -> f64 {
+        self.evaluations.fetch_add(1, Ordering::Relaxed);
+        self.worst_if_nan(self.objective.orient(self.genetic.evaluate(genome)))
+    }
+
+    fn crossover(&self, lhs: &G, rhs: &G) -> G {
+        self.genetic.crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, genome: &G) -> G {
+        self.genetic.mutate(genome)
+    }
+
+    fn mutate_scaled(&self, genome: &G, step_size: f64) -> G {
+        self.genetic.mutate_scaled(genome, step_size)
+    }
+
+    fn violation(&self, genome: &G) -> f64 {
+        self.worst_if_nan(self.genetic.violation(genome))
+    }
+
+    fn repair(&self, genome: G) -> G {
+        self.genetic.repair(genome)
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        self.genetic.distance()
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<G>> {
+        self.genetic.local_search()
+    }
+
+    fn child_created(&self, child: &G) {
+        self.genetic.child_created(child)
+    }
+
+    fn child_evaluated(&self, child: &G, outcome: &ChildOutcome) {
+        self.genetic.child_evaluated(child, outcome)
+    }
+}
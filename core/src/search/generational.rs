@@ -0,0 +1,360 @@
+use crate::common::make_vec;
+use crate::search::{better_cost, evaluate, local_search};
+use crate::selector::Selector;
+use crate::{
+    compare_costs, ChildOutcome, ConcurrencySettings, Distance, Failure, Genetic, LocalSearch,
+    MemeticSettings, Niching, Observer, ProgressSnapshot, RestartSettings, SearchSettings,
+    Strategy, Success,
+};
+use rand::{thread_rng, Rng};
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// The generational genetic algorithm used by [`crate::search`] and [`crate::search_niching`].
+///
+/// In each epoch every genome is paired with a partner chosen by a selector, and the
+/// population is replaced by their offspring.
+/// Niching and restarts are applied when configured.
+pub struct Generational<G> {
+    selector: Box<dyn Selector<G> + Send + Sync>,
+    niching: Option<Niching<G>>,
+    population: Vec<G>,
+    replacement: Vec<G>,
+    origins: Vec<(bool, f64)>,
+    partner_indices: Vec<usize>,
+    costs: Vec<f64>,
+    violations: Vec<f64>,
+    adjusted_costs: Vec<f64>,
+    restarts: usize,
+    restart_epoch: usize,
+    local_search_time: Duration,
+}
+
+impl<G> Generational<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    /// Create a generational genetic algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Define the algorithm used to select genome partners for cross over.
+    pub fn new(selector: Box<dyn Selector<G> + Send + Sync>) -> Self {
+        Self {
+            selector,
+            niching: None,
+            population: Vec::new(),
+            replacement: Vec::new(),
+            origins: Vec::new(),
+            partner_indices: Vec::new(),
+            costs: Vec::new(),
+            violations: Vec::new(),
+            adjusted_costs: Vec::new(),
+            restarts: 0,
+            restart_epoch: 0,
+            local_search_time: Duration::ZERO,
+        }
+    }
+
+    /// Create a generational genetic algorithm with niching.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Define the algorithm used to select genome partners for cross over.
+    /// * `niching` - Define how the population is divided into niches.
+    pub fn with_niching(selector: Box<dyn Selector<G> + Send + Sync>, niching: Niching<G>) -> Self {
+        Self {
+            niching: Some(niching),
+            ..Self::new(selector)
+        }
+    }
+
+    fn resize(&mut self) {
+        let n = self.population.len();
+        self.costs.resize(n, 0.0);
+        self.violations.resize(n, 0.0);
+        self.partner_indices.resize(n, 0);
+    }
+
+    fn evaluate(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+        epoch: usize,
+    ) {
+        self.local_search_time += evaluate(
+            genetic,
+            settings.concurrency(),
+            local_search(genetic, settings, epoch),
+            &mut self.population,
+            &mut self.costs,
+            &mut self.violations,
+        );
+    }
+}
+
+impl<G> Strategy<G> for Generational<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+    ) -> Result<(), Failure> {
+        self.population = make_vec(settings.population_size(), || genetic.initialize());
+        self.replacement = Vec::with_capacity(settings.population_size());
+        self.resize();
+        self.evaluate(genetic, settings, 1);
+        Ok(())
+    }
+
+    fn step(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+        snapshot: &ProgressSnapshot<G>,
+        observer: &mut dyn Observer<G>,
+    ) {
+        let epoch = snapshot.epoch() + 1;
+
+        // Stagnation is counted by the search as a whole, so only count the epochs since the
+        // last restart.
+        let stagnation = snapshot.stagnation().min(epoch - self.restart_epoch);
+        let restart = settings.restart().filter(|restart| {
+            stagnation >= restart.stagnation()
+                || snapshot
+                    .diversity()
+                    .is_some_and(|d| d.mean_distance() <= restart.min_diversity())
+        });
+        if let Some(restart) = restart {
+            self.population = restarted(
+                genetic,
+                &restart,
+                &self.population,
+                &self.costs,
+                &self.violations,
+            );
+            self.resize();
+            self.restarts += 1;
+            self.restart_epoch = epoch;
+            self.evaluate(genetic, settings, epoch);
+            return;
+        }
+
+        match &self.niching {
+            Some(niching) if !niching.is_crowding() => {
+                niching.adjust(&self.population, &self.costs, &mut self.adjusted_costs);
+                self.selector.select_constrained(
+                    &self.population,
+                    &self.adjusted_costs,
+                    &self.violations,
+                    &mut self.partner_indices,
+                );
+            }
+            _ => self.selector.select_constrained(
+                &self.population,
+                &self.costs,
+                &self.violations,
+                &mut self.partner_indices,
+            ),
+        }
+
+        observer.selected(epoch, &self.partner_indices);
+
+        self.replacement.clear();
+        self.origins.clear();
+        for (lhs_index, (lhs, rhs_index)) in
+            std::iter::zip(self.population.iter(), self.partner_indices.iter()).enumerate()
+        {
+            let rhs = self.population.get(*rhs_index).unwrap();
+
+            let (cross, parents) = if thread_rng().gen_bool(0.5) {
+                (genetic.crossover(lhs, rhs), (lhs_index, *rhs_index))
+            } else {
+                (genetic.crossover(rhs, lhs), (*rhs_index, lhs_index))
+            };
+
+            let mutated = thread_rng().gen_bool(settings.mutation_probability());
+            let mutant = if mutated {
+                genetic.mutate(&cross)
+            } else {
+                cross
+            };
+
+            let child = genetic.repair(mutant);
+            observer.child_created(epoch, &child, parents, mutated);
+            self.replacement.push(child);
+            self.origins.push((
+                mutated,
+                better_cost(self.costs[lhs_index], self.costs[*rhs_index]),
+            ));
+        }
+
+        match &self.niching {
+            Some(niching) if niching.is_crowding() => {
+                self.local_search_time += crowd(
+                    genetic,
+                    niching,
+                    settings.concurrency(),
+                    local_search(genetic, settings, epoch),
+                    &mut self.population,
+                    &mut self.costs,
+                    &mut self.violations,
+                    &mut self.replacement,
+                    &self.partner_indices,
+                    &self.origins,
+                    observer,
+                    epoch,
+                );
+            }
+            _ => {
+                std::mem::swap(&mut self.population, &mut self.replacement);
+                self.evaluate(genetic, settings, epoch);
+                for (i, (mutated, parent_cost)) in self.origins.iter().enumerate() {
+                    observer.child_evaluated(
+                        epoch,
+                        &self.population[i],
+                        &ChildOutcome::new(true, *mutated, self.costs[i], *parent_cost),
+                    );
+                }
+            }
+        }
+    }
+
+    fn population(&self) -> &[G] {
+        &self.population
+    }
+
+    fn costs(&self) -> &[f64] {
+        &self.costs
+    }
+
+    fn violations(&self) -> &[f64] {
+        &self.violations
+    }
+
+    fn replace(&mut self, index: usize, genome: G, cost: f64, violation: f64) {
+        self.population[index] = genome;
+        self.costs[index] = cost;
+        self.violations[index] = violation;
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        self.niching.as_ref().map(|niching| niching.measure())
+    }
+
+    fn local_search_time(&self) -> Duration {
+        self.local_search_time
+    }
+
+    fn finish(&self, success: Success<G>) -> Success<G> {
+        let success = success.with_restarts(self.restarts);
+        match self.niching.as_ref().filter(|n| n.representatives()) {
+            Some(niching) => success.with_representatives(
+                niching
+                    .representatives_of(&self.population, &self.costs)
+                    .into_iter()
+                    .map(|i| (self.population[i].clone(), self.costs[i]))
+                    .collect(),
+            ),
+            None => success,
+        }
+    }
+}
+
+/// Replace parents with the offspring in `replacement`, by crowding.
+/// Each offspring competes with whichever of its two parents is more similar.
+/// The observer is notified of each offspring after it is evaluated.
+/// Return the time spent in local search.
+#[allow(clippy::too_many_arguments)]
+fn crowd<G>(
+    genetic: &(dyn Genetic<G> + Send + Sync),
+    niching: &Niching<G>,
+    concurrency: ConcurrencySettings,
+    local: Option<(MemeticSettings, &dyn LocalSearch<G>)>,
+    population: &mut [G],
+    costs: &mut [f64],
+    violations: &mut [f64],
+    replacement: &mut Vec<G>,
+    partner_indices: &[usize],
+    origins: &[(bool, f64)],
+    observer: &mut dyn Observer<G>,
+    epoch: usize,
+) -> Duration
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    let mut child_costs = make_vec(replacement.len(), || 0.0);
+    let mut child_violations = make_vec(replacement.len(), || 0.0);
+    let local_search_time = evaluate(
+        genetic,
+        concurrency,
+        local,
+        replacement,
+        &mut child_costs,
+        &mut child_violations,
+    );
+
+    let parents = population.to_vec();
+    for (i, child) in replacement.drain(..).enumerate() {
+        let (mutated, parent_cost) = origins[i];
+        observer.child_evaluated(
+            epoch,
+            &child,
+            &ChildOutcome::new(true, mutated, child_costs[i], parent_cost),
+        );
+        let partner = partner_indices[i];
+        let slot = if niching.distance(&child, &parents[i])
+            <= niching.distance(&child, &parents[partner])
+        {
+            i
+        } else {
+            partner
+        };
+        let child_violation = child_violations[i].max(0.0);
+        let slot_violation = violations[slot].max(0.0);
+        let wins = if child_violation != slot_violation {
+            child_violation < slot_violation
+        } else {
+            niching.replaces(child_costs[i], costs[slot])
+        };
+        if wins {
+            population[slot] = child;
+            costs[slot] = child_costs[i];
+            violations[slot] = child_violations[i];
+        }
+    }
+    local_search_time
+}
+
+/// Return a new population that keeps the best genomes of `population` and is otherwise
+/// freshly initialized, grown according to the restart settings.
+fn restarted<G>(
+    genetic: &(dyn Genetic<G> + Send + Sync),
+    restart: &RestartSettings,
+    population: &[G],
+    costs: &[f64],
+    violations: &[f64],
+) -> Vec<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    let n = population.len();
+    let size = ((n as f64 * restart.population_growth()).round() as usize)
+        .min(restart.max_population_size().max(n));
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| {
+        violations[*a]
+            .max(0.0)
+            .total_cmp(&violations[*b].max(0.0))
+            .then(compare_costs(costs[*a], costs[*b]))
+    });
+    let mut result: Vec<G> = order
+        .into_iter()
+        .take(restart.elite().min(size))
+        .map(|i| population[i].clone())
+        .collect();
+    result.extend(make_vec(size - result.len(), || genetic.initialize()));
+    result
+}
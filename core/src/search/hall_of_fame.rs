@@ -0,0 +1,127 @@
+use crate::{compare_costs, Distance, HallOfFameSettings, Objective};
+
+/// A genome in the hall of fame.
+/// The cost is reported in the orientation of [`crate::SearchSettings::objective`].
+///
+/// See [`crate::HallOfFameSettings`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HallOfFameEntry<G> {
+    genome: G,
+    cost: f64,
+    violation: f64,
+    epoch: usize,
+}
+
+impl<G> HallOfFameEntry<G> {
+    /// The genome.
+    pub fn genome(&self) -> &G {
+        &self.genome
+    }
+
+    /// Take ownership of the genome.
+    pub fn into_genome(self) -> G {
+        self.genome
+    }
+
+    /// The cost of the genome.
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    /// The constraint violation of the genome.
+    /// See [`crate::Genetic::violation`].
+    pub fn violation(&self) -> f64 {
+        self.violation
+    }
+
+    /// The epoch in which the genome entered the hall of fame.
+    pub fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+/// Keep the best distinct genomes found at any epoch, best first.
+/// Feasible genomes are ranked by cost, infeasible genomes by violation.
+pub(crate) struct HallOfFame<G> {
+    settings: HallOfFameSettings,
+    objective: Objective,
+    entries: Vec<HallOfFameEntry<G>>,
+}
+
+impl<G> HallOfFame<G>
+where
+    G: Clone + PartialEq,
+{
+    pub(crate) fn new(settings: HallOfFameSettings, objective: Objective) -> Self {
+        Self {
+            settings,
+            objective,
+            entries: Vec::with_capacity(settings.size() + 1),
+        }
+    }
+
+    /// The genomes in the hall of fame, best first.
+    pub(crate) fn entries(&self) -> &[HallOfFameEntry<G>] {
+        &self.entries
+    }
+
+    /// True if a genome with `cost` and `violation` is better than the worst entry, or the hall of
+    /// fame is not full.
+    /// The cost is minimised.
+    pub(crate) fn admits(&self, cost: f64, violation: f64) -> bool {
+        self.entries.len() < self.settings.size()
+            || self.entries.last().is_some_and(|entry| {
+                compare_costs(violation.max(0.0), entry.violation.max(0.0))
+                    .then(compare_costs(cost, self.objective.orient(entry.cost)))
+                    .is_lt()
+            })
+    }
+
+    /// Add `genome` if it is better than the worst entry and every entry that is the same genome.
+    /// Entries that are the same genome are removed.
+    /// The cost is minimised.
+    pub(crate) fn consider(
+        &mut self,
+        genome: &G,
+        cost: f64,
+        violation: f64,
+        epoch: usize,
+        distance: Option<&dyn Distance<G>>,
+    ) {
+        if !self.admits(cost, violation) {
+            return;
+        }
+        let objective = self.objective;
+        let better = |entry: &HallOfFameEntry<G>| {
+            compare_costs(violation.max(0.0), entry.violation.max(0.0))
+                .then(compare_costs(cost, objective.orient(entry.cost)))
+                .is_lt()
+        };
+
+        let min_distance = self.settings.min_distance();
+        let same = |entry: &HallOfFameEntry<G>| match distance.filter(|_| min_distance > 0.0) {
+            Some(distance) => distance.distance(&entry.genome, genome) < min_distance,
+            None => entry.genome == *genome,
+        };
+        if self
+            .entries
+            .iter()
+            .any(|entry| same(entry) && !better(entry))
+        {
+            return;
+        }
+        self.entries.retain(|entry| !same(entry));
+
+        let position = self.entries.partition_point(|entry| !better(entry));
+        self.entries.insert(
+            position,
+            HallOfFameEntry {
+                genome: genome.clone(),
+                cost: objective.orient(cost),
+                violation,
+                epoch,
+            },
+        );
+        self.entries.truncate(self.settings.size());
+    }
+}
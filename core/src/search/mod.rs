@@ -1,9 +1,27 @@
+mod channel;
+mod counting;
+mod generational;
+mod hall_of_fame;
+mod observer;
+mod operators;
 mod progress;
 mod reason;
+#[allow(clippy::module_inception)]
 mod search;
+mod spawn;
 mod success;
+mod tracker;
 
-pub use progress::Progress;
+pub use channel::{progress_channel, ProgressRate, ProgressReceiver, ProgressSender};
+pub use generational::Generational;
+pub(crate) use hall_of_fame::HallOfFame;
+pub use hall_of_fame::HallOfFameEntry;
+pub use observer::{Observer, Observers};
+pub(crate) use operators::{better_cost, OperatorRecorder};
+pub use operators::{ChildOutcome, OperatorStatistics, OperatorUsage};
+pub use progress::{OwnedProgressSnapshot, Progress, ProgressSnapshot};
 pub use reason::Reason;
-pub use search::search;
+pub(crate) use search::{evaluate, local_search, run};
+pub use search::{search, search_niching, search_observed, search_until, search_with, Search};
+pub use spawn::{spawn, spawn_until, SearchHandle};
 pub use success::Success;
@@ -0,0 +1,204 @@
+use crate::{ChildOutcome, Progress, ProgressSnapshot, SearchSettings, Success};
+
+/// Observe the phases of a search, for logging, metrics and debugging.
+///
+/// Every hook does nothing by default, so implementors only provide the hooks they need.
+/// Hooks that do not apply to a search strategy are not called: for example only strategies
+/// that select partners call [`Observer::selected`].
+/// Several observers can be combined with [`Observers`].
+///
+/// See [`crate::search_observed`].
+pub trait Observer<G> {
+    /// Called once before the initial population is created.
+    fn search_started(&mut self, _settings: &SearchSettings) {}
+
+    /// Called once when the initial population has been created and evaluated.
+    fn population_initialized(&mut self, _population: &[G]) {}
+
+    /// Called in every epoch with the evaluated population.
+    fn evaluated(&mut self, _epoch: usize, _population: &[G], _costs: &[f64], _violations: &[f64]) {
+    }
+
+    /// Called when partners have been selected for crossover.
+    /// Genome `i` is paired with genome `partner_indices[i]`.
+    fn selected(&mut self, _epoch: usize, _partner_indices: &[usize]) {}
+
+    /// Called for every child created, before it is evaluated and before the next child is
+    /// created.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch the child belongs to.
+    /// * `child` - The child.
+    /// * `parents` - The indices of the parents in the population they were taken from, in the
+    ///   order they were passed to [`crate::Genetic::crossover`].
+    ///   Both are the same when the child was not created by crossover.
+    /// * `mutated` - True if the child was mutated after crossover.
+    fn child_created(
+        &mut self,
+        _epoch: usize,
+        _child: &G,
+        _parents: (usize, usize),
+        _mutated: bool,
+    ) {
+    }
+
+    /// Called for every child after it has been evaluated, in the order the children were
+    /// created.
+    fn child_evaluated(&mut self, _epoch: usize, _child: &G, _outcome: &ChildOutcome) {}
+
+    /// Called at the end of every epoch, after the best genome has been updated.
+    fn epoch_ended(&mut self, _snapshot: &ProgressSnapshot<G>) {}
+
+    /// Called once with the result of the search.
+    fn search_ended(&mut self, _success: &Success<G>) {}
+}
+
+/// The progress callback observes the end of every epoch.
+impl<G: Clone> Observer<G> for Progress<G> {
+    fn epoch_ended(&mut self, snapshot: &ProgressSnapshot<G>) {
+        self(snapshot.clone())
+    }
+}
+
+/// An absent observer observes nothing.
+impl<G, O: Observer<G>> Observer<G> for Option<O> {
+    fn search_started(&mut self, settings: &SearchSettings) {
+        if let Some(observer) = self {
+            observer.search_started(settings);
+        }
+    }
+
+    fn population_initialized(&mut self, population: &[G]) {
+        if let Some(observer) = self {
+            observer.population_initialized(population);
+        }
+    }
+
+    fn evaluated(&mut self, epoch: usize, population: &[G], costs: &[f64], violations: &[f64]) {
+        if let Some(observer) = self {
+            observer.evaluated(epoch, population, costs, violations);
+        }
+    }
+
+    fn selected(&mut self, epoch: usize, partner_indices: &[usize]) {
+        if let Some(observer) = self {
+            observer.selected(epoch, partner_indices);
+        }
+    }
+
+    fn child_created(&mut self, epoch: usize, child: &G, parents: (usize, usize), mutated: bool) {
+        if let Some(observer) = self {
+            observer.child_created(epoch, child, parents, mutated);
+        }
+    }
+
+    fn child_evaluated(&mut self, epoch: usize, child: &G, outcome: &ChildOutcome) {
+        if let Some(observer) = self {
+            observer.child_evaluated(epoch, child, outcome);
+        }
+    }
+
+    fn epoch_ended(&mut self, snapshot: &ProgressSnapshot<G>) {
+        if let Some(observer) = self {
+            observer.epoch_ended(snapshot);
+        }
+    }
+
+    fn search_ended(&mut self, success: &Success<G>) {
+        if let Some(observer) = self {
+            observer.search_ended(success);
+        }
+    }
+}
+
+/// A collection of observers that are each called in turn.
+pub struct Observers<G> {
+    observers: Vec<Box<dyn Observer<G>>>,
+}
+
+impl<G> Observers<G> {
+    /// Create an empty collection of observers.
+    pub fn new() -> Self {
+        Self {
+            observers: Vec::new(),
+        }
+    }
+
+    /// Add an observer, which is called after those already added.
+    pub fn with(mut self, observer: Box<dyn Observer<G>>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Add an observer, which is called after those already added.
+    pub fn push(&mut self, observer: Box<dyn Observer<G>>) {
+        self.observers.push(observer);
+    }
+
+    /// The number of observers.
+    pub fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Return true if there are no observers.
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+}
+
+impl<G> Default for Observers<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G> Observer<G> for Observers<G> {
+    fn search_started(&mut self, settings: &SearchSettings) {
+        for observer in self.observers.iter_mut() {
+            observer.search_started(settings);
+        }
+    }
+
+    fn population_initialized(&mut self, population: &[G]) {
+        for observer in self.observers.iter_mut() {
+            observer.population_initialized(population);
+        }
+    }
+
+    fn evaluated(&mut self, epoch: usize, population: &[G], costs: &[f64], violations: &[f64]) {
+        for observer in self.observers.iter_mut() {
+            observer.evaluated(epoch, population, costs, violations);
+        }
+    }
+
+    fn selected(&mut self, epoch: usize, partner_indices: &[usize]) {
+        for observer in self.observers.iter_mut() {
+            observer.selected(epoch, partner_indices);
+        }
+    }
+
+    fn child_created(&mut self, epoch: usize, child: &G, parents: (usize, usize), mutated: bool) {
+        for observer in self.observers.iter_mut() {
+            observer.child_created(epoch, child, parents, mutated);
+        }
+    }
+
+    fn child_evaluated(&mut self, epoch: usize, child: &G, outcome: &ChildOutcome) {
+        for observer in self.observers.iter_mut() {
+            observer.child_evaluated(epoch, child, outcome);
+        }
+    }
+
+    fn epoch_ended(&mut self, snapshot: &ProgressSnapshot<G>) {
+        for observer in self.observers.iter_mut() {
+            observer.epoch_ended(snapshot);
+        }
+    }
+
+    fn search_ended(&mut self, success: &Success<G>) {
+        for observer in self.observers.iter_mut() {
+            observer.search_ended(success);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{
+    compare_costs, Genetic, Objective, Observer, ProgressSnapshot, SearchSettings, Success,
+};
+use std::fmt::Debug;
+
+/// How a child was created, and its cost compared with its parents.
+/// Costs are reported in the orientation of [`crate::SearchSettings::objective`].
+///
+/// See [`Observer::child_evaluated`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChildOutcome {
+    crossover: bool,
+    mutated: bool,
+    cost: f64,
+    parent_cost: f64,
+    improved: bool,
+}
+
+impl ChildOutcome {
+    /// Describe a child with costs that are minimised.
+    pub(crate) fn new(crossover: bool, mutated: bool, cost: f64, parent_cost: f64) -> Self {
+        Self {
+            crossover,
+            mutated,
+            cost,
+            parent_cost,
+            improved: compare_costs(cost, parent_cost).is_lt(),
+        }
+    }
+
+    fn oriented(&self, objective: Objective) -> Self {
+        Self {
+            cost: objective.orient(self.cost),
+            parent_cost: objective.orient(self.parent_cost),
+            ..*self
+        }
+    }
+
+    /// True if the child was created by [`crate::Genetic::crossover`].
+    pub fn crossover(&self) -> bool {
+        self.crossover
+    }
+
+    /// True if the child was mutated.
+    pub fn mutated(&self) -> bool {
+        self.mutated
+    }
+
+    /// The cost of the child.
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    /// The cost of the better parent.
+    pub fn parent_cost(&self) -> f64 {
+        self.parent_cost
+    }
+
+    /// True if the child is better than both its parents.
+    pub fn improved(&self) -> bool {
+        self.improved
+    }
+}
+
+/// The statistics of one genetic operator.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OperatorStatistics {
+    applications: usize,
+    improvements: usize,
+    deltas: usize,
+    total_delta: f64,
+}
+
+impl OperatorStatistics {
+    /// Record the outcome of a child the operator was applied to.
+    pub(crate) fn record(&mut self, outcome: &ChildOutcome) {
+        self.applications += 1;
+        self.improvements += usize::from(outcome.improved);
+        let delta = outcome.cost - outcome.parent_cost;
+        if delta.is_finite() {
+            self.deltas += 1;
+            self.total_delta += delta;
+        }
+    }
+
+    fn add(&mut self, other: &OperatorStatistics) {
+        self.applications += other.applications;
+        self.improvements += other.improvements;
+        self.deltas += other.deltas;
+        self.total_delta += other.total_delta;
+    }
+
+    /// The number of children the operator was applied to.
+    pub fn applications(&self) -> usize {
+        self.applications
+    }
+
+    /// The number of those children that were better than both their parents.
+    /// See [`ChildOutcome::improved`].
+    pub fn improvements(&self) -> usize {
+        self.improvements
+    }
+
+    /// The mean difference between the cost of a child and the cost of its better parent.
+    /// This is negative on average when the operator helps a minimising search, and positive
+    /// when it helps a maximising search.
+    /// Children whose difference is not finite, such as those with an infinite cost, are left
+    /// out.
+    /// It is 0 when there are no such differences.
+    pub fn mean_delta(&self) -> f64 {
+        if self.deltas == 0 {
+            0.0
+        } else {
+            self.total_delta / self.deltas as f64
+        }
+    }
+}
+
+/// The statistics of the crossover and mutation operators.
+///
+/// A child created by crossover and then mutated counts for both operators.
+/// Only strategies that report their children collect statistics, see
+/// [`Observer::child_evaluated`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OperatorUsage {
+    crossover: OperatorStatistics,
+    mutation: OperatorStatistics,
+}
+
+impl OperatorUsage {
+    /// The statistics of [`crate::Genetic::crossover`].
+    pub fn crossover(&self) -> OperatorStatistics {
+        self.crossover
+    }
+
+    /// The statistics of [`crate::Genetic::mutate`].
+    pub fn mutation(&self) -> OperatorStatistics {
+        self.mutation
+    }
+
+    fn record(&mut self, outcome: &ChildOutcome) {
+        if outcome.crossover {
+            self.crossover.record(outcome);
+        }
+        if outcome.mutated {
+            self.mutation.record(outcome);
+        }
+    }
+
+    pub(crate) fn add(&mut self, other: &OperatorUsage) {
+        self.crossover.add(&other.crossover);
+        self.mutation.add(&other.mutation);
+    }
+}
+
+/// Forward every hook to an observer, recording the operator usage of the children evaluated
+/// and passing the children created and their outcomes to the genetic operations.
+/// Outcomes are converted to the orientation of the objective.
+pub(crate) struct OperatorRecorder<'a, G> {
+    genetic: &'a (dyn Genetic<G> + Send + Sync),
+    observer: &'a mut dyn Observer<G>,
+    usage: &'a mut OperatorUsage,
+    objective: Objective,
+}
+
+impl<'a, G> OperatorRecorder<'a, G> {
+    pub(crate) fn new(
+        genetic: &'a (dyn Genetic<G> + Send + Sync),
+        observer: &'a mut dyn Observer<G>,
+        usage: &'a mut OperatorUsage,
+        objective: Objective,
+    ) -> Self {
+        Self {
+            genetic,
+            observer,
+            usage,
+            objective,
+        }
+    }
+}
+
+impl<G> Observer<G> for OperatorRecorder<'_, G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn search_started(&mut self, settings: &SearchSettings) {
+        self.observer.search_started(settings);
+    }
+
+    fn population_initialized(&mut self, population: &[G]) {
+        self.observer.population_initialized(population);
+    }
+
+    fn evaluated(&mut self, epoch: usize, population: &[G], costs: &[f64], violations: &[f64]) {
+        self.observer
+            .evaluated(epoch, population, costs, violations);
+    }
+
+    fn selected(&mut self, epoch: usize, partner_indices: &[usize]) {
+        self.observer.selected(epoch, partner_indices);
+    }
+
+    fn child_created(&mut self, epoch: usize, child: &G, parents: (usize, usize), mutated: bool) {
+        self.genetic.child_created(child);
+        self.observer.child_created(epoch, child, parents, mutated);
+    }
+
+    fn child_evaluated(&mut self, epoch: usize, child: &G, outcome: &ChildOutcome) {
+        let outcome = outcome.oriented(self.objective);
+        self.usage.record(&outcome);
+        self.genetic.child_evaluated(child, &outcome);
+        self.observer.child_evaluated(epoch, child, &outcome);
+    }
+
+    fn epoch_ended(&mut self, snapshot: &ProgressSnapshot<G>) {
+        self.observer.epoch_ended(snapshot);
+    }
+
+    fn search_ended(&mut self, success: &Success<G>) {
+        self.observer.search_ended(success);
+    }
+}
+
+/// Return the lower of two costs, which are minimised.
+pub(crate) fn better_cost(lhs: f64, rhs: f64) -> f64 {
+    if compare_costs(rhs, lhs).is_lt() {
+        rhs
+    } else {
+        lhs
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{Diversity, HallOfFameEntry, OperatorUsage};
 use std::time::Duration;
 
 /// Define the progress reporting callback.
@@ -6,12 +7,19 @@ use std::time::Duration;
 pub type Progress<G> = Box<dyn FnMut(ProgressSnapshot<G>)>;
 
 /// Define a snapshot of the search progress.
+/// Costs are reported in the orientation of [`crate::SearchSettings::objective`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProgressSnapshot<'a, G> {
     epoch: usize,
     elapsed: Duration,
     best_cost: f64,
     best_genome: &'a G,
+    best_violation: f64,
+    diversity: Option<Diversity>,
+    stagnation: usize,
+    evaluations: usize,
+    hall_of_fame: &'a [HallOfFameEntry<G>],
+    operators: OperatorUsage,
 }
 
 impl<'a, G> ProgressSnapshot<'a, G> {
@@ -21,9 +29,45 @@ impl<'a, G> ProgressSnapshot<'a, G> {
             elapsed,
             best_cost,
             best_genome,
+            best_violation: 0.0,
+            diversity: None,
+            stagnation: 0,
+            evaluations: 0,
+            hall_of_fame: &[],
+            operators: OperatorUsage::default(),
         }
     }
 
+    pub(crate) fn with_best_violation(mut self, best_violation: f64) -> Self {
+        self.best_violation = best_violation;
+        self
+    }
+
+    pub(crate) fn with_hall_of_fame(mut self, hall_of_fame: &'a [HallOfFameEntry<G>]) -> Self {
+        self.hall_of_fame = hall_of_fame;
+        self
+    }
+
+    pub(crate) fn with_operators(mut self, operators: OperatorUsage) -> Self {
+        self.operators = operators;
+        self
+    }
+
+    pub(crate) fn with_evaluations(mut self, evaluations: usize) -> Self {
+        self.evaluations = evaluations;
+        self
+    }
+
+    pub(crate) fn with_stagnation(mut self, stagnation: usize) -> Self {
+        self.stagnation = stagnation;
+        self
+    }
+
+    pub(crate) fn with_diversity(mut self, diversity: Option<Diversity>) -> Self {
+        self.diversity = diversity;
+        self
+    }
+
     /// The number of epochs (iterations) the search took before terminating.
     pub fn epoch(&self) -> usize {
         self.epoch
@@ -45,4 +89,142 @@ impl<'a, G> ProgressSnapshot<'a, G> {
     pub fn best_genome(&self) -> &G {
         self.best_genome
     }
+
+    /// The constraint violation of the best genome, which is zero when it satisfies its
+    /// constraints.
+    pub fn best_violation(&self) -> f64 {
+        self.best_violation
+    }
+
+    /// The diversity of the current population.
+    /// This is `None` unless [`crate::Genetic::distance`] provides a measure of distance.
+    pub fn diversity(&self) -> Option<Diversity> {
+        self.diversity
+    }
+
+    /// The number of consecutive epochs, up to and including this one, in which the best genome
+    /// did not improve.
+    pub fn stagnation(&self) -> usize {
+        self.stagnation
+    }
+
+    /// The number of times [`crate::Genetic::evaluate`] has been called so far.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// The best distinct genomes found so far, best first.
+    /// This is empty unless requested with [`crate::SearchSettings::hall_of_fame`].
+    pub fn hall_of_fame(&self) -> &[HallOfFameEntry<G>] {
+        self.hall_of_fame
+    }
+
+    /// The statistics of the crossover and mutation operators in this epoch.
+    pub fn operators(&self) -> OperatorUsage {
+        self.operators
+    }
+
+    /// Return a copy of the snapshot that owns its best genome, so it can outlive the callback
+    /// and be sent to another thread.
+    pub fn to_owned_snapshot(&self) -> OwnedProgressSnapshot<G>
+    where
+        G: Clone,
+    {
+        OwnedProgressSnapshot {
+            epoch: self.epoch,
+            elapsed: self.elapsed,
+            best_cost: self.best_cost,
+            best_genome: self.best_genome.clone(),
+            best_violation: self.best_violation,
+            diversity: self.diversity,
+            stagnation: self.stagnation,
+            evaluations: self.evaluations,
+            hall_of_fame: self.hall_of_fame.to_vec(),
+            operators: self.operators,
+        }
+    }
+}
+
+/// Define a snapshot of the search progress that owns its best genome.
+///
+/// See [`ProgressSnapshot::to_owned_snapshot`] and [`crate::progress_channel`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedProgressSnapshot<G> {
+    epoch: usize,
+    elapsed: Duration,
+    best_cost: f64,
+    best_genome: G,
+    best_violation: f64,
+    diversity: Option<Diversity>,
+    stagnation: usize,
+    evaluations: usize,
+    hall_of_fame: Vec<HallOfFameEntry<G>>,
+    operators: OperatorUsage,
+}
+
+impl<G> OwnedProgressSnapshot<G> {
+    /// The number of epochs (iterations) the search had taken.
+    pub fn epoch(&self) -> usize {
+        self.epoch
+    }
+
+    /// The duration of the search.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// The cost of the best genome found by the search algorithm.
+    pub fn best_cost(&self) -> f64 {
+        self.best_cost
+    }
+
+    /// The best (lowest cost) genome found by the search algorithm.
+    pub fn best_genome(&self) -> &G {
+        &self.best_genome
+    }
+
+    /// Take ownership of the best genome.
+    pub fn into_best_genome(self) -> G {
+        self.best_genome
+    }
+
+    /// The constraint violation of the best genome, which is zero when it satisfies its
+    /// constraints.
+    pub fn best_violation(&self) -> f64 {
+        self.best_violation
+    }
+
+    /// The diversity of the population.
+    /// This is `None` unless [`crate::Genetic::distance`] provides a measure of distance.
+    pub fn diversity(&self) -> Option<Diversity> {
+        self.diversity
+    }
+
+    /// The number of consecutive epochs, up to and including this one, in which the best genome
+    /// did not improve.
+    pub fn stagnation(&self) -> usize {
+        self.stagnation
+    }
+
+    /// The number of times [`crate::Genetic::evaluate`] had been called.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// The best distinct genomes found, best first.
+    /// This is empty unless requested with [`crate::SearchSettings::hall_of_fame`].
+    pub fn hall_of_fame(&self) -> &[HallOfFameEntry<G>] {
+        &self.hall_of_fame
+    }
+
+    /// The statistics of the crossover and mutation operators in the epoch.
+    pub fn operators(&self) -> OperatorUsage {
+        self.operators
+    }
+}
+
+impl<G: Clone> From<ProgressSnapshot<'_, G>> for OwnedProgressSnapshot<G> {
+    fn from(snapshot: ProgressSnapshot<'_, G>) -> Self {
+        snapshot.to_owned_snapshot()
+    }
 }
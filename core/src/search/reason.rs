@@ -15,6 +15,23 @@ pub enum Reason {
 
     /// The search was requested to terminate.
     ///
-    /// See [`crate::Progress`]
+    /// See [`crate::Search::finish`] and [`crate::SearchHandle::cancel`].
     StopRequested,
+
+    /// The search terminated as [`crate::Genetic::evaluate`] had been called at least as many
+    /// times as its budget allowed.
+    EvaluationLimit(usize),
+
+    /// The search terminated as it reached a wall-clock deadline, after the given duration.
+    DeadlineReached(Duration),
+
+    /// The search terminated as the mean distance between genomes fell below a threshold.
+    DiversityCollapsed(f64),
+
+    /// The search terminated as the best cost improved by less than a threshold over a number
+    /// of epochs. This is the improvement that was made.
+    InsufficientImprovement(f64),
+
+    /// The search terminated for a reason given by a custom condition, with its message.
+    Custom(&'static str),
 }
@@ -1,13 +1,15 @@
-use crate::common::make_vec;
-use crate::search::progress::ProgressSnapshot;
+use crate::search::counting::CountingGenetic;
+use crate::search::tracker::Tracker;
 use crate::selector::Selector;
 use crate::{
-    largest, mean, ConcurrencySettings, Failure, Genetic, Progress, Reason, SearchSettings, Success,
+    compare_costs, ConcurrencySettings, Failure, Generational, Genetic, Inheritance, LocalSearch,
+    MemeticSettings, Niching, Observer, Observers, Progress, ProgressSnapshot, Reason,
+    SearchSettings, Strategy, Success, Terminator,
 };
 use rand::{thread_rng, Rng};
 use rayon::prelude::*;
 use std::fmt::Debug;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Search for a solution using a genetic algorithm.
 /// This is the main entry point for the crate.
@@ -21,116 +23,433 @@ use std::time::Instant;
 ///
 pub fn search<G>(
     genetic: Box<dyn Genetic<G> + Send + Sync>,
-    mut selector: Box<dyn Selector<G> + Send + Sync>,
+    selector: Box<dyn Selector<G> + Send + Sync>,
     mut progress: Option<Progress<G>>,
     settings: &SearchSettings,
 ) -> Result<Success<G>, Failure>
 where
     G: Clone + Debug + PartialEq + Send + Sync,
 {
-    let start_time = Instant::now();
-    let mut population: Vec<G> = make_vec(settings.population_size(), || genetic.initialize());
-    let mut replacement = population.clone();
-    let mut partner_indices = make_vec(settings.population_size(), || 0_usize);
-    let mut costs = make_vec(settings.population_size(), || 0.0);
-    let mut epoch = 0;
-    let mut best_cost = f64::MAX;
-    let mut best_index = 0;
-    let mut best_genome;
+    run(
+        genetic,
+        &mut Generational::new(selector),
+        &mut progress,
+        None,
+        settings,
+    )
+}
 
-    loop {
-        epoch += 1;
+/// Search for several distinct solutions using a genetic algorithm with niching.
+/// This is suitable for multimodal problems, where [`search`] would converge on a single
+/// solution.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `selector` - Define the algorithm used to select genome partners for cross over.
+/// * `niching` - Define how the population is divided into niches.
+/// * `progress` - Define the progress reporting callback.
+/// * `settings` - Configuration of genetic algorithm search.
+///
+pub fn search_niching<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    selector: Box<dyn Selector<G> + Send + Sync>,
+    niching: Niching<G>,
+    mut progress: Option<Progress<G>>,
+    settings: &SearchSettings,
+) -> Result<Success<G>, Failure>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    run(
+        genetic,
+        &mut Generational::with_niching(selector, niching),
+        &mut progress,
+        None,
+        settings,
+    )
+}
 
-        match settings.concurrency() {
-            ConcurrencySettings::MultiThreaded => {
-                costs.par_iter_mut().enumerate().for_each(|(i, c)| {
-                    *c = genetic.evaluate(population.get(i).unwrap());
-                });
-            }
-            ConcurrencySettings::SingleThreaded | ConcurrencySettings::Detect(_) => {
-                costs.iter_mut().enumerate().for_each(|(i, c)| {
-                    *c = genetic.evaluate(population.get(i).unwrap());
-                });
-            }
-        }
+/// Search for a solution using any search strategy.
+/// Progress reporting, termination and the result are the same for every strategy.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `strategy` - Define the search algorithm.
+/// * `progress` - Define the progress reporting callback.
+/// * `settings` - Configuration of the search.
+///
+pub fn search_with<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    mut strategy: Box<dyn Strategy<G> + Send + Sync>,
+    mut progress: Option<Progress<G>>,
+    settings: &SearchSettings,
+) -> Result<Success<G>, Failure>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    run(genetic, strategy.as_mut(), &mut progress, None, settings)
+}
 
-        selector.select(&population, &costs, &mut partner_indices);
+/// Search for a solution using any search strategy, notifying observers of every phase of the
+/// search.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `strategy` - Define the search algorithm.
+/// * `observers` - Define the observers of the search.
+/// * `settings` - Configuration of the search.
+///
+pub fn search_observed<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    mut strategy: Box<dyn Strategy<G> + Send + Sync>,
+    mut observers: Observers<G>,
+    settings: &SearchSettings,
+) -> Result<Success<G>, Failure>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    run(genetic, strategy.as_mut(), &mut observers, None, settings)
+}
 
-        for ((i, lhs), rhs_index) in
-            std::iter::zip(population.iter().enumerate(), partner_indices.iter())
-        {
-            let c = costs.get(i).unwrap();
-            if *c < best_cost {
-                best_cost = *c;
-                best_index = i;
-            }
+/// Search for a solution using any search strategy, notifying observers of every phase of the
+/// search, until a condition is met.
+/// The condition replaces the limits in the settings, which can be kept by combining it with
+/// [`crate::AnyOf::limits`].
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `strategy` - Define the search algorithm.
+/// * `observers` - Define the observers of the search.
+/// * `terminator` - Define the condition that terminates the search.
+/// * `settings` - Configuration of the search.
+///
+pub fn search_until<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    mut strategy: Box<dyn Strategy<G> + Send + Sync>,
+    mut observers: Observers<G>,
+    mut terminator: Box<dyn Terminator<G>>,
+    settings: &SearchSettings,
+) -> Result<Success<G>, Failure>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    run(
+        genetic,
+        strategy.as_mut(),
+        &mut observers,
+        Some(terminator.as_mut()),
+        settings,
+    )
+}
 
-            let rhs = population.get(*rhs_index).unwrap();
+/// A search that is advanced one epoch at a time, for embedding in games, simulations and
+/// user interfaces.
+///
+/// Between epochs the population can be inspected, the settings changed and genomes injected.
+/// Call [`Search::finish`] to get the result.
+pub struct Search<G> {
+    genetic: CountingGenetic<G>,
+    strategy: Box<dyn Strategy<G> + Send + Sync>,
+    observers: Observers<G>,
+    terminator: Option<Box<dyn Terminator<G>>>,
+    settings: SearchSettings,
+    tracker: Tracker<G>,
+    reason: Option<Reason>,
+}
 
-            let cross = if thread_rng().gen_bool(0.5) {
-                genetic.crossover(lhs, rhs)
-            } else {
-                genetic.crossover(rhs, lhs)
-            };
+impl<G> Search<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    /// Create a search and evaluate its initial population, which is the first epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `genetic` - Define the genetic operations on a chromosome `G`.
+    /// * `strategy` - Define the search algorithm.
+    /// * `progress` - Define the progress reporting callback.
+    /// * `settings` - Configuration of the search.
+    ///
+    pub fn new(
+        genetic: Box<dyn Genetic<G> + Send + Sync>,
+        strategy: Box<dyn Strategy<G> + Send + Sync>,
+        progress: Option<Progress<G>>,
+        settings: &SearchSettings,
+    ) -> Result<Self, Failure> {
+        let mut observers = Observers::new();
+        if let Some(progress) = progress {
+            observers.push(Box::new(progress));
+        }
+        Self::with_observers(genetic, strategy, observers, settings)
+    }
 
-            let mutant = if thread_rng().gen_bool(settings.mutation_probability()) {
-                genetic.mutate(&cross)
-            } else {
-                cross
-            };
+    /// Create a search that notifies observers of every phase of the search, and evaluate its
+    /// initial population, which is the first epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `genetic` - Define the genetic operations on a chromosome `G`.
+    /// * `strategy` - Define the search algorithm.
+    /// * `observers` - Define the observers of the search.
+    /// * `settings` - Configuration of the search.
+    ///
+    pub fn with_observers(
+        genetic: Box<dyn Genetic<G> + Send + Sync>,
+        mut strategy: Box<dyn Strategy<G> + Send + Sync>,
+        mut observers: Observers<G>,
+        settings: &SearchSettings,
+    ) -> Result<Self, Failure> {
+        let start_time = Instant::now();
+        let genetic = CountingGenetic::new(genetic, settings);
+        observers.search_started(settings);
+        strategy.initialize(&genetic, settings)?;
+        observers.population_initialized(strategy.population());
+        let mut tracker = Tracker::new(start_time, strategy.as_ref(), settings);
+        let reason =
+            tracker.observe(&genetic, strategy.as_ref(), &mut observers, None, settings)?;
+        Ok(Self {
+            genetic,
+            strategy,
+            observers,
+            terminator: None,
+            settings: *settings,
+            tracker,
+            reason,
+        })
+    }
 
-            replacement.push(mutant);
+    /// Set the condition that terminates the search in place of the limits in the settings.
+    /// Combine it with [`crate::AnyOf::limits`] to keep them.
+    /// It replaces any condition set before, and is first checked for the current epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminator` - The condition that terminates the search.
+    ///
+    pub fn with_terminator(mut self, mut terminator: Box<dyn Terminator<G>>) -> Self {
+        if self.reason.is_none() {
+            self.reason = terminator.terminate(&self.tracker.snapshot());
         }
-        best_genome = &population[best_index];
+        self.terminator = Some(terminator);
+        self
+    }
 
-        let elapsed = Instant::now() - start_time;
+    /// Advance the search by one epoch.
+    /// Return the reason the search should terminate, if any.
+    /// Stepping a search that should terminate continues it anyway.
+    /// Fails if a NaN cost is found and [`crate::NanPolicy::Reject`] is set.
+    pub fn step(&mut self) -> Result<Option<Reason>, Failure> {
+        self.tracker.step(
+            &self.genetic,
+            self.strategy.as_mut(),
+            &mut self.observers,
+            &self.settings,
+        );
+        self.reason = self.tracker.observe(
+            &self.genetic,
+            self.strategy.as_ref(),
+            &mut self.observers,
+            self.terminator.as_deref_mut(),
+            &self.settings,
+        )?;
+        Ok(self.reason)
+    }
 
-        if progress.is_some() {
-            progress.as_mut().unwrap()(ProgressSnapshot::new(
-                epoch,
-                elapsed,
-                best_cost,
-                best_genome,
-            ));
+    /// Advance the search until it terminates and return the result.
+    pub fn run(mut self) -> Result<Success<G>, Failure> {
+        while self.reason.is_none() {
+            self.step()?;
         }
+        Ok(self.finish())
+    }
+
+    /// Return the result of the search so far.
+    /// The reason is [`Reason::StopRequested`] if the search has not terminated.
+    pub fn finish(mut self) -> Success<G> {
+        let success = self.tracker.success(
+            self.reason.take().unwrap_or(Reason::StopRequested),
+            &self.genetic,
+            self.strategy.as_ref(),
+        );
+        self.observers.search_ended(&success);
+        success
+    }
+
+    /// The reason the search should terminate after the current epoch, if any.
+    pub fn reason(&self) -> Option<Reason> {
+        self.reason
+    }
+
+    /// The number of epochs so far, including the initial population.
+    pub fn epoch(&self) -> usize {
+        self.tracker.epoch()
+    }
+
+    /// The progress of the search up to and including the current epoch.
+    pub fn snapshot(&self) -> ProgressSnapshot<'_, G> {
+        self.tracker.snapshot()
+    }
+
+    /// The cost of the best genome found so far.
+    pub fn best_cost(&self) -> f64 {
+        self.tracker.best_cost()
+    }
+
+    /// The best (lowest cost) genome found so far.
+    pub fn best_genome(&self) -> &G {
+        self.tracker.best_genome()
+    }
+
+    /// The current population.
+    pub fn population(&self) -> &[G] {
+        self.strategy.population()
+    }
+
+    /// The cost of each genome in the current population.
+    /// With Baldwinian inheritance these are the costs of the genomes after local search.
+    pub fn costs(&self) -> Vec<f64> {
+        self.tracker.oriented(self.strategy.costs()).into_owned()
+    }
+
+    /// The constraint violation of each genome in the current population.
+    pub fn violations(&self) -> &[f64] {
+        self.strategy.violations()
+    }
 
-        if epoch == settings.epoch_limit() {
-            return Ok(Success::new(
-                Reason::Epoch(epoch),
-                epoch,
-                elapsed,
-                best_cost,
-                mean(&costs),
-                largest(&costs),
-                best_genome.clone(),
-            ));
+    /// The configuration of the search.
+    pub fn settings(&self) -> &SearchSettings {
+        &self.settings
+    }
+
+    /// Change the configuration of the search from the next epoch.
+    /// The population size only applies when the search is created.
+    pub fn set_settings(&mut self, settings: &SearchSettings) {
+        self.settings = *settings;
+    }
+
+    /// Evaluate genomes and put them into the current population in place of the worst genomes.
+    /// Genomes beyond the size of the population are ignored.
+    /// Fails if a NaN cost is found and [`crate::NanPolicy::Reject`] is set.
+    pub fn inject(&mut self, genomes: Vec<G>) -> Result<(), Failure> {
+        let costs = self.strategy.costs();
+        let violations = self.strategy.violations();
+        let mut order: Vec<usize> = (0..costs.len()).collect();
+        order.sort_by(|a, b| {
+            violations[*b]
+                .max(0.0)
+                .total_cmp(&violations[*a].max(0.0))
+                .then(compare_costs(costs[*b], costs[*a]))
+        });
+
+        for (index, genome) in std::iter::zip(order, genomes) {
+            let cost = self.genetic.evaluate(&genome);
+            let violation = self.genetic.violation(&genome);
+            self.tracker.track(&genome, cost, violation);
+            self.strategy.replace(index, genome, cost, violation);
         }
+        self.genetic.check()
+    }
+}
+
+pub(crate) fn run<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    strategy: &mut dyn Strategy<G>,
+    observer: &mut dyn Observer<G>,
+    mut terminator: Option<&mut (dyn Terminator<G> + '_)>,
+    settings: &SearchSettings,
+) -> Result<Success<G>, Failure>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    let start_time = Instant::now();
+    let genetic = CountingGenetic::new(genetic, settings);
+    observer.search_started(settings);
+    strategy.initialize(&genetic, settings)?;
+    observer.population_initialized(strategy.population());
+    let mut tracker = Tracker::new(start_time, strategy, settings);
 
-        if best_cost <= settings.cost_target() {
-            return Ok(Success::new(
-                Reason::CostTargetReached(best_cost),
-                epoch,
-                elapsed,
-                best_cost,
-                mean(&costs),
-                largest(&costs),
-                best_genome.clone(),
-            ));
+    loop {
+        if let Some(reason) = tracker.observe(
+            &genetic,
+            strategy,
+            observer,
+            terminator.as_deref_mut(),
+            settings,
+        )? {
+            let success = tracker.success(reason, &genetic, strategy);
+            observer.search_ended(&success);
+            return Ok(success);
         }
+        tracker.step(&genetic, strategy, observer, settings);
+    }
+}
+
+/// Return the local search to apply in the given epoch, if memetic search is enabled.
+pub(crate) fn local_search<'a, G>(
+    genetic: &'a (dyn Genetic<G> + Send + Sync),
+    settings: &SearchSettings,
+    epoch: usize,
+) -> Option<(MemeticSettings, &'a dyn LocalSearch<G>)>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    settings
+        .memetic()
+        .filter(|memetic| (epoch - 1) % memetic.interval() == 0)
+        .zip(genetic.local_search())
+}
 
-        if elapsed >= settings.time_limit() {
-            return Ok(Success::new(
-                Reason::TimeOut(elapsed),
-                epoch,
-                elapsed,
-                best_cost,
-                mean(&costs),
-                largest(&costs),
-                best_genome.clone(),
-            ));
+/// Evaluate the cost and constraint violation of every genome, first improving genomes by
+/// local search when `local` is present.
+/// Return the time spent in local search.
+pub(crate) fn evaluate<G>(
+    genetic: &(dyn Genetic<G> + Send + Sync),
+    concurrency: ConcurrencySettings,
+    local: Option<(MemeticSettings, &dyn LocalSearch<G>)>,
+    population: &mut [G],
+    costs: &mut [f64],
+    violations: &mut [f64],
+) -> Duration
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    let evaluate_one = |genome: &mut G, c: &mut f64, v: &mut f64| -> Duration {
+        match local.filter(|(memetic, _)| thread_rng().gen_bool(memetic.probability())) {
+            Some((memetic, local_search)) => {
+                let start_time = Instant::now();
+                let improved = local_search.improve(genome);
+                let elapsed = Instant::now() - start_time;
+                *c = genetic.evaluate(&improved);
+                *v = genetic.violation(&improved);
+                if memetic.inheritance() == Inheritance::Lamarckian {
+                    *genome = improved;
+                }
+                elapsed
+            }
+            None => {
+                *c = genetic.evaluate(genome);
+                *v = genetic.violation(genome);
+                Duration::ZERO
+            }
         }
+    };
 
-        std::mem::swap(&mut population, &mut replacement);
-        replacement.clear();
+    match concurrency {
+        ConcurrencySettings::MultiThreaded => population
+            .par_iter_mut()
+            .zip(costs.par_iter_mut())
+            .zip(violations.par_iter_mut())
+            .map(|((genome, c), v)| evaluate_one(genome, c, v))
+            .sum(),
+        ConcurrencySettings::SingleThreaded | ConcurrencySettings::Detect(_) => population
+            .iter_mut()
+            .zip(costs.iter_mut())
+            .zip(violations.iter_mut())
+            .map(|((genome, c), v)| evaluate_one(genome, c, v))
+            .sum(),
     }
 }
@@ -0,0 +1,225 @@
+use crate::{
+    Failure, Genetic, ProgressSender, Search, SearchSettings, Strategy, Success, Terminator,
+};
+use std::fmt::Debug;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+
+/// Start a search on a new thread and return a handle to control it.
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `strategy` - Define the search algorithm.
+/// * `progress` - Define where progress is sent. See [`crate::progress_channel`].
+/// * `settings` - Configuration of the search.
+///
+pub fn spawn<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    strategy: Box<dyn Strategy<G> + Send + Sync>,
+    progress: Option<ProgressSender<G>>,
+    settings: &SearchSettings,
+) -> SearchHandle<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    start(genetic, strategy, progress, None, settings)
+}
+
+/// Start a search on a new thread that runs until a condition is met, and return a handle to
+/// control it.
+/// The condition replaces the limits in the settings, which can be kept by combining it with
+/// [`crate::AnyOf::limits`].
+///
+/// # Arguments
+///
+/// * `genetic` - Define the genetic operations on a chromosome `G`.
+/// * `strategy` - Define the search algorithm.
+/// * `progress` - Define where progress is sent. See [`crate::progress_channel`].
+/// * `terminator` - Define the condition that terminates the search.
+/// * `settings` - Configuration of the search.
+///
+pub fn spawn_until<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    strategy: Box<dyn Strategy<G> + Send + Sync>,
+    progress: Option<ProgressSender<G>>,
+    terminator: Box<dyn Terminator<G> + Send>,
+    settings: &SearchSettings,
+) -> SearchHandle<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    start(genetic, strategy, progress, Some(terminator), settings)
+}
+
+fn start<G>(
+    genetic: Box<dyn Genetic<G> + Send + Sync>,
+    strategy: Box<dyn Strategy<G> + Send + Sync>,
+    progress: Option<ProgressSender<G>>,
+    terminator: Option<Box<dyn Terminator<G> + Send>>,
+    settings: &SearchSettings,
+) -> SearchHandle<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    let shared = Arc::new(Shared {
+        control: Mutex::new(Control::Running),
+        changed: Condvar::new(),
+        latest: Mutex::new(Latest {
+            epoch: 0,
+            best_cost: None,
+            best_genome: None,
+        }),
+    });
+    let settings = *settings;
+    let thread = {
+        let shared = shared.clone();
+        std::thread::spawn(move || {
+            let progress = progress.map(ProgressSender::into_progress);
+            let mut search = Search::new(genetic, strategy, progress, &settings)?;
+            if let Some(terminator) = terminator {
+                search = search.with_terminator(terminator);
+            }
+            shared.publish(&search);
+            while search.reason().is_none() {
+                if shared.wait() == Control::Cancelled {
+                    break;
+                }
+                search.step()?;
+                shared.publish(&search);
+            }
+            Ok(search.finish())
+        })
+    };
+    SearchHandle {
+        shared,
+        thread: Some(thread),
+    }
+}
+
+/// A handle to a search running on another thread.
+/// Dropping the handle cancels the search, which stops after the current epoch.
+///
+/// See [`spawn`].
+pub struct SearchHandle<G> {
+    shared: Arc<Shared<G>>,
+    thread: Option<JoinHandle<Result<Success<G>, Failure>>>,
+}
+
+impl<G> SearchHandle<G>
+where
+    G: Clone,
+{
+    /// The number of epochs completed so far.
+    pub fn epoch(&self) -> usize {
+        self.shared.latest.lock().unwrap().epoch
+    }
+
+    /// The cost of the best genome found so far, or `None` before the initial population has
+    /// been evaluated.
+    pub fn best_cost(&self) -> Option<f64> {
+        self.shared.latest.lock().unwrap().best_cost
+    }
+
+    /// The best genome found so far, or `None` before the initial population has been
+    /// evaluated.
+    pub fn best_genome(&self) -> Option<G> {
+        self.shared.latest.lock().unwrap().best_genome.clone()
+    }
+
+    /// Pause the search after the current epoch.
+    pub fn pause(&self) {
+        self.shared.control(Control::Paused);
+    }
+
+    /// Resume a paused search.
+    pub fn resume(&self) {
+        self.shared.control(Control::Running);
+    }
+
+    /// Stop the search after the current epoch.
+    /// The result of [`SearchHandle::join`] then has the reason [`crate::Reason::StopRequested`],
+    /// unless the search had already terminated.
+    pub fn cancel(&self) {
+        self.shared.control(Control::Cancelled);
+    }
+
+    /// Return true if the search has finished.
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().map_or(true, JoinHandle::is_finished)
+    }
+
+    /// Wait for the search to finish and return its result.
+    /// A paused search is resumed first.
+    pub fn join(mut self) -> Result<Success<G>, Failure> {
+        self.resume();
+        let thread = self.thread.take().expect("the search has not been joined");
+        match thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+impl<G> Drop for SearchHandle<G> {
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            self.shared.control(Control::Cancelled);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Control {
+    Running,
+    Paused,
+    Cancelled,
+}
+
+struct Latest<G> {
+    epoch: usize,
+    best_cost: Option<f64>,
+    best_genome: Option<G>,
+}
+
+/// The state shared between a search thread and its handle.
+struct Shared<G> {
+    control: Mutex<Control>,
+    changed: Condvar,
+    latest: Mutex<Latest<G>>,
+}
+
+impl<G> Shared<G> {
+    fn control(&self, control: Control) {
+        let mut current = self.control.lock().unwrap();
+        if *current != Control::Cancelled {
+            *current = control;
+        }
+        self.changed.notify_all();
+    }
+
+    /// Block while the search is paused and return the control that ended the wait.
+    fn wait(&self) -> Control {
+        let current = self.control.lock().unwrap();
+        let current = self
+            .changed
+            .wait_while(current, |control| *control == Control::Paused)
+            .unwrap();
+        *current
+    }
+}
+
+impl<G> Shared<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync + 'static,
+{
+    /// Make the latest progress of the search visible to the handle.
+    fn publish(&self, search: &Search<G>) {
+        let mut latest = self.latest.lock().unwrap();
+        latest.epoch = search.epoch();
+        if search.snapshot().stagnation() == 0 {
+            latest.best_cost = Some(search.best_cost());
+            latest.best_genome = Some(search.best_genome().clone());
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use crate::Reason;
+use crate::{Diversity, HallOfFameEntry, OperatorUsage, Reason};
 use std::time::Duration;
 
 /// Define the successful outcome of a genetic algorithm search.
+/// Costs are reported in the orientation of [`crate::SearchSettings::objective`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct Success<G> {
     reason: Reason,
@@ -10,7 +11,16 @@ pub struct Success<G> {
     best_cost: f64,
     mean_cost: f64,
     worst_cost: f64,
+    best_violation: f64,
     best_genome: G,
+    representatives: Vec<(G, f64)>,
+    population: Vec<(G, f64)>,
+    hall_of_fame: Vec<HallOfFameEntry<G>>,
+    diversity: Option<Diversity>,
+    restarts: usize,
+    local_search_time: Duration,
+    evaluations: usize,
+    operators: OperatorUsage,
 }
 
 impl<G> Success<G> {
@@ -30,10 +40,80 @@ impl<G> Success<G> {
             best_cost,
             mean_cost,
             worst_cost,
+            best_violation: 0.0,
             best_genome,
+            representatives: Vec::new(),
+            population: Vec::new(),
+            hall_of_fame: Vec::new(),
+            diversity: None,
+            restarts: 0,
+            local_search_time: Duration::ZERO,
+            evaluations: 0,
+            operators: OperatorUsage::default(),
         }
     }
 
+    pub(crate) fn with_best_violation(mut self, best_violation: f64) -> Self {
+        self.best_violation = best_violation;
+        self
+    }
+
+    pub(crate) fn with_representatives(mut self, representatives: Vec<(G, f64)>) -> Self {
+        self.representatives = representatives;
+        self
+    }
+
+    pub(crate) fn with_population(mut self, population: Vec<(G, f64)>) -> Self {
+        self.population = population;
+        self
+    }
+
+    /// The hall of fame costs are already in the orientation of the objective.
+    pub(crate) fn with_hall_of_fame(mut self, hall_of_fame: Vec<HallOfFameEntry<G>>) -> Self {
+        self.hall_of_fame = hall_of_fame;
+        self
+    }
+
+    pub(crate) fn with_diversity(mut self, diversity: Option<Diversity>) -> Self {
+        self.diversity = diversity;
+        self
+    }
+
+    pub(crate) fn with_restarts(mut self, restarts: usize) -> Self {
+        self.restarts = restarts;
+        self
+    }
+
+    pub(crate) fn with_evaluations(mut self, evaluations: usize) -> Self {
+        self.evaluations = evaluations;
+        self
+    }
+
+    /// Convert every cost, which is minimised while searching, with `orient`.
+    pub(crate) fn with_costs_oriented(mut self, orient: impl Fn(f64) -> f64) -> Self {
+        self.best_cost = orient(self.best_cost);
+        self.mean_cost = orient(self.mean_cost);
+        self.worst_cost = orient(self.worst_cost);
+        for (_, cost) in self
+            .representatives
+            .iter_mut()
+            .chain(self.population.iter_mut())
+        {
+            *cost = orient(*cost);
+        }
+        self
+    }
+
+    pub(crate) fn with_operators(mut self, operators: OperatorUsage) -> Self {
+        self.operators = operators;
+        self
+    }
+
+    pub(crate) fn with_local_search_time(mut self, local_search_time: Duration) -> Self {
+        self.local_search_time = local_search_time;
+        self
+    }
+
     /// The reason the search terminated.
     pub fn reason(&self) -> Reason {
         self.reason
@@ -65,9 +145,75 @@ impl<G> Success<G> {
         self.worst_cost
     }
 
-    /// The best (lowest cost) genome found by the search algorithm.
+    /// The constraint violation of the best genome found by the search algorithm.
+    /// See [`crate::Genetic::violation`].
+    pub fn best_violation(&self) -> f64 {
+        self.best_violation
+    }
+
+    /// True when the best genome found by the search algorithm satisfies all constraints.
+    pub fn feasible(&self) -> bool {
+        self.best_violation <= 0.0
+    }
+
+    /// The best (lowest cost, or highest when maximising) genome found by the search algorithm.
     /// This is the genome associated with [`Success::best_cost`].
     pub fn best_genome(&self) -> &G {
         &self.best_genome
     }
+
+    /// The best genome of each niche in the final generation, with its cost, best first.
+    /// This is empty unless requested with [`crate::Niching`].
+    pub fn representatives(&self) -> &[(G, f64)] {
+        &self.representatives
+    }
+
+    /// The final population with the cost of each genome, best first.
+    /// Feasible genomes come before infeasible genomes, which are ordered by violation.
+    /// This is empty unless requested with [`crate::SearchSettings::keep_population`].
+    pub fn population(&self) -> &[(G, f64)] {
+        &self.population
+    }
+
+    /// Take ownership of the final population.
+    /// See [`Success::population`] and [`crate::SeededGenetic`].
+    pub fn into_population(self) -> Vec<(G, f64)> {
+        self.population
+    }
+
+    /// The best distinct genomes found at any epoch of the search, best first.
+    /// This is empty unless requested with [`crate::SearchSettings::hall_of_fame`].
+    pub fn hall_of_fame(&self) -> &[HallOfFameEntry<G>] {
+        &self.hall_of_fame
+    }
+
+    /// The diversity of the final generation.
+    /// This is `None` unless [`crate::Genetic::distance`] provides a measure of distance.
+    pub fn diversity(&self) -> Option<Diversity> {
+        self.diversity
+    }
+
+    /// The number of times the search restarted from a fresh population.
+    /// See [`crate::RestartSettings`].
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// The time spent improving genomes by local search, included in [`Success::elapsed`].
+    /// When genomes are evaluated in parallel, this is the total over all threads.
+    /// See [`crate::MemeticSettings`].
+    pub fn local_search_time(&self) -> Duration {
+        self.local_search_time
+    }
+
+    /// The number of times [`crate::Genetic::evaluate`] was called during the search,
+    /// including calls made by local search.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// The statistics of the crossover and mutation operators over the whole search.
+    pub fn operators(&self) -> OperatorUsage {
+        self.operators
+    }
 }
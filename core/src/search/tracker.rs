@@ -0,0 +1,286 @@
+use crate::search::counting::CountingGenetic;
+use crate::search::{HallOfFame, OperatorRecorder};
+use crate::{
+    compare_costs, largest, mean, AnyOf, Diversity, Failure, Genetic, HallOfFameEntry, Inheritance,
+    Objective, Observer, OperatorUsage, ProgressSnapshot, Reason, SearchSettings, Strategy,
+    Success, Terminator,
+};
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+/// Track the state shared by every search strategy: the epoch, the best genome found so far and
+/// how long it has stagnated.
+/// Costs are minimised internally and reported in the orientation of the objective.
+pub(crate) struct Tracker<G> {
+    start_time: Instant,
+    objective: Objective,
+    epoch: usize,
+    elapsed: Duration,
+    best_cost: f64,
+    best_violation: f64,
+    best_genome: G,
+    stagnation: usize,
+    diversity: Option<Diversity>,
+    evaluations: usize,
+    hall_of_fame: Option<HallOfFame<G>>,
+    keep_population: bool,
+    baldwinian: bool,
+    operators: OperatorUsage,
+    total_operators: OperatorUsage,
+}
+
+impl<G> Tracker<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    /// Start tracking an initialized strategy.
+    pub(crate) fn new(
+        start_time: Instant,
+        strategy: &dyn Strategy<G>,
+        settings: &SearchSettings,
+    ) -> Self {
+        Self {
+            start_time,
+            objective: settings.objective(),
+            epoch: 0,
+            elapsed: Duration::ZERO,
+            best_cost: f64::INFINITY,
+            best_violation: f64::INFINITY,
+            best_genome: strategy.population()[0].clone(),
+            stagnation: 0,
+            diversity: None,
+            evaluations: 0,
+            hall_of_fame: settings
+                .hall_of_fame()
+                .map(|hall_of_fame| HallOfFame::new(hall_of_fame, settings.objective())),
+            keep_population: settings.keep_population(),
+            baldwinian: settings
+                .memetic()
+                .is_some_and(|memetic| memetic.inheritance() == Inheritance::Baldwinian),
+            operators: OperatorUsage::default(),
+            total_operators: OperatorUsage::default(),
+        }
+    }
+
+    /// Record the current population of `strategy` as the next epoch and notify the observer.
+    /// Return the reason the search should terminate after this epoch, if any, or fail if a NaN
+    /// cost has been rejected.
+    /// Without a terminator the limits in the settings apply.
+    pub(crate) fn observe(
+        &mut self,
+        genetic: &CountingGenetic<G>,
+        strategy: &dyn Strategy<G>,
+        observer: &mut dyn Observer<G>,
+        terminator: Option<&mut (dyn Terminator<G> + '_)>,
+        settings: &SearchSettings,
+    ) -> Result<Option<Reason>, Failure> {
+        genetic.check()?;
+        self.epoch += 1;
+        observer.evaluated(
+            self.epoch,
+            strategy.population(),
+            &self.oriented(strategy.costs()),
+            strategy.violations(),
+        );
+        let distance = genetic.distance().or_else(|| strategy.distance());
+        let mut improved = false;
+        for (i, genome) in strategy.population().iter().enumerate() {
+            let (cost, violation) = (strategy.costs()[i], strategy.violations()[i]);
+            if !self.baldwinian {
+                improved |= self.track(genome, cost, violation);
+            }
+            if let Some(hall_of_fame) = self.hall_of_fame.as_mut() {
+                if !self.baldwinian {
+                    hall_of_fame.consider(genome, cost, violation, self.epoch, distance);
+                } else if hall_of_fame.admits(cost, violation) {
+                    // The cost after local search is no worse than the genome's own cost, so
+                    // only genomes that could enter the hall of fame are evaluated again.
+                    let (cost, violation) = (genetic.evaluate(genome), genetic.violation(genome));
+                    hall_of_fame.consider(genome, cost, violation, self.epoch, distance);
+                }
+            }
+        }
+        if self.baldwinian {
+            improved = self.track_baldwinian(genetic, strategy);
+        }
+        self.stagnation = if improved { 0 } else { self.stagnation + 1 };
+
+        self.elapsed = Instant::now() - self.start_time;
+        self.evaluations = genetic.evaluations();
+        self.diversity =
+            distance.map(|distance| Diversity::measure(strategy.population(), distance));
+
+        let snapshot = self.snapshot();
+        observer.epoch_ended(&snapshot);
+
+        Ok(match terminator {
+            Some(terminator) => terminator.terminate(&snapshot),
+            None => AnyOf::limits(settings).terminate(&snapshot),
+        })
+    }
+
+    /// Advance `strategy` by one epoch, recording the operator usage of the children it creates.
+    pub(crate) fn step(
+        &mut self,
+        genetic: &CountingGenetic<G>,
+        strategy: &mut dyn Strategy<G>,
+        observer: &mut dyn Observer<G>,
+        settings: &SearchSettings,
+    ) {
+        let mut operators = OperatorUsage::default();
+        let mut recorder = OperatorRecorder::new(genetic, observer, &mut operators, self.objective);
+        strategy.step(genetic, settings, &self.snapshot(), &mut recorder);
+        self.operators = operators;
+        self.total_operators.add(&operators);
+    }
+
+    /// Keep `genome` if it is better than the best genome so far.
+    /// Feasible genomes are compared by cost, infeasible genomes by violation.
+    /// Return true if it was kept.
+    pub(crate) fn track(&mut self, genome: &G, cost: f64, violation: f64) -> bool {
+        let violation = violation.max(0.0);
+        let better = compare_costs(violation, self.best_violation)
+            .then(compare_costs(cost, self.best_cost))
+            .is_lt();
+        if better {
+            self.best_cost = cost;
+            self.best_violation = violation;
+            self.best_genome = genome.clone();
+        }
+        better
+    }
+
+    /// Keep the genome with the best cost after local search if its own cost is better than the
+    /// best genome so far.
+    /// With Baldwinian inheritance the costs of the population are those of the improved
+    /// genomes, so the candidate is evaluated again to keep the best cost and genome consistent.
+    /// Return true if it was kept.
+    fn track_baldwinian(
+        &mut self,
+        genetic: &CountingGenetic<G>,
+        strategy: &dyn Strategy<G>,
+    ) -> bool {
+        let costs = strategy.costs();
+        let violations = strategy.violations();
+        let candidate = (0..costs.len()).min_by(|a, b| {
+            compare_costs(violations[*a].max(0.0), violations[*b].max(0.0))
+                .then(compare_costs(costs[*a], costs[*b]))
+        });
+        let Some(candidate) = candidate else {
+            return false;
+        };
+        let better = compare_costs(violations[candidate].max(0.0), self.best_violation)
+            .then(compare_costs(costs[candidate], self.best_cost))
+            .is_lt();
+        if !better {
+            return false;
+        }
+        let genome = &strategy.population()[candidate];
+        self.track(genome, genetic.evaluate(genome), genetic.violation(genome))
+    }
+
+    /// The progress of the search up to and including the current epoch.
+    pub(crate) fn snapshot(&self) -> ProgressSnapshot<'_, G> {
+        ProgressSnapshot::new(
+            self.epoch,
+            self.elapsed,
+            self.best_cost(),
+            &self.best_genome,
+        )
+        .with_best_violation(self.best_violation)
+        .with_diversity(self.diversity)
+        .with_stagnation(self.stagnation)
+        .with_evaluations(self.evaluations)
+        .with_hall_of_fame(self.hall_of_fame())
+        .with_operators(self.operators)
+    }
+
+    /// The result of the search, terminated for `reason` after the current epoch.
+    /// With Baldwinian inheritance the costs of the population are those of the improved
+    /// genomes, so the population is evaluated again to report each genome's own cost.
+    pub(crate) fn success(
+        &self,
+        reason: Reason,
+        genetic: &CountingGenetic<G>,
+        strategy: &dyn Strategy<G>,
+    ) -> Success<G> {
+        let (costs, violations) = if self.baldwinian {
+            let population = strategy.population();
+            (
+                Cow::Owned(population.iter().map(|g| genetic.evaluate(g)).collect()),
+                Cow::Owned(population.iter().map(|g| genetic.violation(g)).collect()),
+            )
+        } else {
+            (
+                Cow::Borrowed(strategy.costs()),
+                Cow::Borrowed(strategy.violations()),
+            )
+        };
+        let success = Success::new(
+            reason,
+            self.epoch,
+            self.elapsed,
+            self.best_cost,
+            mean(&costs),
+            largest(&costs),
+            self.best_genome.clone(),
+        )
+        .with_best_violation(self.best_violation)
+        .with_diversity(self.diversity)
+        .with_evaluations(self.evaluations)
+        .with_operators(self.total_operators)
+        .with_local_search_time(strategy.local_search_time())
+        .with_population(self.population(strategy.population(), &costs, &violations));
+        strategy
+            .finish(success)
+            .with_costs_oriented(|cost| self.objective.orient(cost))
+            .with_hall_of_fame(self.hall_of_fame().to_vec())
+    }
+
+    /// The population with its costs, best first, if it is to be kept.
+    fn population(&self, population: &[G], costs: &[f64], violations: &[f64]) -> Vec<(G, f64)> {
+        if !self.keep_population {
+            return Vec::new();
+        }
+        let mut order: Vec<usize> = (0..costs.len()).collect();
+        order.sort_by(|a, b| {
+            compare_costs(violations[*a].max(0.0), violations[*b].max(0.0))
+                .then(compare_costs(costs[*a], costs[*b]))
+        });
+        order
+            .into_iter()
+            .map(|i| (population[i].clone(), costs[i]))
+            .collect()
+    }
+
+    /// The best distinct genomes found so far, best first.
+    fn hall_of_fame(&self) -> &[HallOfFameEntry<G>] {
+        self.hall_of_fame
+            .as_ref()
+            .map_or(&[], |hall_of_fame| hall_of_fame.entries())
+    }
+
+    /// Return costs in the orientation of the objective.
+    pub(crate) fn oriented<'a>(&self, costs: &'a [f64]) -> Cow<'a, [f64]> {
+        match self.objective {
+            Objective::Minimise => Cow::Borrowed(costs),
+            Objective::Maximise => costs.iter().map(|c| self.objective.orient(*c)).collect(),
+        }
+    }
+
+    /// The number of epochs so far.
+    pub(crate) fn epoch(&self) -> usize {
+        self.epoch
+    }
+
+    /// The cost of the best genome found so far, in the orientation of the objective.
+    pub(crate) fn best_cost(&self) -> f64 {
+        self.objective.orient(self.best_cost)
+    }
+
+    /// The best genome found so far.
+    pub(crate) fn best_genome(&self) -> &G {
+        &self.best_genome
+    }
+}
@@ -0,0 +1,201 @@
+use crate::selector::Selector;
+use crate::{compare_costs, Failure};
+
+/// Define how constraint violations are combined with costs when selecting partners.
+///
+/// See [`crate::Genetic::violation`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// Deb's feasibility rules:
+    /// * A feasible genome is preferred to an infeasible one.
+    /// * Feasible genomes are compared by cost.
+    /// * Infeasible genomes are compared by violation.
+    Feasibility,
+
+    /// Add a fixed multiple of the violation to the cost.
+    StaticPenalty { coefficient: f64 },
+
+    /// Add `(coefficient * epoch) ^ exponent` times the violation to the cost, so that the
+    /// pressure towards feasibility increases as the search progresses.
+    DynamicPenalty { coefficient: f64, exponent: f64 },
+
+    /// Add an adaptive multiple of the violation to the cost (Bean and Hadj-Alouane).
+    /// The multiple starts at `initial`.
+    /// It is multiplied by `increase` when the best genome has been infeasible for the last
+    /// `window` epochs and divided by `decrease` when it has been feasible for the last `window`
+    /// epochs.
+    AdaptivePenalty {
+        initial: f64,
+        increase: f64,
+        decrease: f64,
+        window: usize,
+    },
+}
+
+/// A population crossover selector that handles constraint violations.
+/// Costs are adjusted according to a [`Constraint`] policy and passed to another selector.
+pub struct ConstraintSelector<G> {
+    inner: Box<dyn Selector<G> + Send + Sync>,
+    constraint: Constraint,
+    epoch: usize,
+    penalty: f64,
+    feasible_streak: usize,
+    infeasible_streak: usize,
+    adjusted: Vec<f64>,
+}
+
+impl<G> ConstraintSelector<G> {
+    /// Create new population selector.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The selector that chooses partners using the adjusted costs.
+    /// * `constraint` - How constraint violations are combined with costs. Its parameters must
+    ///   be finite.
+    pub fn new(
+        inner: Box<dyn Selector<G> + Send + Sync>,
+        constraint: Constraint,
+    ) -> Result<Self, Failure> {
+        let valid = match constraint {
+            Constraint::Feasibility => true,
+            Constraint::StaticPenalty { coefficient } => {
+                coefficient >= 0.0 && coefficient.is_finite()
+            }
+            Constraint::DynamicPenalty {
+                coefficient,
+                exponent,
+            } => {
+                coefficient >= 0.0
+                    && coefficient.is_finite()
+                    && exponent >= 0.0
+                    && exponent.is_finite()
+            }
+            Constraint::AdaptivePenalty {
+                initial,
+                increase,
+                decrease,
+                window,
+            } => {
+                initial > 0.0
+                    && initial.is_finite()
+                    && increase >= 1.0
+                    && increase.is_finite()
+                    && decrease >= 1.0
+                    && decrease.is_finite()
+                    && window >= 1
+            }
+        };
+        if !valid {
+            return Err(Failure::constraint_setting());
+        }
+        let penalty = match constraint {
+            Constraint::AdaptivePenalty { initial, .. } => initial,
+            _ => 0.0,
+        };
+        Ok(Self {
+            inner,
+            constraint,
+            epoch: 0,
+            penalty,
+            feasible_streak: 0,
+            infeasible_streak: 0,
+            adjusted: Vec::new(),
+        })
+    }
+
+    /// How constraint violations are combined with costs.
+    pub fn constraint(&self) -> Constraint {
+        self.constraint
+    }
+
+    /// The multiple of the violation added to the cost in the most recent epoch.
+    /// This is 0 for [`Constraint::Feasibility`].
+    pub fn penalty(&self) -> f64 {
+        self.penalty
+    }
+
+    fn adjust(&mut self, costs: &[f64], violations: &[f64]) {
+        self.epoch += 1;
+        self.adjusted.clear();
+        match self.constraint {
+            Constraint::Feasibility => {
+                let offset = std::iter::zip(costs, violations)
+                    .filter(|(_, v)| **v <= 0.0)
+                    .map(|(c, _)| *c)
+                    .max_by(|lhs, rhs| compare_costs(*lhs, *rhs))
+                    .unwrap_or(0.0);
+                self.adjusted
+                    .extend(std::iter::zip(costs, violations).map(|(c, v)| {
+                        if *v <= 0.0 {
+                            *c
+                        } else {
+                            offset + v
+                        }
+                    }));
+                return;
+            }
+            Constraint::StaticPenalty { coefficient } => self.penalty = coefficient,
+            Constraint::DynamicPenalty {
+                coefficient,
+                exponent,
+            } => self.penalty = (coefficient * self.epoch as f64).powf(exponent),
+            Constraint::AdaptivePenalty { .. } => {}
+        }
+
+        // The penalty can grow to infinity, which must not turn the cost of a feasible genome
+        // into `inf * 0 = NaN`.
+        let penalty = self.penalty;
+        self.adjusted
+            .extend(std::iter::zip(costs, violations).map(|(c, v)| {
+                if *v <= 0.0 {
+                    *c
+                } else {
+                    c + penalty * v
+                }
+            }));
+
+        if let Constraint::AdaptivePenalty {
+            increase,
+            decrease,
+            window,
+            ..
+        } = self.constraint
+        {
+            let best = (0..self.adjusted.len())
+                .min_by(|a, b| compare_costs(self.adjusted[*a], self.adjusted[*b]))
+                .unwrap();
+            if violations[best] <= 0.0 {
+                self.feasible_streak += 1;
+                self.infeasible_streak = 0;
+            } else {
+                self.infeasible_streak += 1;
+                self.feasible_streak = 0;
+            }
+            if self.feasible_streak >= window {
+                self.penalty /= decrease;
+                self.feasible_streak = 0;
+            } else if self.infeasible_streak >= window {
+                self.penalty *= increase;
+                self.infeasible_streak = 0;
+            }
+        }
+    }
+}
+
+impl<G> Selector<G> for ConstraintSelector<G> {
+    fn select(&mut self, population: &[G], costs: &[f64], partner_indices: &mut [usize]) {
+        self.inner.select(population, costs, partner_indices)
+    }
+
+    fn select_constrained(
+        &mut self,
+        population: &[G],
+        costs: &[f64],
+        violations: &[f64],
+        partner_indices: &mut [usize],
+    ) {
+        self.adjust(costs, violations);
+        self.inner
+            .select(population, &self.adjusted, partner_indices)
+    }
+}
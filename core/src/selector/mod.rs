@@ -1,7 +1,10 @@
+mod constraint_selector;
+#[allow(clippy::module_inception)]
 mod selector;
 mod torus_selector;
 mod tournament_selector;
 
+pub use constraint_selector::{Constraint, ConstraintSelector};
 pub use selector::Selector;
 pub use torus_selector::TorusSelector;
 pub use tournament_selector::TournamentSelector;
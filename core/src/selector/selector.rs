@@ -8,4 +8,25 @@ pub trait Selector<G> {
     /// * `partner_indices` - The selected partners, as indices into `population`.
     ///
     fn select(&mut self, population: &[G], costs: &[f64], partner_indices: &mut [usize]);
+
+    /// Select cross over partners, taking constraint violations into account.
+    /// The default implementation ignores the violations.
+    ///
+    /// # Arguments
+    ///
+    /// * `population` - The previous generation of genomes.  
+    /// * `costs` - The cost associated with each item in `population`.
+    /// * `violations` - The constraint violation associated with each item in `population`.
+    ///   See [`crate::Genetic::violation`].
+    /// * `partner_indices` - The selected partners, as indices into `population`.
+    ///
+    fn select_constrained(
+        &mut self,
+        population: &[G],
+        costs: &[f64],
+        _violations: &[f64],
+        partner_indices: &mut [usize],
+    ) {
+        self.select(population, costs, partner_indices)
+    }
 }
@@ -1,5 +1,5 @@
 use crate::selector::Selector;
-use crate::Failure;
+use crate::{compare_costs, Failure};
 use rand::Rng;
 use std::marker::PhantomData;
 
@@ -17,8 +17,8 @@ impl<G> TournamentSelector<G> {
     /// # Arguments
     ///
     /// * `cross_over_candidates` - The number of candidate genomes that will be compared when
-    ///                             deciding which genome to use when new genomes are generated
-    ///                             during cross over.  
+    ///   deciding which genome to use when new genomes are generated
+    ///   during cross over.  
     pub fn new(cross_over_candidates: usize) -> Result<Self, Failure> {
         if cross_over_candidates < 1 {
             return Err(Failure::cross_over_candidates());
@@ -49,17 +49,16 @@ impl<G> Selector<G> for TournamentSelector<G> {
     #[allow(clippy::needless_range_loop)]
     fn select(&mut self, _population: &[G], costs: &[f64], partner_indices: &mut [usize]) {
         for lhs_index in 0..costs.len() {
-            let mut rhs_index = 0;
-            let mut rhs_cost = f64::MAX;
+            let mut rhs_index = None;
             for _ in 0..self.cross_over_candidates {
                 let j = rand::thread_rng().gen_range(0..costs.len());
-                let rhs_cost_candidate = *costs.get(j).unwrap();
-                if rhs_cost_candidate < rhs_cost {
-                    rhs_cost = rhs_cost_candidate;
-                    rhs_index = j;
+                let better =
+                    rhs_index.map_or(true, |i: usize| compare_costs(costs[j], costs[i]).is_lt());
+                if better {
+                    rhs_index = Some(j);
                 }
             }
-            partner_indices[lhs_index] = rhs_index;
+            partner_indices[lhs_index] = rhs_index.unwrap_or(lhs_index);
         }
     }
 }
@@ -0,0 +1,56 @@
+use crate::Failure;
+
+/// The settings for a covariance matrix adaptation evolution strategy (CMA-ES) search.
+///
+/// The number of genomes sampled in each epoch (λ) is the population size of the
+/// [`crate::SearchSettings`].
+/// A good choice for `n` dimensions is `4 + 3 ln(n)`, which is much smaller than for a genetic
+/// algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct CmaSettings {
+    initial_step_size: f64,
+    parent_fraction: f64,
+}
+
+impl CmaSettings {
+    /// The initial standard deviation (σ) of the search distribution.
+    /// This should be about a quarter of the width of the region expected to contain the
+    /// optimum.
+    pub fn initial_step_size(&self) -> f64 {
+        self.initial_step_size
+    }
+
+    /// The fraction of the sampled genomes (μ / λ) that are recombined to update the search
+    /// distribution.
+    pub fn parent_fraction(&self) -> f64 {
+        self.parent_fraction
+    }
+
+    pub(super) fn new(
+        initial_step_size: f64,
+        parent_fraction: f64,
+    ) -> Result<CmaSettings, Failure> {
+        let valid = initial_step_size > 0.0
+            && initial_step_size.is_finite()
+            && parent_fraction > 0.0
+            && parent_fraction <= 1.0;
+        if !valid {
+            return Err(Failure::cma_setting());
+        }
+
+        Ok(Self {
+            initial_step_size,
+            parent_fraction,
+        })
+    }
+}
+
+impl Default for CmaSettings {
+    /// The default CMA-ES settings, which recombine the best half of each epoch.
+    fn default() -> Self {
+        Self {
+            initial_step_size: 1.0,
+            parent_fraction: 0.5,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::settings::cma::CmaSettings;
+use crate::Failure;
+
+/// Use to construct the settings for a CMA-ES search.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct CmaSettingsBuilder {
+    initial_step_size: f64,
+    parent_fraction: f64,
+}
+
+impl CmaSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &CmaSettings) -> Self {
+        Self {
+            initial_step_size: settings.initial_step_size(),
+            parent_fraction: settings.parent_fraction(),
+        }
+    }
+
+    /// The initial standard deviation (σ) of the search distribution.
+    /// This should be about a quarter of the width of the region expected to contain the
+    /// optimum.
+    pub fn initial_step_size(mut self, value: f64) -> Self {
+        self.initial_step_size = value;
+        self
+    }
+
+    /// The fraction of the sampled genomes (μ / λ) that are recombined to update the search
+    /// distribution.
+    pub fn parent_fraction(mut self, value: f64) -> Self {
+        self.parent_fraction = value;
+        self
+    }
+
+    /// Construct the settings for a CMA-ES search.
+    pub fn build(&self) -> Result<CmaSettings, Failure> {
+        CmaSettings::new(self.initial_step_size, self.parent_fraction)
+    }
+}
+
+impl Default for CmaSettingsBuilder {
+    fn default() -> Self {
+        CmaSettingsBuilder::from(&CmaSettings::default())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::Failure;
+
+/// Define how differential evolution builds the mutant vector for each genome.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DifferentialVariant {
+    /// DE/rand/1/bin: a random genome plus the scaled difference of two others.
+    Rand1Bin,
+
+    /// DE/best/1/bin: the best genome plus the scaled difference of two others.
+    Best1Bin,
+
+    /// DE/current-to-best/1/bin: the genome itself, moved towards the best genome and by the
+    /// scaled difference of two others.
+    CurrentToBest1Bin,
+}
+
+/// Define how the scale factor and crossover rate change during the search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParameterAdaptation {
+    /// The scale factor and crossover rate are fixed.
+    Fixed,
+
+    /// jDE (Brest et al.): each genome carries its own scale factor and crossover rate, which
+    /// start at the configured values and are occasionally resampled.
+    /// Values that produce a surviving genome are inherited by it.
+    Jde,
+}
+
+/// The settings for a differential evolution search.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct DifferentialSettings {
+    adaptation: ParameterAdaptation,
+    crossover_rate: f64,
+    scale_factor: f64,
+    variant: DifferentialVariant,
+}
+
+impl DifferentialSettings {
+    /// How the scale factor and crossover rate change during the search.
+    pub fn adaptation(&self) -> ParameterAdaptation {
+        self.adaptation
+    }
+
+    /// The probability (CR) of each element of a new genome coming from the mutant vector
+    /// rather than the genome it replaces.
+    pub fn crossover_rate(&self) -> f64 {
+        self.crossover_rate
+    }
+
+    /// The factor (F) by which differences between genomes are scaled.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// How the mutant vector is built.
+    pub fn variant(&self) -> DifferentialVariant {
+        self.variant
+    }
+
+    pub(super) fn new(
+        adaptation: ParameterAdaptation,
+        crossover_rate: f64,
+        scale_factor: f64,
+        variant: DifferentialVariant,
+    ) -> Result<DifferentialSettings, Failure> {
+        let valid =
+            (0.0..=1.0).contains(&crossover_rate) && scale_factor > 0.0 && scale_factor <= 2.0;
+        if !valid {
+            return Err(Failure::differential_setting());
+        }
+
+        Ok(Self {
+            adaptation,
+            crossover_rate,
+            scale_factor,
+            variant,
+        })
+    }
+}
+
+impl Default for DifferentialSettings {
+    /// The default differential evolution settings: DE/rand/1/bin with F = 0.5 and CR = 0.9.
+    fn default() -> Self {
+        Self {
+            adaptation: ParameterAdaptation::Fixed,
+            crossover_rate: 0.9,
+            scale_factor: 0.5,
+            variant: DifferentialVariant::Rand1Bin,
+        }
+    }
+}
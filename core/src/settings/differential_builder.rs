@@ -0,0 +1,66 @@
+use crate::settings::differential::{
+    DifferentialSettings, DifferentialVariant, ParameterAdaptation,
+};
+use crate::Failure;
+
+/// Use to construct the settings for a differential evolution search.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct DifferentialSettingsBuilder {
+    adaptation: ParameterAdaptation,
+    crossover_rate: f64,
+    scale_factor: f64,
+    variant: DifferentialVariant,
+}
+
+impl DifferentialSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &DifferentialSettings) -> Self {
+        Self {
+            adaptation: settings.adaptation(),
+            crossover_rate: settings.crossover_rate(),
+            scale_factor: settings.scale_factor(),
+            variant: settings.variant(),
+        }
+    }
+
+    /// How the scale factor and crossover rate change during the search.
+    pub fn adaptation(mut self, value: ParameterAdaptation) -> Self {
+        self.adaptation = value;
+        self
+    }
+
+    /// The probability (CR) of each element of a new genome coming from the mutant vector
+    /// rather than the genome it replaces.
+    pub fn crossover_rate(mut self, value: f64) -> Self {
+        self.crossover_rate = value;
+        self
+    }
+
+    /// The factor (F) by which differences between genomes are scaled.
+    pub fn scale_factor(mut self, value: f64) -> Self {
+        self.scale_factor = value;
+        self
+    }
+
+    /// How the mutant vector is built.
+    pub fn variant(mut self, value: DifferentialVariant) -> Self {
+        self.variant = value;
+        self
+    }
+
+    /// Construct the settings for a differential evolution search.
+    pub fn build(&self) -> Result<DifferentialSettings, Failure> {
+        DifferentialSettings::new(
+            self.adaptation,
+            self.crossover_rate,
+            self.scale_factor,
+            self.variant,
+        )
+    }
+}
+
+impl Default for DifferentialSettingsBuilder {
+    fn default() -> Self {
+        DifferentialSettingsBuilder::from(&DifferentialSettings::default())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::Failure;
+
+/// Define which genomes survive to become the parents of the next epoch of an evolution
+/// strategy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Survivor {
+    /// (μ, λ): the best μ offspring replace the parents.
+    Comma,
+
+    /// (μ + λ): the best μ of the parents and offspring together survive.
+    Plus,
+}
+
+/// The settings for an evolution strategy search.
+///
+/// The number of parents (μ) is the population size of the [`crate::SearchSettings`].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct EvolutionSettings {
+    initial_step_size: f64,
+    learning_rate: f64,
+    offspring: usize,
+    recombination_probability: f64,
+    self_adaptive: bool,
+    survivor: Survivor,
+}
+
+impl EvolutionSettings {
+    /// The step size of each genome in the initial population, when step sizes are
+    /// self-adaptive.
+    pub fn initial_step_size(&self) -> f64 {
+        self.initial_step_size
+    }
+
+    /// The learning rate (τ) of self-adaptive step sizes.
+    /// The step size of each offspring is that of its parent multiplied by `exp(τ N(0, 1))`.
+    /// A common choice for `n` dimensions is `1 / sqrt(n)`.
+    pub fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    /// The number of offspring (λ) generated in each epoch.
+    pub fn offspring(&self) -> usize {
+        self.offspring
+    }
+
+    /// The probability that an offspring is the crossover of two parents, rather than a copy of
+    /// one, before it is mutated.
+    pub fn recombination_probability(&self) -> f64 {
+        self.recombination_probability
+    }
+
+    /// When true, each genome carries its own step size, which is inherited and mutated along
+    /// with the genome and passed to [`mutate_scaled()`](crate::Genetic#mutate_scaled()).
+    /// When false, offspring are mutated with [`mutate()`](crate::Genetic#mutate()).
+    pub fn self_adaptive(&self) -> bool {
+        self.self_adaptive
+    }
+
+    /// Which genomes survive to become the parents of the next epoch.
+    pub fn survivor(&self) -> Survivor {
+        self.survivor
+    }
+
+    pub(super) fn new(
+        initial_step_size: f64,
+        learning_rate: f64,
+        offspring: usize,
+        recombination_probability: f64,
+        self_adaptive: bool,
+        survivor: Survivor,
+    ) -> Result<EvolutionSettings, Failure> {
+        let valid = initial_step_size > 0.0
+            && initial_step_size.is_finite()
+            && learning_rate >= 0.0
+            && learning_rate.is_finite()
+            && offspring >= 1
+            && (0.0..=1.0).contains(&recombination_probability);
+        if !valid {
+            return Err(Failure::evolution_setting());
+        }
+
+        Ok(Self {
+            initial_step_size,
+            learning_rate,
+            offspring,
+            recombination_probability,
+            self_adaptive,
+            survivor,
+        })
+    }
+}
+
+impl Default for EvolutionSettings {
+    /// The default evolution strategy settings: a (μ, λ) strategy with 100 offspring per epoch,
+    /// no recombination and fixed step sizes.
+    fn default() -> Self {
+        Self {
+            initial_step_size: 1.0,
+            learning_rate: 0.5,
+            offspring: 100,
+            recombination_probability: 0.0,
+            self_adaptive: false,
+            survivor: Survivor::Comma,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::settings::evolution::{EvolutionSettings, Survivor};
+use crate::Failure;
+
+/// Use to construct the settings for an evolution strategy search.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct EvolutionSettingsBuilder {
+    initial_step_size: f64,
+    learning_rate: f64,
+    offspring: usize,
+    recombination_probability: f64,
+    self_adaptive: bool,
+    survivor: Survivor,
+}
+
+impl EvolutionSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &EvolutionSettings) -> Self {
+        Self {
+            initial_step_size: settings.initial_step_size(),
+            learning_rate: settings.learning_rate(),
+            offspring: settings.offspring(),
+            recombination_probability: settings.recombination_probability(),
+            self_adaptive: settings.self_adaptive(),
+            survivor: settings.survivor(),
+        }
+    }
+
+    /// The step size of each genome in the initial population, when step sizes are
+    /// self-adaptive.
+    pub fn initial_step_size(mut self, value: f64) -> Self {
+        self.initial_step_size = value;
+        self
+    }
+
+    /// The learning rate (τ) of self-adaptive step sizes.
+    /// The step size of each offspring is that of its parent multiplied by `exp(τ N(0, 1))`.
+    /// A common choice for `n` dimensions is `1 / sqrt(n)`.
+    pub fn learning_rate(mut self, value: f64) -> Self {
+        self.learning_rate = value;
+        self
+    }
+
+    /// The number of offspring (λ) generated in each epoch.
+    pub fn offspring(mut self, value: usize) -> Self {
+        self.offspring = value;
+        self
+    }
+
+    /// The probability that an offspring is the crossover of two parents, rather than a copy of
+    /// one, before it is mutated.
+    pub fn recombination_probability(mut self, value: f64) -> Self {
+        self.recombination_probability = value;
+        self
+    }
+
+    /// When true, each genome carries its own step size, which is inherited and mutated along
+    /// with the genome and passed to [`mutate_scaled()`](crate::Genetic#mutate_scaled()).
+    /// When false, offspring are mutated with [`mutate()`](crate::Genetic#mutate()).
+    pub fn self_adaptive(mut self, value: bool) -> Self {
+        self.self_adaptive = value;
+        self
+    }
+
+    /// Which genomes survive to become the parents of the next epoch.
+    pub fn survivor(mut self, value: Survivor) -> Self {
+        self.survivor = value;
+        self
+    }
+
+    /// Construct the settings for an evolution strategy search.
+    pub fn build(&self) -> Result<EvolutionSettings, Failure> {
+        EvolutionSettings::new(
+            self.initial_step_size,
+            self.learning_rate,
+            self.offspring,
+            self.recombination_probability,
+            self.self_adaptive,
+            self.survivor,
+        )
+    }
+}
+
+impl Default for EvolutionSettingsBuilder {
+    fn default() -> Self {
+        EvolutionSettingsBuilder::from(&EvolutionSettings::default())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Failure;
+
+/// The settings that control the hall of fame: the best distinct genomes found at any epoch of
+/// the search.
+///
+/// Two genomes are the same when they are equal, or, when `min_distance` is positive and
+/// [`distance()`](crate::Genetic#distance()) provides a measure of distance, when they are
+/// closer than `min_distance`.
+/// Only the better of two such genomes is kept.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct HallOfFameSettings {
+    size: usize,
+    min_distance: f64,
+}
+
+impl HallOfFameSettings {
+    /// The maximum number of genomes kept.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Genomes closer than this distance are treated as the same genome.
+    /// When this is 0, or there is no measure of distance, only equal genomes are the same.
+    pub fn min_distance(&self) -> f64 {
+        self.min_distance
+    }
+
+    pub(super) fn new(size: usize, min_distance: f64) -> Result<HallOfFameSettings, Failure> {
+        if size < 1 || min_distance.is_nan() || min_distance < 0.0 {
+            return Err(Failure::hall_of_fame_setting());
+        }
+
+        Ok(Self { size, min_distance })
+    }
+}
+
+impl Default for HallOfFameSettings {
+    /// The default hall of fame settings.
+    /// These keep the 10 best genomes that are not equal.
+    fn default() -> Self {
+        Self {
+            size: 10,
+            min_distance: 0.0,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::settings::hall_of_fame::HallOfFameSettings;
+use crate::Failure;
+
+/// Use to construct the settings that control the hall of fame.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct HallOfFameSettingsBuilder {
+    size: usize,
+    min_distance: f64,
+}
+
+impl HallOfFameSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &HallOfFameSettings) -> Self {
+        Self {
+            size: settings.size(),
+            min_distance: settings.min_distance(),
+        }
+    }
+
+    /// The maximum number of genomes kept.
+    pub fn size(mut self, value: usize) -> Self {
+        self.size = value;
+        self
+    }
+
+    /// Genomes closer than this distance are treated as the same genome.
+    /// When this is 0, or there is no measure of distance, only equal genomes are the same.
+    pub fn min_distance(mut self, value: f64) -> Self {
+        self.min_distance = value;
+        self
+    }
+
+    /// Construct the hall of fame settings.
+    pub fn build(&self) -> Result<HallOfFameSettings, Failure> {
+        HallOfFameSettings::new(self.size, self.min_distance)
+    }
+}
+
+impl Default for HallOfFameSettingsBuilder {
+    fn default() -> Self {
+        HallOfFameSettingsBuilder::from(&HallOfFameSettings::default())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Failure;
+
+/// Define how the result of local search is inherited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Inheritance {
+    /// The improved genome replaces the original in the population.
+    Lamarckian,
+
+    /// The original genome stays in the population but takes the cost of the improved genome.
+    /// The best genome of the search is reported with its own cost, so it is evaluated again
+    /// whenever it might be the best so far.
+    Baldwinian,
+}
+
+/// The settings for memetic search, which improves new genomes with a local search.
+///
+/// The local search is defined by [`local_search()`](crate::Genetic#local_search()).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MemeticSettings {
+    inheritance: Inheritance,
+    interval: usize,
+    probability: f64,
+}
+
+impl MemeticSettings {
+    /// How the result of local search is inherited.
+    pub fn inheritance(&self) -> Inheritance {
+        self.inheritance
+    }
+
+    /// Local search is applied every `interval` epochs, starting with the first.
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    /// The probability that local search is applied to each genome, in epochs where it runs.
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    pub(super) fn new(
+        inheritance: Inheritance,
+        interval: usize,
+        probability: f64,
+    ) -> Result<MemeticSettings, Failure> {
+        if interval < 1 || !(0.0..=1.0).contains(&probability) {
+            return Err(Failure::memetic_setting());
+        }
+
+        Ok(Self {
+            inheritance,
+            interval,
+            probability,
+        })
+    }
+}
+
+impl Default for MemeticSettings {
+    /// The default memetic settings.
+    /// These improve every genome in every epoch, writing back the improved genome.
+    fn default() -> Self {
+        Self {
+            inheritance: Inheritance::Lamarckian,
+            interval: 1,
+            probability: 1.0,
+        }
+    }
+}
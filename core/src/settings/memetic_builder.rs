@@ -0,0 +1,50 @@
+use crate::settings::memetic::{Inheritance, MemeticSettings};
+use crate::Failure;
+
+/// Use to construct the settings for memetic search.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct MemeticSettingsBuilder {
+    inheritance: Inheritance,
+    interval: usize,
+    probability: f64,
+}
+
+impl MemeticSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &MemeticSettings) -> Self {
+        Self {
+            inheritance: settings.inheritance(),
+            interval: settings.interval(),
+            probability: settings.probability(),
+        }
+    }
+
+    /// How the result of local search is inherited.
+    pub fn inheritance(mut self, value: Inheritance) -> Self {
+        self.inheritance = value;
+        self
+    }
+
+    /// Local search is applied every `interval` epochs, starting with the first.
+    pub fn interval(mut self, value: usize) -> Self {
+        self.interval = value;
+        self
+    }
+
+    /// The probability that local search is applied to each genome, in epochs where it runs.
+    pub fn probability(mut self, value: f64) -> Self {
+        self.probability = value;
+        self
+    }
+
+    /// Construct the settings for memetic search.
+    pub fn build(&self) -> Result<MemeticSettings, Failure> {
+        MemeticSettings::new(self.inheritance, self.interval, self.probability)
+    }
+}
+
+impl Default for MemeticSettingsBuilder {
+    fn default() -> Self {
+        MemeticSettingsBuilder::from(&MemeticSettings::default())
+    }
+}
@@ -1,11 +1,43 @@
+mod cma;
+mod cma_builder;
 mod concurrency;
 mod detect_concurrency;
 mod detect_concurrency_builder;
+mod differential;
+mod differential_builder;
+mod evolution;
+mod evolution_builder;
+mod hall_of_fame;
+mod hall_of_fame_builder;
+mod memetic;
+mod memetic_builder;
+mod nan_policy;
+mod objective;
+mod restart;
+mod restart_builder;
 mod search;
 mod search_builder;
+mod tree;
+mod tree_builder;
 
+pub use cma::CmaSettings;
+pub use cma_builder::CmaSettingsBuilder;
 pub use concurrency::ConcurrencySettings;
 pub use detect_concurrency::DetectConcurrencySettings;
 pub use detect_concurrency_builder::DetectConcurrencySettingsBuilder;
+pub use differential::{DifferentialSettings, DifferentialVariant, ParameterAdaptation};
+pub use differential_builder::DifferentialSettingsBuilder;
+pub use evolution::{EvolutionSettings, Survivor};
+pub use evolution_builder::EvolutionSettingsBuilder;
+pub use hall_of_fame::HallOfFameSettings;
+pub use hall_of_fame_builder::HallOfFameSettingsBuilder;
+pub use memetic::{Inheritance, MemeticSettings};
+pub use memetic_builder::MemeticSettingsBuilder;
+pub use nan_policy::NanPolicy;
+pub use objective::Objective;
+pub use restart::RestartSettings;
+pub use restart_builder::RestartSettingsBuilder;
 pub use search::SearchSettings;
 pub use search_builder::SearchSettingsBuilder;
+pub use tree::TreeSettings;
+pub use tree_builder::TreeSettingsBuilder;
@@ -0,0 +1,11 @@
+/// Define what happens when [`evaluate()`](crate::Genetic#evaluate()) or
+/// [`violation()`](crate::Genetic#violation()) returns NaN.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NanPolicy {
+    /// Treat NaN as the worst possible value, so the genome is never preferred to any other.
+    #[default]
+    Worst,
+
+    /// Fail the search with [`crate::Failure::nan_cost`].
+    Reject,
+}
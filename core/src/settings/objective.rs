@@ -0,0 +1,35 @@
+/// Define whether the search minimises or maximises the value returned by
+/// [`evaluate()`](crate::Genetic#evaluate()).
+///
+/// Costs, cost targets and statistics are always reported in the orientation of the objective.
+/// Internally the search minimises, so search strategies and selectors see the negated value
+/// when maximising.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Objective {
+    /// Lower values are better.
+    #[default]
+    Minimise,
+
+    /// Higher values are better.
+    Maximise,
+}
+
+impl Objective {
+    /// Return true if `lhs` is a strictly better value than `rhs`.
+    pub fn is_better(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Objective::Minimise => lhs < rhs,
+            Objective::Maximise => lhs > rhs,
+        }
+    }
+
+    /// Convert between a value in the orientation of the objective and a cost, which is always
+    /// minimised.
+    /// The conversion is its own inverse.
+    pub(crate) fn orient(&self, value: f64) -> f64 {
+        match self {
+            Objective::Minimise => value,
+            Objective::Maximise => -value,
+        }
+    }
+}
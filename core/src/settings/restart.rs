@@ -0,0 +1,87 @@
+use crate::Failure;
+
+/// The settings that control when a search restarts from a fresh population.
+///
+/// A restart keeps the best genomes and replaces the rest of the population with new genomes
+/// from [`initialize()`](crate::Genetic#initialize()).
+/// The population grows by a constant factor at each restart, as in IPOP (Auger and Hansen).
+/// The epoch and time limits of the search apply across all restarts.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct RestartSettings {
+    stagnation: usize,
+    min_diversity: f64,
+    elite: usize,
+    population_growth: f64,
+    max_population_size: usize,
+}
+
+impl RestartSettings {
+    /// The number of consecutive epochs without improvement of the best genome that trigger a
+    /// restart.
+    pub fn stagnation(&self) -> usize {
+        self.stagnation
+    }
+
+    /// A restart is triggered when the mean pairwise distance of the population falls to this
+    /// value or below.
+    /// This only applies when [`distance()`](crate::Genetic#distance()) provides a measure of
+    /// distance.
+    pub fn min_diversity(&self) -> f64 {
+        self.min_diversity
+    }
+
+    /// The number of best genomes kept at each restart.
+    pub fn elite(&self) -> usize {
+        self.elite
+    }
+
+    /// The factor by which the population size is multiplied at each restart.
+    pub fn population_growth(&self) -> f64 {
+        self.population_growth
+    }
+
+    /// The population size never grows beyond this value.
+    pub fn max_population_size(&self) -> usize {
+        self.max_population_size
+    }
+
+    pub(super) fn new(
+        stagnation: usize,
+        min_diversity: f64,
+        elite: usize,
+        population_growth: f64,
+        max_population_size: usize,
+    ) -> Result<RestartSettings, Failure> {
+        if stagnation < 1
+            || min_diversity.is_nan()
+            || min_diversity < 0.0
+            || population_growth.is_nan()
+            || population_growth < 1.0
+            || max_population_size < 1
+        {
+            return Err(Failure::restart_setting());
+        }
+
+        Ok(Self {
+            stagnation,
+            min_diversity,
+            elite,
+            population_growth,
+            max_population_size,
+        })
+    }
+}
+
+impl Default for RestartSettings {
+    /// The default restart settings.
+    /// These double the population at each restart, as in IPOP.
+    fn default() -> Self {
+        Self {
+            stagnation: 64,
+            min_diversity: 0.0,
+            elite: 1,
+            population_growth: 2.0,
+            max_population_size: 65_536,
+        }
+    }
+}
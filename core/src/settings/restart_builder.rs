@@ -0,0 +1,76 @@
+use crate::settings::restart::RestartSettings;
+use crate::Failure;
+
+/// Use to construct the settings that control when a search restarts.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct RestartSettingsBuilder {
+    stagnation: usize,
+    min_diversity: f64,
+    elite: usize,
+    population_growth: f64,
+    max_population_size: usize,
+}
+
+impl RestartSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &RestartSettings) -> Self {
+        Self {
+            stagnation: settings.stagnation(),
+            min_diversity: settings.min_diversity(),
+            elite: settings.elite(),
+            population_growth: settings.population_growth(),
+            max_population_size: settings.max_population_size(),
+        }
+    }
+
+    /// The number of consecutive epochs without improvement of the best genome that trigger a
+    /// restart.
+    pub fn stagnation(mut self, value: usize) -> Self {
+        self.stagnation = value;
+        self
+    }
+
+    /// A restart is triggered when the mean pairwise distance of the population falls to this
+    /// value or below.
+    /// This only applies when [`distance()`](crate::Genetic#distance()) provides a measure of
+    /// distance.
+    pub fn min_diversity(mut self, value: f64) -> Self {
+        self.min_diversity = value;
+        self
+    }
+
+    /// The number of best genomes kept at each restart.
+    pub fn elite(mut self, value: usize) -> Self {
+        self.elite = value;
+        self
+    }
+
+    /// The factor by which the population size is multiplied at each restart.
+    pub fn population_growth(mut self, value: f64) -> Self {
+        self.population_growth = value;
+        self
+    }
+
+    /// The population size never grows beyond this value.
+    pub fn max_population_size(mut self, value: usize) -> Self {
+        self.max_population_size = value;
+        self
+    }
+
+    /// Construct the restart settings.
+    pub fn build(&self) -> Result<RestartSettings, Failure> {
+        RestartSettings::new(
+            self.stagnation,
+            self.min_diversity,
+            self.elite,
+            self.population_growth,
+            self.max_population_size,
+        )
+    }
+}
+
+impl Default for RestartSettingsBuilder {
+    fn default() -> Self {
+        RestartSettingsBuilder::from(&RestartSettings::default())
+    }
+}
@@ -1,4 +1,9 @@
 use crate::settings::concurrency::ConcurrencySettings;
+use crate::settings::hall_of_fame::HallOfFameSettings;
+use crate::settings::memetic::MemeticSettings;
+use crate::settings::nan_policy::NanPolicy;
+use crate::settings::objective::Objective;
+use crate::settings::restart::RestartSettings;
 use crate::Failure;
 use std::time::Duration;
 
@@ -8,8 +13,15 @@ pub struct SearchSettings {
     concurrency: ConcurrencySettings,
     cost_target: f64,
     epoch_limit: usize,
+    evaluation_limit: usize,
+    hall_of_fame: Option<HallOfFameSettings>,
+    keep_population: bool,
+    memetic: Option<MemeticSettings>,
     mutation_probability: f64,
+    nan_policy: NanPolicy,
+    objective: Objective,
     population_size: usize,
+    restart: Option<RestartSettings>,
     time_limit: Duration,
 }
 
@@ -23,7 +35,9 @@ impl SearchSettings {
     /// Genome costs are expected to be higher at the start of the search process and become
     /// smaller as the search progresses.
     /// The search will terminate when the cost of any genome in the population is less than or
-    /// equal to this value.   
+    /// equal to this value.
+    /// When maximising, it terminates when the cost is greater than or equal to this value
+    /// instead, so the default of 0 is rarely suitable.
     pub fn cost_target(&self) -> f64 {
         self.cost_target
     }
@@ -34,34 +48,89 @@ impl SearchSettings {
         self.epoch_limit
     }
 
+    /// The maximum number of times [`evaluate()`](crate::Genetic#evaluate()) may be called
+    /// before terminating the search.
+    /// The limit is checked at the end of each epoch, so it may be exceeded by up to one epoch.
+    pub fn evaluation_limit(&self) -> usize {
+        self.evaluation_limit
+    }
+
+    /// How the best distinct genomes found at any epoch are kept, if at all.
+    /// This only applies when a search is created.
+    pub fn hall_of_fame(&self) -> Option<HallOfFameSettings> {
+        self.hall_of_fame
+    }
+
+    /// When true, the final population is returned with its costs in
+    /// [`Success::population`](crate::Success::population).
+    pub fn keep_population(&self) -> bool {
+        self.keep_population
+    }
+
+    /// How new genomes are improved by local search, if at all.
+    pub fn memetic(&self) -> Option<MemeticSettings> {
+        self.memetic
+    }
+
     /// The probability of a newly generated genome being mutated.
     /// The mutation implementation is defined by the [`mutate()`](crate::Genetic#mutate()).
     pub fn mutation_probability(&self) -> f64 {
         self.mutation_probability
     }
 
+    /// What happens when [`evaluate()`](crate::Genetic#evaluate()) or
+    /// [`violation()`](crate::Genetic#violation()) returns NaN.
+    /// This only applies when a search is created.
+    pub fn nan_policy(&self) -> NanPolicy {
+        self.nan_policy
+    }
+
+    /// Whether the search minimises or maximises the value returned by
+    /// [`evaluate()`](crate::Genetic#evaluate()).
+    /// This only applies when a search is created.
+    pub fn objective(&self) -> Objective {
+        self.objective
+    }
+
     /// The number of genomes that exist at any one epoch (iteration) of the search.
     pub fn population_size(&self) -> usize {
         self.population_size
     }
 
+    /// When and how the search restarts from a fresh population, if at all.
+    pub fn restart(&self) -> Option<RestartSettings> {
+        self.restart
+    }
+
     /// The maximum duration of the search.
     pub fn time_limit(&self) -> Duration {
         self.time_limit
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         concurrency: ConcurrencySettings,
         cost_target: f64,
         epoch_limit: usize,
+        evaluation_limit: usize,
+        hall_of_fame: Option<HallOfFameSettings>,
+        keep_population: bool,
+        memetic: Option<MemeticSettings>,
         mutation_probability: f64,
+        nan_policy: NanPolicy,
+        objective: Objective,
         population_size: usize,
+        restart: Option<RestartSettings>,
         time_limit: Duration,
     ) -> Result<SearchSettings, Failure> {
         if epoch_limit < 1 {
             return Err(Failure::epoch_limit());
         }
 
+        if evaluation_limit < 1 {
+            return Err(Failure::evaluation_limit());
+        }
+
         if mutation_probability < 0.0 {
             return Err(Failure::mutation_probability());
         }
@@ -78,8 +147,15 @@ impl SearchSettings {
             concurrency,
             cost_target,
             epoch_limit,
+            evaluation_limit,
+            hall_of_fame,
+            keep_population,
+            memetic,
             mutation_probability,
+            nan_policy,
+            objective,
             population_size,
+            restart,
             time_limit,
         })
     }
@@ -93,8 +169,15 @@ impl Default for SearchSettings {
             concurrency: ConcurrencySettings::SingleThreaded,
             cost_target: 0.0,
             epoch_limit: 1_024,
+            evaluation_limit: usize::MAX,
+            hall_of_fame: None,
+            keep_population: false,
+            memetic: None,
             mutation_probability: 0.01,
+            nan_policy: NanPolicy::Worst,
+            objective: Objective::Minimise,
             population_size: 1_024,
+            restart: None,
             time_limit: Duration::from_secs(5),
         }
     }
@@ -1,4 +1,9 @@
 use crate::settings::concurrency::ConcurrencySettings;
+use crate::settings::hall_of_fame::HallOfFameSettings;
+use crate::settings::memetic::MemeticSettings;
+use crate::settings::nan_policy::NanPolicy;
+use crate::settings::objective::Objective;
+use crate::settings::restart::RestartSettings;
 use crate::settings::search::SearchSettings;
 use crate::Failure;
 use std::time::Duration;
@@ -10,8 +15,15 @@ pub struct SearchSettingsBuilder {
     concurrency: ConcurrencySettings,
     cost_target: f64,
     epoch_limit: usize,
+    evaluation_limit: usize,
+    hall_of_fame: Option<HallOfFameSettings>,
+    keep_population: bool,
+    memetic: Option<MemeticSettings>,
     mutation_probability: f64,
+    nan_policy: NanPolicy,
+    objective: Objective,
     population_size: usize,
+    restart: Option<RestartSettings>,
     time_limit: Duration,
 }
 
@@ -22,8 +34,15 @@ impl SearchSettingsBuilder {
             concurrency: settings.concurrency(),
             cost_target: settings.cost_target(),
             epoch_limit: settings.epoch_limit(),
+            evaluation_limit: settings.evaluation_limit(),
+            hall_of_fame: settings.hall_of_fame(),
+            keep_population: settings.keep_population(),
+            memetic: settings.memetic(),
             mutation_probability: settings.mutation_probability(),
+            nan_policy: settings.nan_policy(),
+            objective: settings.objective(),
             population_size: settings.population_size(),
+            restart: settings.restart(),
             time_limit: settings.time_limit(),
         }
     }
@@ -38,7 +57,9 @@ impl SearchSettingsBuilder {
     /// Genome costs are expected to be higher at the start of the search process and become
     /// smaller as the search progresses.
     /// The search will terminate when the cost of any genome in the population is less than or
-    /// equal to this value.   
+    /// equal to this value.
+    /// When maximising, it terminates when the cost is greater than or equal to this value
+    /// instead, so the default of 0 is rarely suitable.
     pub fn cost_target(mut self, value: f64) -> Self {
         self.cost_target = value;
         self
@@ -51,6 +72,36 @@ impl SearchSettingsBuilder {
         self
     }
 
+    /// The maximum number of times [`evaluate()`](crate::Genetic#evaluate()) may be called
+    /// before terminating the search.
+    /// The limit is checked at the end of each epoch, so it may be exceeded by up to one epoch.
+    pub fn evaluation_limit(mut self, value: usize) -> Self {
+        self.evaluation_limit = value;
+        self
+    }
+
+    /// How the best distinct genomes found at any epoch are kept.
+    /// `None` disables the hall of fame.
+    /// This only applies when a search is created.
+    pub fn hall_of_fame(mut self, value: Option<HallOfFameSettings>) -> Self {
+        self.hall_of_fame = value;
+        self
+    }
+
+    /// When true, the final population is returned with its costs in
+    /// [`Success::population`](crate::Success::population).
+    pub fn keep_population(mut self, value: bool) -> Self {
+        self.keep_population = value;
+        self
+    }
+
+    /// How new genomes are improved by local search.
+    /// `None` disables local search.
+    pub fn memetic(mut self, value: Option<MemeticSettings>) -> Self {
+        self.memetic = value;
+        self
+    }
+
     /// The probability of a newly generated genome being mutated.
     /// The mutation implementation is defined by the [`mutate()`](crate::Genetic#mutate()).
     pub fn mutation_probability(mut self, value: f64) -> Self {
@@ -58,12 +109,35 @@ impl SearchSettingsBuilder {
         self
     }
 
+    /// What happens when [`evaluate()`](crate::Genetic#evaluate()) or
+    /// [`violation()`](crate::Genetic#violation()) returns NaN.
+    /// This only applies when a search is created.
+    pub fn nan_policy(mut self, value: NanPolicy) -> Self {
+        self.nan_policy = value;
+        self
+    }
+
+    /// Whether the search minimises or maximises the value returned by
+    /// [`evaluate()`](crate::Genetic#evaluate()).
+    /// This only applies when a search is created.
+    pub fn objective(mut self, value: Objective) -> Self {
+        self.objective = value;
+        self
+    }
+
     /// The number of genomes that exist at any one epoch (iteration) of the search.
     pub fn population_size(mut self, value: usize) -> Self {
         self.population_size = value;
         self
     }
 
+    /// When and how the search restarts from a fresh population.
+    /// `None` disables restarts.
+    pub fn restart(mut self, value: Option<RestartSettings>) -> Self {
+        self.restart = value;
+        self
+    }
+
     /// The maximum duration of the search.
     pub fn time_limit(mut self, value: Duration) -> Self {
         self.time_limit = value;
@@ -76,8 +150,15 @@ impl SearchSettingsBuilder {
             self.concurrency,
             self.cost_target,
             self.epoch_limit,
+            self.evaluation_limit,
+            self.hall_of_fame,
+            self.keep_population,
+            self.memetic,
             self.mutation_probability,
+            self.nan_policy,
+            self.objective,
             self.population_size,
+            self.restart,
             self.time_limit,
         )
     }
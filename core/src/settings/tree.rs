@@ -0,0 +1,131 @@
+use crate::Failure;
+
+/// The settings for genetic programming with expression trees.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct TreeSettings {
+    min_init_depth: usize,
+    max_init_depth: usize,
+    max_depth: usize,
+    max_size: usize,
+    mutation_depth: usize,
+    parsimony_coefficient: f64,
+    point_mutation_weight: f64,
+    subtree_mutation_weight: f64,
+    hoist_mutation_weight: f64,
+}
+
+impl TreeSettings {
+    /// The smallest depth used by ramped half-and-half initialization.
+    pub fn min_init_depth(&self) -> usize {
+        self.min_init_depth
+    }
+
+    /// The largest depth used by ramped half-and-half initialization.
+    pub fn max_init_depth(&self) -> usize {
+        self.max_init_depth
+    }
+
+    /// The maximum depth of any tree produced by crossover or mutation.
+    /// Offspring that exceed this limit are replaced by a copy of their first parent.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// The maximum number of nodes in any tree produced by crossover or mutation.
+    /// Offspring that exceed this limit are replaced by a copy of their first parent.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// The maximum depth of the random subtree inserted by subtree mutation.
+    pub fn mutation_depth(&self) -> usize {
+        self.mutation_depth
+    }
+
+    /// The cost added per node of a tree, to apply parsimony pressure against bloat.
+    pub fn parsimony_coefficient(&self) -> f64 {
+        self.parsimony_coefficient
+    }
+
+    /// The relative weight of point mutation, which replaces a single node.
+    pub fn point_mutation_weight(&self) -> f64 {
+        self.point_mutation_weight
+    }
+
+    /// The relative weight of subtree mutation, which replaces a subtree with a random one.
+    pub fn subtree_mutation_weight(&self) -> f64 {
+        self.subtree_mutation_weight
+    }
+
+    /// The relative weight of hoist mutation, which replaces a subtree with one of its own
+    /// subtrees.
+    pub fn hoist_mutation_weight(&self) -> f64 {
+        self.hoist_mutation_weight
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        min_init_depth: usize,
+        max_init_depth: usize,
+        max_depth: usize,
+        max_size: usize,
+        mutation_depth: usize,
+        parsimony_coefficient: f64,
+        point_mutation_weight: f64,
+        subtree_mutation_weight: f64,
+        hoist_mutation_weight: f64,
+    ) -> Result<TreeSettings, Failure> {
+        if min_init_depth > max_init_depth || max_init_depth > max_depth {
+            return Err(Failure::tree_depth());
+        }
+
+        if max_size < 1 {
+            return Err(Failure::tree_size());
+        }
+
+        if !parsimony_coefficient.is_finite() || parsimony_coefficient < 0.0 {
+            return Err(Failure::parsimony_coefficient());
+        }
+
+        let weights = [
+            point_mutation_weight,
+            subtree_mutation_weight,
+            hoist_mutation_weight,
+        ];
+        let total: f64 = weights.iter().sum();
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) || total <= 0.0 || !total.is_finite()
+        {
+            return Err(Failure::mutation_weights());
+        }
+
+        Ok(Self {
+            min_init_depth,
+            max_init_depth,
+            max_depth,
+            max_size,
+            mutation_depth,
+            parsimony_coefficient,
+            point_mutation_weight,
+            subtree_mutation_weight,
+            hoist_mutation_weight,
+        })
+    }
+}
+
+impl Default for TreeSettings {
+    /// The default tree settings.
+    /// These follow the depth limits commonly used since Koza.
+    fn default() -> Self {
+        TreeSettings {
+            min_init_depth: 2,
+            max_init_depth: 6,
+            max_depth: 17,
+            max_size: 512,
+            mutation_depth: 4,
+            parsimony_coefficient: 0.0,
+            point_mutation_weight: 1.0,
+            subtree_mutation_weight: 1.0,
+            hoist_mutation_weight: 1.0,
+        }
+    }
+}
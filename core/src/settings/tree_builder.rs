@@ -0,0 +1,109 @@
+use crate::settings::tree::TreeSettings;
+use crate::Failure;
+
+/// Use to construct the settings required for genetic programming with expression trees.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct TreeSettingsBuilder {
+    min_init_depth: usize,
+    max_init_depth: usize,
+    max_depth: usize,
+    max_size: usize,
+    mutation_depth: usize,
+    parsimony_coefficient: f64,
+    point_mutation_weight: f64,
+    subtree_mutation_weight: f64,
+    hoist_mutation_weight: f64,
+}
+
+impl TreeSettingsBuilder {
+    /// The settings used to initialize the builder.
+    pub fn from(settings: &TreeSettings) -> Self {
+        Self {
+            min_init_depth: settings.min_init_depth(),
+            max_init_depth: settings.max_init_depth(),
+            max_depth: settings.max_depth(),
+            max_size: settings.max_size(),
+            mutation_depth: settings.mutation_depth(),
+            parsimony_coefficient: settings.parsimony_coefficient(),
+            point_mutation_weight: settings.point_mutation_weight(),
+            subtree_mutation_weight: settings.subtree_mutation_weight(),
+            hoist_mutation_weight: settings.hoist_mutation_weight(),
+        }
+    }
+
+    /// The smallest depth used by ramped half-and-half initialization.
+    pub fn min_init_depth(mut self, value: usize) -> Self {
+        self.min_init_depth = value;
+        self
+    }
+
+    /// The largest depth used by ramped half-and-half initialization.
+    pub fn max_init_depth(mut self, value: usize) -> Self {
+        self.max_init_depth = value;
+        self
+    }
+
+    /// The maximum depth of any tree produced by crossover or mutation.
+    pub fn max_depth(mut self, value: usize) -> Self {
+        self.max_depth = value;
+        self
+    }
+
+    /// The maximum number of nodes in any tree produced by crossover or mutation.
+    pub fn max_size(mut self, value: usize) -> Self {
+        self.max_size = value;
+        self
+    }
+
+    /// The maximum depth of the random subtree inserted by subtree mutation.
+    pub fn mutation_depth(mut self, value: usize) -> Self {
+        self.mutation_depth = value;
+        self
+    }
+
+    /// The cost added per node of a tree, to apply parsimony pressure against bloat.
+    pub fn parsimony_coefficient(mut self, value: f64) -> Self {
+        self.parsimony_coefficient = value;
+        self
+    }
+
+    /// The relative weight of point mutation, which replaces a single node.
+    pub fn point_mutation_weight(mut self, value: f64) -> Self {
+        self.point_mutation_weight = value;
+        self
+    }
+
+    /// The relative weight of subtree mutation, which replaces a subtree with a random one.
+    pub fn subtree_mutation_weight(mut self, value: f64) -> Self {
+        self.subtree_mutation_weight = value;
+        self
+    }
+
+    /// The relative weight of hoist mutation, which replaces a subtree with one of its own
+    /// subtrees.
+    pub fn hoist_mutation_weight(mut self, value: f64) -> Self {
+        self.hoist_mutation_weight = value;
+        self
+    }
+
+    /// Construct the settings required for genetic programming with expression trees.
+    pub fn build(&self) -> Result<TreeSettings, Failure> {
+        TreeSettings::new(
+            self.min_init_depth,
+            self.max_init_depth,
+            self.max_depth,
+            self.max_size,
+            self.mutation_depth,
+            self.parsimony_coefficient,
+            self.point_mutation_weight,
+            self.subtree_mutation_weight,
+            self.hoist_mutation_weight,
+        )
+    }
+}
+
+impl Default for TreeSettingsBuilder {
+    fn default() -> Self {
+        TreeSettingsBuilder::from(&TreeSettings::default())
+    }
+}
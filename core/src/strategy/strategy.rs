@@ -0,0 +1,83 @@
+use crate::{Distance, Failure, Genetic, Observer, ProgressSnapshot, SearchSettings, Success};
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Define a search algorithm, such as a generational genetic algorithm or differential
+/// evolution.
+///
+/// A strategy owns a population of evaluated genomes.
+/// Strategies always minimise cost: when the objective is to maximise, the
+/// [`Genetic`] passed to a strategy returns negated values from
+/// [`evaluate()`](crate::Genetic#evaluate()).
+/// The search calls [`Strategy::initialize`] once and then, in each epoch, tracks the best
+/// genome, reports progress and checks for termination before calling [`Strategy::step`].
+///
+/// See [`crate::search_with`].
+pub trait Strategy<G>
+where
+    G: Clone + Debug + PartialEq + Send + Sync,
+{
+    /// Create and evaluate the initial population, which must not be empty.
+    /// Return a failure if the settings are not suitable for the strategy.
+    fn initialize(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+    ) -> Result<(), Failure>;
+
+    /// Replace the population with the next evaluated population.
+    ///
+    /// # Arguments
+    ///
+    /// * `genetic` - Define the genetic operations on a chromosome `G`.
+    /// * `settings` - Configuration of the search.
+    /// * `snapshot` - The progress of the search up to and including the current population.
+    /// * `observer` - Notify of selection and of each child created and evaluated, where
+    ///   applicable.
+    fn step(
+        &mut self,
+        genetic: &(dyn Genetic<G> + Send + Sync),
+        settings: &SearchSettings,
+        snapshot: &ProgressSnapshot<G>,
+        observer: &mut dyn Observer<G>,
+    );
+
+    /// The current population.
+    fn population(&self) -> &[G];
+
+    /// The cost of each genome in the current population.
+    fn costs(&self) -> &[f64];
+
+    /// The constraint violation of each genome in the current population.
+    /// See [`crate::Genetic::violation`].
+    fn violations(&self) -> &[f64];
+
+    /// Replace the genome at `index` in the current population with an evaluated genome.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the genome to replace.
+    /// * `genome` - The new genome.
+    /// * `cost` - The cost of the new genome.
+    /// * `violation` - The constraint violation of the new genome.
+    fn replace(&mut self, index: usize, genome: G, cost: f64, violation: f64);
+
+    /// The measure of distance used to report diversity when
+    /// [`distance()`](crate::Genetic#distance()) provides none.
+    /// The default implementation returns `None`.
+    fn distance(&self) -> Option<&dyn Distance<G>> {
+        None
+    }
+
+    /// The total time spent in local search.
+    /// The default implementation returns zero.
+    fn local_search_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /// Add details specific to the strategy to the result of the search.
+    /// The default implementation returns the result unchanged.
+    fn finish(&self, success: Success<G>) -> Success<G> {
+        success
+    }
+}
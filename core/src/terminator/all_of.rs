@@ -0,0 +1,33 @@
+use crate::{ProgressSnapshot, Reason, Terminator};
+
+/// Terminate when all of several conditions are met in the same epoch.
+/// The reason is that of the first condition.
+pub struct AllOf<G> {
+    terminators: Vec<Box<dyn Terminator<G>>>,
+}
+
+impl<G> AllOf<G> {
+    /// Create a condition that is met when all of `terminators` are met.
+    /// An empty list of terminators is never met.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminators` - The conditions to check.
+    pub fn new(terminators: Vec<Box<dyn Terminator<G>>>) -> Self {
+        Self { terminators }
+    }
+}
+
+impl<G> Terminator<G> for AllOf<G> {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        let reasons: Vec<Option<Reason>> = self
+            .terminators
+            .iter_mut()
+            .map(|terminator| terminator.terminate(snapshot))
+            .collect();
+        if reasons.iter().any(Option::is_none) {
+            return None;
+        }
+        reasons.into_iter().next().flatten()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    CostTarget, EpochLimit, EvaluationBudget, ProgressSnapshot, Reason, SearchSettings, Terminator,
+    TimeLimit,
+};
+
+/// Terminate when any of several conditions is met, with the reason of the first one.
+pub struct AnyOf<G> {
+    terminators: Vec<Box<dyn Terminator<G>>>,
+}
+
+impl<G> AnyOf<G> {
+    /// Create a condition that is met when any of `terminators` is met.
+    /// Every terminator is checked in every epoch, so each can keep its state up to date.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminators` - The conditions to check, in order of precedence.
+    pub fn new(terminators: Vec<Box<dyn Terminator<G>>>) -> Self {
+        Self { terminators }
+    }
+
+    /// Create the condition used when a search is not given a terminator: the epoch limit, cost
+    /// target, time limit and evaluation limit in `settings`, in that order of precedence.
+    /// Combine it with other terminators to keep these limits, for example
+    /// `AnyOf::new(vec![Box::new(AnyOf::limits(&settings)), custom])`.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The configuration of the search.
+    pub fn limits(settings: &SearchSettings) -> Self {
+        // The settings have already checked that the epoch limit is valid.
+        let epoch_limit = EpochLimit::new(settings.epoch_limit())
+            .ok()
+            .map(|epoch_limit| Box::new(epoch_limit) as Box<dyn Terminator<G>>);
+        let limits: [Box<dyn Terminator<G>>; 3] = [
+            Box::new(CostTarget::new(
+                settings.cost_target(),
+                settings.objective(),
+            )),
+            Box::new(TimeLimit::new(settings.time_limit())),
+            Box::new(EvaluationBudget::new(settings.evaluation_limit())),
+        ];
+        Self::new(epoch_limit.into_iter().chain(limits).collect())
+    }
+}
+
+impl<G> Terminator<G> for AnyOf<G> {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        self.terminators
+            .iter_mut()
+            .map(|terminator| terminator.terminate(snapshot))
+            .fold(None, |first, reason| first.or(reason))
+    }
+}
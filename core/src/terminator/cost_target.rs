@@ -0,0 +1,44 @@
+use crate::{Objective, ProgressSnapshot, Reason, Terminator};
+
+/// Terminate once a feasible genome reaches a target cost.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CostTarget {
+    cost_target: f64,
+    objective: Objective,
+}
+
+impl CostTarget {
+    /// Create a condition that is met once the best genome satisfies its constraints and its
+    /// cost is as good as `cost_target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cost_target` - The cost to reach, in the orientation of `objective`.
+    /// * `objective` - Whether lower or higher costs are better.
+    pub fn new(cost_target: f64, objective: Objective) -> Self {
+        Self {
+            cost_target,
+            objective,
+        }
+    }
+
+    /// The cost to reach.
+    pub fn cost_target(&self) -> f64 {
+        self.cost_target
+    }
+
+    /// Whether lower or higher costs are better.
+    pub fn objective(&self) -> Objective {
+        self.objective
+    }
+}
+
+impl<G> Terminator<G> for CostTarget {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        (snapshot.best_violation() <= 0.0
+            && !self
+                .objective
+                .is_better(self.cost_target, snapshot.best_cost()))
+        .then_some(Reason::CostTargetReached(snapshot.best_cost()))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{ProgressSnapshot, Reason, Terminator};
+use std::time::Instant;
+
+/// Terminate at a wall-clock deadline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Deadline {
+    deadline: Instant,
+}
+
+impl Deadline {
+    /// Create a condition that is met at the end of the first epoch that finishes at or after
+    /// `deadline`.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - The time at which the search should stop.
+    pub fn new(deadline: Instant) -> Self {
+        Self { deadline }
+    }
+
+    /// The time at which the search should stop.
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+}
+
+impl<G> Terminator<G> for Deadline {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        (Instant::now() >= self.deadline).then_some(Reason::DeadlineReached(snapshot.elapsed()))
+    }
+}
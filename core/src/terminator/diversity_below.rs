@@ -0,0 +1,36 @@
+use crate::{ProgressSnapshot, Reason, Terminator};
+
+/// Terminate when the diversity of the population collapses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiversityBelow {
+    threshold: f64,
+}
+
+impl DiversityBelow {
+    /// Create a condition that is met when the mean distance between genomes falls below
+    /// `threshold`.
+    /// It is never met unless diversity is measured.
+    /// See [`crate::ProgressSnapshot::diversity`].
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The mean distance below which the search stops.
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold }
+    }
+
+    /// The mean distance below which the search stops.
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl<G> Terminator<G> for DiversityBelow {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        snapshot
+            .diversity()
+            .map(|diversity| diversity.mean_distance())
+            .filter(|mean_distance| *mean_distance < self.threshold)
+            .map(Reason::DiversityCollapsed)
+    }
+}
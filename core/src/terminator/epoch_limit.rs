@@ -0,0 +1,32 @@
+use crate::{Failure, ProgressSnapshot, Reason, Terminator};
+
+/// Terminate after a number of epochs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EpochLimit {
+    epochs: usize,
+}
+
+impl EpochLimit {
+    /// Create a condition that is met after `epochs` epochs.
+    ///
+    /// # Arguments
+    ///
+    /// * `epochs` - The number of epochs, which must be at least 1.
+    pub fn new(epochs: usize) -> Result<Self, Failure> {
+        if epochs < 1 {
+            return Err(Failure::epoch_limit());
+        }
+        Ok(Self { epochs })
+    }
+
+    /// The number of epochs.
+    pub fn epochs(&self) -> usize {
+        self.epochs
+    }
+}
+
+impl<G> Terminator<G> for EpochLimit {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        (snapshot.epoch() >= self.epochs).then_some(Reason::Epoch(snapshot.epoch()))
+    }
+}
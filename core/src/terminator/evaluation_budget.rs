@@ -0,0 +1,32 @@
+use crate::{ProgressSnapshot, Reason, Terminator};
+
+/// Terminate once a number of genomes have been evaluated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EvaluationBudget {
+    evaluations: usize,
+}
+
+impl EvaluationBudget {
+    /// Create a condition that is met once [`crate::Genetic::evaluate`] has been called
+    /// `evaluations` times.
+    /// The budget is checked at the end of each epoch, so it may be exceeded by up to one epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `evaluations` - The number of evaluations.
+    pub fn new(evaluations: usize) -> Self {
+        Self { evaluations }
+    }
+
+    /// The number of evaluations.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+}
+
+impl<G> Terminator<G> for EvaluationBudget {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        (snapshot.evaluations() >= self.evaluations)
+            .then_some(Reason::EvaluationLimit(snapshot.evaluations()))
+    }
+}
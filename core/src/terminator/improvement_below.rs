@@ -0,0 +1,56 @@
+use crate::{Failure, ProgressSnapshot, Reason, Terminator};
+use std::collections::VecDeque;
+
+/// Terminate when the best cost stops improving.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImprovementBelow {
+    epsilon: f64,
+    epochs: usize,
+    history: VecDeque<f64>,
+}
+
+impl ImprovementBelow {
+    /// Create a condition that is met when the best cost has improved by less than `epsilon`
+    /// over the last `epochs` epochs.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - The smallest improvement that continues the search, which must be
+    ///   non-negative.
+    /// * `epochs` - The number of epochs over which improvement is measured, which must be at
+    ///   least 1.
+    pub fn new(epsilon: f64, epochs: usize) -> Result<Self, Failure> {
+        let valid = epsilon >= 0.0 && epochs >= 1;
+        if !valid {
+            return Err(Failure::terminator_setting());
+        }
+        Ok(Self {
+            epsilon,
+            epochs,
+            history: VecDeque::with_capacity(epochs + 1),
+        })
+    }
+
+    /// The smallest improvement that continues the search.
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// The number of epochs over which improvement is measured.
+    pub fn epochs(&self) -> usize {
+        self.epochs
+    }
+}
+
+impl<G> Terminator<G> for ImprovementBelow {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        self.history.push_back(snapshot.best_cost());
+        if self.history.len() <= self.epochs {
+            return None;
+        }
+        let earlier = self.history.pop_front().unwrap();
+        // The best cost never gets worse, so this is the improvement whatever the objective.
+        let improvement = (earlier - snapshot.best_cost()).abs();
+        (improvement < self.epsilon).then_some(Reason::InsufficientImprovement(improvement))
+    }
+}
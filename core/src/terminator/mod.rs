@@ -0,0 +1,22 @@
+mod all_of;
+mod any_of;
+mod cost_target;
+mod deadline;
+mod diversity_below;
+mod epoch_limit;
+mod evaluation_budget;
+mod improvement_below;
+#[allow(clippy::module_inception)]
+mod terminator;
+mod time_limit;
+
+pub use all_of::AllOf;
+pub use any_of::AnyOf;
+pub use cost_target::CostTarget;
+pub use deadline::Deadline;
+pub use diversity_below::DiversityBelow;
+pub use epoch_limit::EpochLimit;
+pub use evaluation_budget::EvaluationBudget;
+pub use improvement_below::ImprovementBelow;
+pub use terminator::Terminator;
+pub use time_limit::TimeLimit;
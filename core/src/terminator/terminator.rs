@@ -0,0 +1,26 @@
+use crate::{ProgressSnapshot, Reason};
+
+/// Define a condition that terminates a search.
+///
+/// A terminator is checked at the end of every epoch, in place of the limits in
+/// [`crate::SearchSettings`].
+/// Without a terminator a search checks those limits through [`crate::AnyOf::limits`].
+/// Closures that take a [`ProgressSnapshot`] and return an optional [`Reason`] are terminators,
+/// so custom conditions can use [`Reason::Custom`] to explain themselves.
+///
+/// See [`crate::Search::with_terminator`], [`crate::search_until`] and [`crate::spawn_until`].
+pub trait Terminator<G> {
+    /// Return the reason the search should terminate after the epoch described by `snapshot`,
+    /// if any.
+    /// This is called once for every epoch, so terminators may keep state between calls.
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason>;
+}
+
+impl<G, F> Terminator<G> for F
+where
+    F: FnMut(&ProgressSnapshot<G>) -> Option<Reason>,
+{
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        self(snapshot)
+    }
+}
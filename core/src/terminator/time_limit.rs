@@ -0,0 +1,31 @@
+use crate::{ProgressSnapshot, Reason, Terminator};
+use std::time::Duration;
+
+/// Terminate once the search has run for a length of time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeLimit {
+    time_limit: Duration,
+}
+
+impl TimeLimit {
+    /// Create a condition that is met at the end of the first epoch that finishes at least
+    /// `time_limit` after the search started.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_limit` - The time the search may run for.
+    pub fn new(time_limit: Duration) -> Self {
+        Self { time_limit }
+    }
+
+    /// The time the search may run for.
+    pub fn time_limit(&self) -> Duration {
+        self.time_limit
+    }
+}
+
+impl<G> Terminator<G> for TimeLimit {
+    fn terminate(&mut self, snapshot: &ProgressSnapshot<G>) -> Option<Reason> {
+        (snapshot.elapsed() >= self.time_limit).then_some(Reason::TimeOut(snapshot.elapsed()))
+    }
+}
@@ -0,0 +1,10 @@
+mod node;
+mod primitive;
+#[allow(clippy::module_inception)]
+mod tree;
+mod tree_genetic;
+
+pub use node::Node;
+pub use primitive::{Function, PrimitiveSet, Terminal, TerminalValue};
+pub use tree::Tree;
+pub use tree_genetic::TreeGenetic;
@@ -0,0 +1,44 @@
+/// Define a single node of an expression [`Tree`](crate::Tree).
+///
+/// Nodes refer to primitives by their index in the [`PrimitiveSet`](crate::PrimitiveSet) that
+/// created them and record the `kind` (type) of value they produce, so that the genetic
+/// operators can keep trees type correct without consulting the primitive set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node<V> {
+    /// Apply a function to the values of the `arity` subtrees that follow this node.
+    Function {
+        index: usize,
+        arity: usize,
+        kind: usize,
+    },
+
+    /// Produce the value of a terminal.
+    /// Ephemeral random constants store the value they were created with.
+    Terminal {
+        index: usize,
+        kind: usize,
+        value: Option<V>,
+    },
+}
+
+impl<V> Node<V> {
+    /// The number of child subtrees of this node.
+    pub fn arity(&self) -> usize {
+        match self {
+            Node::Function { arity, .. } => *arity,
+            Node::Terminal { .. } => 0,
+        }
+    }
+
+    /// The kind (type) of the value produced by this node.
+    pub fn kind(&self) -> usize {
+        match self {
+            Node::Function { kind, .. } | Node::Terminal { kind, .. } => *kind,
+        }
+    }
+
+    /// True when this node is a function.
+    pub fn is_function(&self) -> bool {
+        matches!(self, Node::Function { .. })
+    }
+}
@@ -0,0 +1,400 @@
+use crate::tree::node::Node;
+use crate::tree::tree::Tree;
+use crate::Failure;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+type Apply<V> = Arc<dyn Fn(&[V]) -> V + Send + Sync>;
+type Generate<V> = Arc<dyn Fn() -> V + Send + Sync>;
+
+/// Define a function that may appear at the internal nodes of an expression tree.
+pub struct Function<V> {
+    name: Box<str>,
+    kind: usize,
+    arguments: Vec<usize>,
+    apply: Apply<V>,
+}
+
+impl<V> Function<V> {
+    /// Create a function where the result and all arguments are of kind 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name used when printing trees.
+    /// * `arity` - The number of arguments.
+    /// * `apply` - Compute the result from the argument values.
+    pub fn new(
+        name: &str,
+        arity: usize,
+        apply: impl Fn(&[V]) -> V + Send + Sync + 'static,
+    ) -> Self {
+        Self::typed(name, 0, &vec![0; arity], apply)
+    }
+
+    /// Create a strongly typed function.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name used when printing trees.
+    /// * `kind` - The kind (type) of the result.
+    /// * `arguments` - The kind (type) of each argument.
+    /// * `apply` - Compute the result from the argument values.
+    pub fn typed(
+        name: &str,
+        kind: usize,
+        arguments: &[usize],
+        apply: impl Fn(&[V]) -> V + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: Box::from(name),
+            kind,
+            arguments: Vec::from(arguments),
+            apply: Arc::new(apply),
+        }
+    }
+
+    /// The name used when printing trees.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The kind (type) of the result.
+    pub fn kind(&self) -> usize {
+        self.kind
+    }
+
+    /// The kind (type) of each argument.
+    pub fn arguments(&self) -> &[usize] {
+        &self.arguments
+    }
+
+    /// The number of arguments.
+    pub fn arity(&self) -> usize {
+        self.arguments.len()
+    }
+
+    /// Compute the result from the argument values.
+    pub fn apply(&self, arguments: &[V]) -> V {
+        (self.apply)(arguments)
+    }
+}
+
+impl<V> Clone for Function<V> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            kind: self.kind,
+            arguments: self.arguments.clone(),
+            apply: self.apply.clone(),
+        }
+    }
+}
+
+impl<V> Debug for Function<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Function")
+            .field("name", &self.name)
+            .field("kind", &self.kind)
+            .field("arguments", &self.arguments)
+            .finish()
+    }
+}
+
+/// Define where the value of a terminal comes from.
+pub enum TerminalValue<V> {
+    /// The input value at the given index.
+    Variable(usize),
+
+    /// A fixed value.
+    Constant(V),
+
+    /// A random value, generated once when the terminal is added to a tree.
+    Ephemeral(Generate<V>),
+}
+
+impl<V: Clone> Clone for TerminalValue<V> {
+    fn clone(&self) -> Self {
+        match self {
+            TerminalValue::Variable(index) => TerminalValue::Variable(*index),
+            TerminalValue::Constant(value) => TerminalValue::Constant(value.clone()),
+            TerminalValue::Ephemeral(generate) => TerminalValue::Ephemeral(generate.clone()),
+        }
+    }
+}
+
+impl<V: Debug> Debug for TerminalValue<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TerminalValue::Variable(index) => f.debug_tuple("Variable").field(index).finish(),
+            TerminalValue::Constant(value) => f.debug_tuple("Constant").field(value).finish(),
+            TerminalValue::Ephemeral(_) => f.write_str("Ephemeral"),
+        }
+    }
+}
+
+/// Define a terminal that may appear at the leaves of an expression tree.
+#[derive(Clone, Debug)]
+pub struct Terminal<V> {
+    name: Box<str>,
+    kind: usize,
+    value: TerminalValue<V>,
+}
+
+impl<V> Terminal<V> {
+    /// Create a terminal that reads the input value at `index`.
+    pub fn variable(name: &str, index: usize) -> Self {
+        Self::of(name, TerminalValue::Variable(index))
+    }
+
+    /// Create a terminal with a fixed value.
+    pub fn constant(name: &str, value: V) -> Self {
+        Self::of(name, TerminalValue::Constant(value))
+    }
+
+    /// Create an ephemeral random constant.
+    /// Each time the terminal is added to a tree, `generate` is called to produce its value.
+    pub fn ephemeral(name: &str, generate: impl Fn() -> V + Send + Sync + 'static) -> Self {
+        Self::of(name, TerminalValue::Ephemeral(Arc::new(generate)))
+    }
+
+    /// Return the terminal with the kind (type) of its value changed.
+    /// Terminals are of kind 0 by default.
+    pub fn of_kind(mut self, kind: usize) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// The name used when printing trees.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The kind (type) of the value.
+    pub fn kind(&self) -> usize {
+        self.kind
+    }
+
+    /// Where the value of the terminal comes from.
+    pub fn value(&self) -> &TerminalValue<V> {
+        &self.value
+    }
+
+    fn of(name: &str, value: TerminalValue<V>) -> Self {
+        Self {
+            name: Box::from(name),
+            kind: 0,
+            value,
+        }
+    }
+}
+
+/// Define the functions and terminals from which expression trees are built.
+/// Also acts as the interpreter for those trees.
+#[derive(Clone, Debug)]
+pub struct PrimitiveSet<V> {
+    root_kind: usize,
+    functions: Vec<Function<V>>,
+    terminals: Vec<Terminal<V>>,
+}
+
+impl<V: Clone + Debug> PrimitiveSet<V> {
+    /// Create a primitive set for trees that produce a value of kind 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `functions` - The functions that may appear at internal nodes.
+    /// * `terminals` - The terminals that may appear at leaves.
+    pub fn new(functions: Vec<Function<V>>, terminals: Vec<Terminal<V>>) -> Result<Self, Failure> {
+        Self::typed(0, functions, terminals)
+    }
+
+    /// Create a primitive set for strongly typed trees.
+    ///
+    /// # Arguments
+    ///
+    /// * `root_kind` - The kind (type) of the value produced by complete trees.
+    /// * `functions` - The functions that may appear at internal nodes.
+    /// * `terminals` - The terminals that may appear at leaves.
+    pub fn typed(
+        root_kind: usize,
+        functions: Vec<Function<V>>,
+        terminals: Vec<Terminal<V>>,
+    ) -> Result<Self, Failure> {
+        let has_terminal = |kind: usize| terminals.iter().any(|t| t.kind() == kind);
+        let kinds_used = std::iter::once(root_kind).chain(
+            functions
+                .iter()
+                .flat_map(|f| std::iter::once(f.kind()).chain(f.arguments().iter().copied())),
+        );
+        for kind in kinds_used {
+            if !has_terminal(kind) {
+                return Err(Failure::primitive_set());
+            }
+        }
+        Ok(Self {
+            root_kind,
+            functions,
+            terminals,
+        })
+    }
+
+    /// The kind (type) of the value produced by complete trees.
+    pub fn root_kind(&self) -> usize {
+        self.root_kind
+    }
+
+    /// The functions that may appear at internal nodes.
+    pub fn functions(&self) -> &[Function<V>] {
+        &self.functions
+    }
+
+    /// The terminals that may appear at leaves.
+    pub fn terminals(&self) -> &[Terminal<V>] {
+        &self.terminals
+    }
+
+    /// Compute the value of a tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `tree` - A tree created from this primitive set.
+    /// * `inputs` - The values of the variable terminals.
+    pub fn interpret(&self, tree: &Tree<V>, inputs: &[V]) -> V {
+        let mut stack: Vec<V> = Vec::with_capacity(tree.size());
+        for node in tree.nodes().iter().rev() {
+            let value = match node {
+                Node::Function { index, arity, .. } => {
+                    let mut arguments = stack.split_off(stack.len() - arity);
+                    arguments.reverse();
+                    self.functions[*index].apply(&arguments)
+                }
+                Node::Terminal {
+                    value: Some(value), ..
+                } => value.clone(),
+                Node::Terminal { index, .. } => match self.terminals[*index].value() {
+                    TerminalValue::Variable(i) => inputs[*i].clone(),
+                    TerminalValue::Constant(value) => value.clone(),
+                    TerminalValue::Ephemeral(generate) => generate(),
+                },
+            };
+            stack.push(value);
+        }
+        stack.pop().unwrap()
+    }
+
+    /// Format a tree as a prefix (Lisp style) expression.
+    pub fn format(&self, tree: &Tree<V>) -> String {
+        let mut parts: Vec<String> = Vec::with_capacity(tree.size());
+        for node in tree.nodes().iter().rev() {
+            let part = match node {
+                Node::Function { index, arity, .. } => {
+                    let arguments = parts.split_off(parts.len() - arity);
+                    let mut text = format!("({}", self.functions[*index].name());
+                    for argument in arguments.iter().rev() {
+                        text.push(' ');
+                        text.push_str(argument);
+                    }
+                    text.push(')');
+                    text
+                }
+                Node::Terminal {
+                    value: Some(value), ..
+                } => format!("{:?}", value),
+                Node::Terminal { index, .. } => String::from(self.terminals[*index].name()),
+            };
+            parts.push(part);
+        }
+        parts.pop().unwrap()
+    }
+
+    /// Create a random tree where every path from the root to a terminal has length `depth`,
+    /// where the types of the functions allow it.
+    pub fn full(&self, depth: usize) -> Tree<V> {
+        Tree::new(self.generate(self.root_kind, depth, true))
+    }
+
+    /// Create a random tree where no path from the root to a terminal is longer than `depth`.
+    pub fn grow(&self, depth: usize) -> Tree<V> {
+        Tree::new(self.generate(self.root_kind, depth, false))
+    }
+
+    /// Create the nodes of a random subtree that produces a value of `kind`.
+    pub(crate) fn generate(&self, kind: usize, depth: usize, full: bool) -> Vec<Node<V>> {
+        let mut nodes = Vec::new();
+        self.generate_into(&mut nodes, kind, depth, full);
+        nodes
+    }
+
+    /// Return a random node that can replace `node` without changing the type of any argument.
+    pub(crate) fn replacement(&self, node: &Node<V>) -> Node<V> {
+        match node {
+            Node::Function { index, .. } => {
+                let current = &self.functions[*index];
+                let candidates: Vec<usize> = (0..self.functions.len())
+                    .filter(|i| {
+                        let f = &self.functions[*i];
+                        f.kind() == current.kind() && f.arguments() == current.arguments()
+                    })
+                    .collect();
+                self.function_node(*candidates.choose(&mut rand::thread_rng()).unwrap())
+            }
+            Node::Terminal { kind, .. } => self.terminal_node(self.random_terminal(*kind)),
+        }
+    }
+
+    fn generate_into(&self, nodes: &mut Vec<Node<V>>, kind: usize, depth: usize, full: bool) {
+        let functions: Vec<usize> = if depth == 0 {
+            Vec::new()
+        } else {
+            (0..self.functions.len())
+                .filter(|i| self.functions[*i].kind() == kind)
+                .collect()
+        };
+        let terminal_count = self.terminals.iter().filter(|t| t.kind() == kind).count();
+
+        let use_function = !functions.is_empty()
+            && (full
+                || rand::thread_rng().gen_range(0..functions.len() + terminal_count)
+                    < functions.len());
+
+        if use_function {
+            let index = *functions.choose(&mut rand::thread_rng()).unwrap();
+            nodes.push(self.function_node(index));
+            for argument in self.functions[index].arguments() {
+                self.generate_into(nodes, *argument, depth - 1, full);
+            }
+        } else {
+            nodes.push(self.terminal_node(self.random_terminal(kind)));
+        }
+    }
+
+    fn random_terminal(&self, kind: usize) -> usize {
+        let candidates: Vec<usize> = (0..self.terminals.len())
+            .filter(|i| self.terminals[*i].kind() == kind)
+            .collect();
+        *candidates.choose(&mut rand::thread_rng()).unwrap()
+    }
+
+    fn function_node(&self, index: usize) -> Node<V> {
+        let function = &self.functions[index];
+        Node::Function {
+            index,
+            arity: function.arity(),
+            kind: function.kind(),
+        }
+    }
+
+    fn terminal_node(&self, index: usize) -> Node<V> {
+        let terminal = &self.terminals[index];
+        Node::Terminal {
+            index,
+            kind: terminal.kind(),
+            value: match terminal.value() {
+                TerminalValue::Ephemeral(generate) => Some(generate()),
+                _ => None,
+            },
+        }
+    }
+}
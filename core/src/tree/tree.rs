@@ -0,0 +1,97 @@
+use crate::tree::node::Node;
+
+/// Define an expression tree for genetic programming.
+///
+/// The nodes are stored in prefix order: each function node is followed by the subtrees of its
+/// arguments, from first to last.
+/// A tree consisting of a single terminal has a depth of 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tree<V> {
+    nodes: Vec<Node<V>>,
+}
+
+impl<V: Clone> Tree<V> {
+    /// Create a tree from nodes in prefix order.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The nodes of a single complete tree, in prefix order.
+    pub fn new(nodes: Vec<Node<V>>) -> Self {
+        debug_assert!(!nodes.is_empty() && Self::span(&nodes, 0) == nodes.len());
+        Self { nodes }
+    }
+
+    /// The nodes of the tree, in prefix order.
+    pub fn nodes(&self) -> &[Node<V>] {
+        &self.nodes
+    }
+
+    /// The number of nodes in the tree.
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The length of the longest path from the root to a terminal.
+    pub fn depth(&self) -> usize {
+        self.depths().into_iter().max().unwrap_or(0)
+    }
+
+    /// The kind (type) of the value produced by the tree.
+    pub fn kind(&self) -> usize {
+        self.nodes[0].kind()
+    }
+
+    /// The distance of each node from the root, in prefix order.
+    pub fn depths(&self) -> Vec<usize> {
+        let mut result = Vec::with_capacity(self.nodes.len());
+        // The depth of each open function node and the number of its children still to visit.
+        let mut open: Vec<(usize, usize)> = Vec::new();
+        for node in &self.nodes {
+            let depth = match open.last_mut() {
+                Some((parent_depth, remaining)) => {
+                    *remaining -= 1;
+                    *parent_depth + 1
+                }
+                None => 0,
+            };
+            result.push(depth);
+            if node.arity() > 0 {
+                open.push((depth, node.arity()));
+            }
+            while matches!(open.last(), Some((_, 0))) {
+                open.pop();
+            }
+        }
+        result
+    }
+
+    /// The nodes of the subtree rooted at `index`.
+    pub fn subtree(&self, index: usize) -> &[Node<V>] {
+        &self.nodes[index..self.subtree_end(index)]
+    }
+
+    /// The index one past the last node of the subtree rooted at `index`.
+    pub fn subtree_end(&self, index: usize) -> usize {
+        index + Self::span(&self.nodes, index)
+    }
+
+    /// Return a copy of the tree with the subtree rooted at `index` replaced by `replacement`.
+    pub fn replace(&self, index: usize, replacement: &[Node<V>]) -> Self {
+        let end = self.subtree_end(index);
+        let mut nodes = Vec::with_capacity(self.nodes.len() - (end - index) + replacement.len());
+        nodes.extend_from_slice(&self.nodes[..index]);
+        nodes.extend_from_slice(replacement);
+        nodes.extend_from_slice(&self.nodes[end..]);
+        Self::new(nodes)
+    }
+
+    fn span(nodes: &[Node<V>], index: usize) -> usize {
+        let mut needed = 1;
+        let mut i = index;
+        while needed > 0 {
+            needed = needed + nodes[i].arity() - 1;
+            i += 1;
+        }
+        i - index
+    }
+}
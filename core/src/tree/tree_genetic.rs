@@ -0,0 +1,170 @@
+use crate::tree::node::Node;
+use crate::tree::primitive::PrimitiveSet;
+use crate::tree::tree::Tree;
+use crate::{Genetic, TreeSettings};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// The probability of choosing a function node, rather than a terminal, as a crossover or
+/// mutation point.
+const FUNCTION_NODE_PROBABILITY: f64 = 0.9;
+
+type Cost<V> = Arc<dyn Fn(&PrimitiveSet<V>, &Tree<V>) -> f64 + Send + Sync>;
+
+/// Define the genetic operations on expression trees, for genetic programming.
+///
+/// * Initialization uses ramped half-and-half.
+/// * Crossover swaps in a subtree of the same kind (type) from the other parent.
+/// * Mutation is one of point, subtree or hoist mutation, chosen by weight.
+/// * Offspring that exceed the depth or size limits are replaced by a copy of their parent.
+pub struct TreeGenetic<V> {
+    primitives: PrimitiveSet<V>,
+    settings: TreeSettings,
+    cost: Cost<V>,
+}
+
+impl<V: Clone + Debug> TreeGenetic<V> {
+    /// Create the genetic operations for expression trees.
+    ///
+    /// # Arguments
+    ///
+    /// * `primitives` - The functions and terminals from which trees are built.
+    /// * `settings` - The tree depth, size and mutation settings.
+    /// * `cost` - Evaluate the cost of a tree, typically by calling
+    ///   [`PrimitiveSet::interpret`] on some test cases.
+    ///   The parsimony pressure is added to this cost.
+    pub fn new(
+        primitives: PrimitiveSet<V>,
+        settings: TreeSettings,
+        cost: impl Fn(&PrimitiveSet<V>, &Tree<V>) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            primitives,
+            settings,
+            cost: Arc::new(cost),
+        }
+    }
+
+    /// The functions and terminals from which trees are built.
+    pub fn primitives(&self) -> &PrimitiveSet<V> {
+        &self.primitives
+    }
+
+    /// The tree depth, size and mutation settings.
+    pub fn settings(&self) -> &TreeSettings {
+        &self.settings
+    }
+
+    /// Replace the subtree at a random node with a random subtree of the same kind.
+    pub fn subtree_mutation(&self, genome: &Tree<V>) -> Tree<V> {
+        let index = self.random_node(genome, |_| true).unwrap();
+        let depth = rand::thread_rng().gen_range(0..=self.settings.mutation_depth());
+        let replacement = self
+            .primitives
+            .generate(genome.nodes()[index].kind(), depth, false);
+        self.limit(genome.replace(index, &replacement), genome)
+    }
+
+    /// Replace a random node with another primitive of the same signature.
+    pub fn point_mutation(&self, genome: &Tree<V>) -> Tree<V> {
+        let index = self.random_node(genome, |_| true).unwrap();
+        let mut nodes = genome.nodes().to_vec();
+        nodes[index] = self.primitives.replacement(&nodes[index]);
+        Tree::new(nodes)
+    }
+
+    /// Replace the subtree at a random node with one of its own subtrees of the same kind.
+    /// This always produces a smaller tree, which helps to control bloat.
+    pub fn hoist_mutation(&self, genome: &Tree<V>) -> Tree<V> {
+        let index = self.random_node(genome, |_| true).unwrap();
+        let kind = genome.nodes()[index].kind();
+        let end = genome.subtree_end(index);
+        let candidates: Vec<usize> = (index + 1..end)
+            .filter(|i| genome.nodes()[*i].kind() == kind)
+            .collect();
+        match candidates.choose(&mut rand::thread_rng()) {
+            Some(inner) => genome.replace(index, genome.subtree(*inner)),
+            None => genome.clone(),
+        }
+    }
+
+    /// True when the tree is within the depth and size limits.
+    pub fn within_limits(&self, tree: &Tree<V>) -> bool {
+        tree.size() <= self.settings.max_size() && tree.depth() <= self.settings.max_depth()
+    }
+
+    fn limit(&self, offspring: Tree<V>, parent: &Tree<V>) -> Tree<V> {
+        if self.within_limits(&offspring) {
+            offspring
+        } else {
+            parent.clone()
+        }
+    }
+
+    fn random_node(&self, tree: &Tree<V>, filter: impl Fn(&Node<V>) -> bool) -> Option<usize> {
+        let (functions, terminals): (Vec<usize>, Vec<usize>) = (0..tree.size())
+            .filter(|i| filter(&tree.nodes()[*i]))
+            .partition(|i| tree.nodes()[*i].is_function());
+        let mut rng = rand::thread_rng();
+        if !functions.is_empty()
+            && (terminals.is_empty() || rng.gen_bool(FUNCTION_NODE_PROBABILITY))
+        {
+            functions.choose(&mut rng).copied()
+        } else {
+            terminals.choose(&mut rng).copied()
+        }
+    }
+}
+
+impl<V> Genetic<Tree<V>> for TreeGenetic<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn initialize(&self) -> Tree<V> {
+        let mut depth = rand::thread_rng()
+            .gen_range(self.settings.min_init_depth()..=self.settings.max_init_depth());
+        let mut full = rand::thread_rng().gen_bool(0.5);
+        loop {
+            let tree = if full {
+                self.primitives.full(depth)
+            } else {
+                self.primitives.grow(depth)
+            };
+            if tree.size() <= self.settings.max_size() || depth == 0 {
+                return tree;
+            }
+            depth -= 1;
+            full = false;
+        }
+    }
+
+    fn evaluate(&self, genome: &Tree<V>) -> f64 {
+        (self.cost)(&self.primitives, genome)
+            + self.settings.parsimony_coefficient() * genome.size() as f64
+    }
+
+    fn crossover(&self, lhs: &Tree<V>, rhs: &Tree<V>) -> Tree<V> {
+        let index = self.random_node(lhs, |_| true).unwrap();
+        let kind = lhs.nodes()[index].kind();
+        match self.random_node(rhs, |node| node.kind() == kind) {
+            Some(donor) => self.limit(lhs.replace(index, rhs.subtree(donor)), lhs),
+            None => lhs.clone(),
+        }
+    }
+
+    fn mutate(&self, genome: &Tree<V>) -> Tree<V> {
+        let point = self.settings.point_mutation_weight();
+        let subtree = self.settings.subtree_mutation_weight();
+        let hoist = self.settings.hoist_mutation_weight();
+        let choice = rand::thread_rng().gen_range(0.0..point + subtree + hoist);
+        if choice < point {
+            self.point_mutation(genome)
+        } else if choice < point + subtree {
+            self.subtree_mutation(genome)
+        } else {
+            self.hoist_mutation(genome)
+        }
+    }
+}
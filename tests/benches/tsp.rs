@@ -1,8 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::time::Duration;
-use watchmaker::{
-    search, ConcurrencySettings, SearchSettingsBuilder, TournamentSelector, TspGenetic,
-};
+use tests::TspGenetic;
+use watchmaker::{search, ConcurrencySettings, SearchSettingsBuilder, TournamentSelector};
 
 #[inline]
 fn tsp(population_size: usize, cross_over_candidates: usize, concurrency: ConcurrencySettings) {
@@ -27,7 +26,7 @@ fn tsp_population_size_1_024_single_threaded(c: &mut Criterion) {
             tsp(
                 black_box(1_024),
                 black_box(10),
-                black_box(ConcurrencySettings::Single),
+                black_box(ConcurrencySettings::SingleThreaded),
             )
         })
     });
@@ -39,7 +38,7 @@ fn tsp_population_size_32_768_single_threaded(c: &mut Criterion) {
             tsp(
                 black_box(32_768),
                 black_box(15),
-                black_box(ConcurrencySettings::Single),
+                black_box(ConcurrencySettings::SingleThreaded),
             )
         })
     });
@@ -51,7 +50,7 @@ fn tsp_population_size_1_024_multi_threaded(c: &mut Criterion) {
             tsp(
                 black_box(1_024),
                 black_box(10),
-                black_box(ConcurrencySettings::Multi),
+                black_box(ConcurrencySettings::MultiThreaded),
             )
         })
     });
@@ -63,7 +62,7 @@ fn tsp_population_size_32_768_multi_threaded(c: &mut Criterion) {
             tsp(
                 black_box(32_768),
                 black_box(15),
-                black_box(ConcurrencySettings::Multi),
+                black_box(ConcurrencySettings::MultiThreaded),
             )
         })
     });
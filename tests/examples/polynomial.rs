@@ -0,0 +1,31 @@
+use tests::*;
+use watchmaker::*;
+
+// Show genetic programming with expression trees.
+// The genetic program will search for a formula that matches samples of x^2 + x + 1.
+fn main() {
+    println!("This example uses genetic programming to rediscover the formula x^2 + x + 1.");
+    let genetic = make_polynomial_genetic(
+        TreeSettingsBuilder::default()
+            .parsimony_coefficient(0.001)
+            .build()
+            .unwrap(),
+    );
+    let primitives = genetic.primitives().clone();
+    let result = search(
+        Box::new(genetic),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(|snapshot| {
+            println!("{} {}", snapshot.epoch(), snapshot.best_cost());
+        })),
+        &SearchSettingsBuilder::default()
+            .population_size(1_024)
+            .mutation_probability(0.2)
+            .cost_target(0.02)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+    println!("{:?}", result.reason());
+    println!("{}", primitives.format(result.best_genome()));
+}
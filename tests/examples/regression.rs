@@ -0,0 +1,36 @@
+use std::time::Duration;
+use watchmaker::*;
+
+// Show symbolic regression.
+// The genetic program will search for a formula that fits the CSV data, which was sampled from
+// the area of a circle, a = 3.14159 r^2.
+fn main() {
+    println!("This example uses symbolic regression to find a formula that fits CSV data.");
+    let csv = "r,a\n0.5,0.785\n1.0,3.142\n1.5,7.069\n2.0,12.566\n2.5,19.635\n3.0,28.274\n";
+    let regression = SymbolicRegression::new(
+        Dataset::from_csv(csv.as_bytes(), "a").unwrap(),
+        &Operator::scientific(),
+        Metric::RSquared,
+        TreeSettingsBuilder::default()
+            .parsimony_coefficient(0.0001)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+    let result = regression
+        .search(
+            Box::new(TournamentSelector::default()),
+            None,
+            &SearchSettingsBuilder::default()
+                .population_size(2_048)
+                .mutation_probability(0.2)
+                .time_limit(Duration::from_secs(10))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    println!("{:?}", result.reason());
+    println!("R²:    {}", regression.score(result.best_genome()));
+    println!("infix: {}", regression.infix(result.best_genome()));
+    println!("LaTeX: {}", regression.latex(result.best_genome()));
+}
@@ -1,13 +1,19 @@
 mod assertions;
 mod math;
 mod peak;
+mod polynomial;
 mod random;
+mod real;
 mod tsp;
 mod weasel;
 
-pub use assertions::*;
 pub use math::*;
-pub use peak::*;
+pub use peak::{
+    peak_distance, ConstrainedPeakGenetic, MultiPeakGenetic, PeakGenetic, PeakGenome, LIMIT, PEAKS,
+    TARGET,
+};
+pub use polynomial::*;
 pub use random::*;
+pub use real::*;
 pub use tsp::*;
 pub use weasel::*;
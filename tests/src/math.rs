@@ -4,6 +4,6 @@ pub fn round(value: f64, base: usize, places: usize) -> f64 {
         value.trunc()
     } else {
         let scale = (base * places) as f64;
-        (value * scale).floor() / scale as f64
+        (value * scale).floor() / scale
     }
 }
@@ -25,4 +25,85 @@ impl Genetic<PeakGenome> for PeakGenetic {
     fn mutate(&self, original: &PeakGenome) -> PeakGenome {
         PeakGenome(original.0 + rand::thread_rng().gen_range(-10.0..10.0))
     }
+
+    fn distance(&self) -> Option<&dyn Distance<PeakGenome>> {
+        Some(&peak_distance)
+    }
+}
+
+/// The upper bound on the genome value for the constrained variant of the peak problem.
+pub const LIMIT: f64 = 50.0;
+
+/// The peak problem with the constraint that the genome value must not exceed [`LIMIT`].
+/// The best feasible genome is therefore [`LIMIT`] rather than [`TARGET`].
+#[derive(Default)]
+pub struct ConstrainedPeakGenetic {
+    pub repair: bool,
+}
+
+impl Genetic<PeakGenome> for ConstrainedPeakGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic {}.initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PeakGenetic {}.evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenetic {}.crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        PeakGenetic {}.mutate(original)
+    }
+
+    fn violation(&self, genome: &PeakGenome) -> f64 {
+        (genome.0 - LIMIT).max(0.0)
+    }
+
+    fn repair(&self, genome: PeakGenome) -> PeakGenome {
+        if self.repair {
+            PeakGenome(genome.0.min(LIMIT))
+        } else {
+            genome
+        }
+    }
+}
+
+/// The locations of the equally good optima of the multimodal variant of the peak problem.
+pub const PEAKS: [f64; 3] = [200.0, 500.0, 800.0];
+
+/// The peak problem with several equally good optima, at [`PEAKS`].
+#[derive(Default)]
+pub struct MultiPeakGenetic {}
+
+impl Genetic<PeakGenome> for MultiPeakGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic {}.initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PEAKS
+            .iter()
+            .map(|peak| (peak - genome.0).abs())
+            .fold(f64::MAX, f64::min)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        if (lhs.0 - rhs.0).abs() < 50.0 {
+            PeakGenetic {}.crossover(lhs, rhs)
+        } else {
+            lhs.clone()
+        }
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        PeakGenetic {}.mutate(original)
+    }
+}
+
+/// The distance between two peak genomes.
+pub fn peak_distance(lhs: &PeakGenome, rhs: &PeakGenome) -> f64 {
+    (lhs.0 - rhs.0).abs()
 }
@@ -0,0 +1,42 @@
+use rand::Rng;
+use watchmaker::*;
+
+/// The polynomial that the genetic program should rediscover: x^2 + x + 1.
+pub fn polynomial(x: f64) -> f64 {
+    x * x + x + 1.0
+}
+
+/// Make the arithmetic primitives used by the polynomial examples.
+pub fn make_arithmetic_primitives() -> PrimitiveSet<f64> {
+    PrimitiveSet::new(
+        vec![
+            Function::new("+", 2, |a: &[f64]| a[0] + a[1]),
+            Function::new("-", 2, |a: &[f64]| a[0] - a[1]),
+            Function::new("*", 2, |a: &[f64]| a[0] * a[1]),
+        ],
+        vec![
+            Terminal::variable("x", 0),
+            Terminal::constant("1", 1.0),
+            Terminal::ephemeral("c", || rand::thread_rng().gen_range(-1.0..1.0)),
+        ],
+    )
+    .unwrap()
+}
+
+/// Make the genetic operations for a search that rediscovers [`polynomial`] from samples.
+pub fn make_polynomial_genetic(settings: TreeSettings) -> TreeGenetic<f64> {
+    let samples: Vec<(f64, f64)> = (-10..=10)
+        .map(|i| i as f64 / 5.0)
+        .map(|x| (x, polynomial(x)))
+        .collect();
+    TreeGenetic::new(
+        make_arithmetic_primitives(),
+        settings,
+        move |primitives, tree| {
+            samples
+                .iter()
+                .map(|(x, y)| (primitives.interpret(tree, &[*x]) - y).abs())
+                .sum()
+        },
+    )
+}
@@ -0,0 +1,102 @@
+use rand::Rng;
+use watchmaker::*;
+
+/// A real-valued test problem: minimise a cost function over a box centred on the origin.
+pub struct RealGenetic {
+    dimensions: usize,
+    bound: f64,
+    cost: fn(&[f64]) -> f64,
+}
+
+impl RealGenetic {
+    /// The sum of squares, with minimum 0 at the origin.
+    pub fn sphere(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            bound: 5.0,
+            cost: sphere,
+        }
+    }
+
+    /// The Rosenbrock valley, with minimum 0 at (1, 1, ...).
+    pub fn rosenbrock(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            bound: 5.0,
+            cost: rosenbrock,
+        }
+    }
+
+    /// The highly multimodal Rastrigin function, with minimum 0 at the origin.
+    pub fn rastrigin(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            bound: 5.12,
+            cost: rastrigin,
+        }
+    }
+}
+
+impl Genetic<Vec<f64>> for RealGenetic {
+    fn initialize(&self) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..self.dimensions)
+            .map(|_| rng.gen_range(-self.bound..self.bound))
+            .collect()
+    }
+
+    fn evaluate(&self, genome: &Vec<f64>) -> f64 {
+        (self.cost)(genome)
+    }
+
+    fn crossover(&self, lhs: &Vec<f64>, rhs: &Vec<f64>) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        std::iter::zip(lhs, rhs)
+            .map(|(l, r)| if rng.gen_bool(0.5) { *l } else { *r })
+            .collect()
+    }
+
+    fn mutate(&self, genome: &Vec<f64>) -> Vec<f64> {
+        let mut result = genome.clone();
+        let i = rand::thread_rng().gen_range(0..result.len());
+        result[i] += rand::thread_rng().gen_range(-0.1..0.1) * self.bound;
+        result
+    }
+
+    fn mutate_scaled(&self, genome: &Vec<f64>, step_size: f64) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        genome
+            .iter()
+            .map(|x| {
+                // Box-Muller transform of two uniform samples.
+                let u: f64 = 1.0 - rng.gen::<f64>();
+                let v: f64 = rng.gen();
+                x + step_size * (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+            })
+            .collect()
+    }
+
+    fn repair(&self, genome: Vec<f64>) -> Vec<f64> {
+        genome
+            .into_iter()
+            .map(|x| x.clamp(-self.bound, self.bound))
+            .collect()
+    }
+}
+
+fn sphere(x: &[f64]) -> f64 {
+    x.iter().map(|x| x * x).sum()
+}
+
+fn rosenbrock(x: &[f64]) -> f64 {
+    x.windows(2)
+        .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (1.0 - w[0]).powi(2))
+        .sum()
+}
+
+fn rastrigin(x: &[f64]) -> f64 {
+    10.0 * x.len() as f64
+        + x.iter()
+            .map(|x| x * x - 10.0 * (2.0 * std::f64::consts::PI * x).cos())
+            .sum::<f64>()
+}
@@ -17,7 +17,7 @@ pub struct TspGenetic {
     locations: Vec<Location>,
 }
 
-impl<'a> Genetic<TspGenome> for TspGenetic {
+impl Genetic<TspGenome> for TspGenetic {
     fn initialize(&self) -> TspGenome {
         let mut locations: Vec<usize> = (0..self.locations.len()).collect();
         locations.shuffle(&mut rand::thread_rng());
@@ -26,7 +26,7 @@ impl<'a> Genetic<TspGenome> for TspGenetic {
 
     fn evaluate(&self, genome: &TspGenome) -> f64 {
         let last = genome.locations.len() - 1;
-        let mut cost = self.cost_of_arc(last, genome.locations[0]);
+        let mut cost = self.cost_of_arc(genome.locations[last], genome.locations[0]);
         for i in 0..last {
             cost += self.cost_of_arc(genome.locations[i], genome.locations[i + 1]);
         }
@@ -54,6 +54,40 @@ impl<'a> Genetic<TspGenome> for TspGenetic {
         result.locations.swap(lhs, rhs);
         result
     }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<TspGenome>> {
+        Some(self)
+    }
+}
+
+impl LocalSearch<TspGenome> for TspGenetic {
+    /// Apply 2-opt moves, reversing a segment of the tour whenever that shortens it, until no
+    /// move improves the tour.
+    fn improve(&self, genome: &TspGenome) -> TspGenome {
+        let mut tour = genome.locations.clone();
+        let n = tour.len();
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n - 1 {
+                for j in i + 2..n {
+                    let (a, b) = (tour[i], tour[i + 1]);
+                    let (c, d) = (tour[j], tour[(j + 1) % n]);
+                    if a == d {
+                        continue;
+                    }
+                    let delta = self.cost_of_arc(a, c) + self.cost_of_arc(b, d)
+                        - self.cost_of_arc(a, b)
+                        - self.cost_of_arc(c, d);
+                    if delta < -1e-9 {
+                        tour[i + 1..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+        TspGenome { locations: tour }
+    }
 }
 
 impl TspGenetic {
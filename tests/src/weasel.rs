@@ -37,10 +37,10 @@ impl Genetic<WSGenome> for WSGenetic {
 
     fn mutate(&self, original: &WSGenome) -> WSGenome {
         let chars = original.0.as_str();
-        let mutate_index = thread_rng().gen_range(0..chars.len() as usize);
-        let chars_result = chars.chars().into_iter().enumerate().map(|(i, c)| {
+        let mutate_index = thread_rng().gen_range(0..chars.len());
+        let chars_result = chars.chars().enumerate().map(|(i, c)| {
             if i == mutate_index {
-                char::from(thread_rng().sample(&RandomStringDistribution::new(CHARACTERS)))
+                char::from(thread_rng().sample(RandomStringDistribution::new(CHARACTERS)))
             } else {
                 c
             }
@@ -0,0 +1,99 @@
+use tests::RealGenetic;
+use watchmaker::*;
+
+fn minimise(genetic: RealGenetic, population_size: usize) -> Success<Vec<f64>> {
+    cma_es(
+        Box::new(genetic),
+        &CmaSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(population_size)
+            .epoch_limit(5_000)
+            .cost_target(1e-10)
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn minimises_sphere() {
+    let success = minimise(RealGenetic::sphere(10), 10);
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+}
+
+#[test]
+fn minimises_rosenbrock() {
+    let success = minimise(RealGenetic::rosenbrock(6), 32);
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+    for x in success.best_genome() {
+        assert_eq!((x - 1.0).abs() < 1e-3, true);
+    }
+}
+
+#[test]
+fn reports_progress_every_epoch() {
+    let costs = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let recorded = costs.clone();
+    let success = cma_es(
+        Box::new(RealGenetic::sphere(3)),
+        &CmaSettings::default(),
+        Some(Box::new(move |snapshot| {
+            recorded.borrow_mut().push(snapshot.best_cost())
+        })),
+        &SearchSettingsBuilder::default()
+            .population_size(8)
+            .epoch_limit(20)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    let costs = costs.borrow();
+    assert_eq!(success.reason(), Reason::Epoch(20));
+    assert_eq!(costs.len(), 20);
+    assert_eq!(costs.windows(2).all(|w| w[1] <= w[0]), true);
+}
+
+#[test]
+fn fails_when_population_is_too_small() {
+    let result = cma_es(
+        Box::new(RealGenetic::sphere(2)),
+        &CmaSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(1)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::cma_setting());
+}
+
+#[test]
+fn samples_are_reported_as_mutated_children() {
+    let success = cma_es(
+        Box::new(RealGenetic::sphere(3)),
+        &CmaSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(8)
+            .epoch_limit(20)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.operators().crossover().applications(), 0);
+    assert_eq!(success.operators().mutation().applications(), 8 * 19);
+    assert_eq!(success.operators().mutation().improvements() > 0, true);
+}
@@ -0,0 +1,24 @@
+use std::cmp::Ordering;
+use watchmaker::*;
+
+#[test]
+fn lower_costs_are_less() {
+    assert_eq!(compare_costs(1.0, 2.0), Ordering::Less);
+    assert_eq!(compare_costs(2.0, 1.0), Ordering::Greater);
+    assert_eq!(compare_costs(-3.0, -2.0), Ordering::Less);
+}
+
+#[test]
+fn infinite_costs_compare_by_value() {
+    assert_eq!(compare_costs(f64::MAX, f64::INFINITY), Ordering::Less);
+    assert_eq!(compare_costs(f64::NEG_INFINITY, f64::MIN), Ordering::Less);
+    assert_eq!(compare_costs(f64::INFINITY, f64::INFINITY), Ordering::Equal);
+}
+
+#[test]
+fn nan_is_worse_than_every_cost() {
+    assert_eq!(compare_costs(f64::NAN, f64::INFINITY), Ordering::Greater);
+    assert_eq!(compare_costs(-f64::NAN, f64::INFINITY), Ordering::Greater);
+    assert_eq!(compare_costs(f64::INFINITY, f64::NAN), Ordering::Less);
+    assert_eq!(compare_costs(f64::NAN, -f64::NAN), Ordering::Equal);
+}
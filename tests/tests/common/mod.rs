@@ -1 +1,2 @@
+mod costs;
 mod math;
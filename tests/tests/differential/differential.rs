@@ -0,0 +1,135 @@
+use tests::RealGenetic;
+use watchmaker::*;
+
+fn minimise(genetic: RealGenetic, differential: DifferentialSettings) -> Success<Vec<f64>> {
+    differential_evolution(
+        Box::new(genetic),
+        &differential,
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(40)
+            .epoch_limit(2_000)
+            .cost_target(1e-8)
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+fn variant(variant: DifferentialVariant) -> DifferentialSettings {
+    DifferentialSettingsBuilder::default()
+        .variant(variant)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn rand_1_bin_minimises_sphere() {
+    let success = minimise(
+        RealGenetic::sphere(5),
+        variant(DifferentialVariant::Rand1Bin),
+    );
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+}
+
+#[test]
+fn best_1_bin_minimises_sphere() {
+    let success = minimise(
+        RealGenetic::sphere(5),
+        variant(DifferentialVariant::Best1Bin),
+    );
+
+    // DE/best/1/bin is greedy and occasionally stalls just short of the cost target.
+    assert_eq!(success.best_cost() < 1e-4, true);
+}
+
+#[test]
+fn current_to_best_1_bin_minimises_sphere() {
+    let success = minimise(
+        RealGenetic::sphere(5),
+        variant(DifferentialVariant::CurrentToBest1Bin),
+    );
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+}
+
+#[test]
+fn jde_minimises_rosenbrock() {
+    let success = minimise(
+        RealGenetic::rosenbrock(4),
+        DifferentialSettingsBuilder::default()
+            .adaptation(ParameterAdaptation::Jde)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+    for x in success.best_genome() {
+        assert_eq!((x - 1.0).abs() < 1e-3, true);
+    }
+}
+
+#[test]
+fn reports_progress_every_epoch() {
+    let epochs = std::rc::Rc::new(std::cell::Cell::new(0));
+    let counter = epochs.clone();
+    let success = differential_evolution(
+        Box::new(RealGenetic::sphere(2)),
+        &DifferentialSettings::default(),
+        Some(Box::new(move |snapshot| counter.set(snapshot.epoch()))),
+        &SearchSettingsBuilder::default()
+            .population_size(10)
+            .epoch_limit(7)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::Epoch(7));
+    assert_eq!(epochs.get(), 7);
+}
+
+#[test]
+fn fails_when_population_is_too_small() {
+    let result = differential_evolution(
+        Box::new(RealGenetic::sphere(2)),
+        &DifferentialSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(3)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::differential_setting());
+}
+
+#[test]
+fn trials_are_reported_as_mutated_children() {
+    let success = differential_evolution(
+        Box::new(RealGenetic::sphere(5)),
+        &DifferentialSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(40)
+            .epoch_limit(5)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.operators().crossover().applications(), 0);
+    assert_eq!(success.operators().mutation().applications(), 40 * 4);
+    assert_eq!(success.operators().mutation().improvements() > 0, true);
+}
@@ -0,0 +1,61 @@
+use watchmaker::*;
+
+#[test]
+fn hamming_distance_counts_differing_positions() {
+    assert_eq!(
+        HammingDistance.distance(&vec![1, 2, 3, 4], &vec![1, 0, 3, 0]),
+        2.0
+    );
+}
+
+#[test]
+fn hamming_distance_counts_extra_elements() {
+    assert_eq!(HammingDistance.distance("abcde", "abx"), 3.0);
+}
+
+#[test]
+fn hamming_distance_of_identical_genomes_is_zero() {
+    let genome = String::from("METHINKS");
+    assert_eq!(HammingDistance.distance(&genome, &genome), 0.0);
+}
+
+#[test]
+fn euclidean_distance_is_straight_line_distance() {
+    assert_eq!(
+        EuclideanDistance.distance(&vec![1.0, 2.0], &vec![4.0, 6.0]),
+        5.0
+    );
+}
+
+#[test]
+fn euclidean_distance_treats_missing_elements_as_zero() {
+    assert_eq!(EuclideanDistance.distance(&vec![3.0], &vec![0.0, 4.0]), 5.0);
+}
+
+#[test]
+fn permutation_distance_ignores_rotation_and_reversal() {
+    let tour = vec![0, 1, 2, 3, 4];
+    assert_eq!(
+        PermutationDistance.distance(&tour, &vec![2, 3, 4, 0, 1]),
+        0.0
+    );
+    assert_eq!(
+        PermutationDistance.distance(&tour, &vec![4, 3, 2, 1, 0]),
+        0.0
+    );
+}
+
+#[test]
+fn permutation_distance_counts_missing_adjacencies() {
+    // Swapping 1 and 3 breaks the adjacencies 0-1 and 3-4, but keeps 1-2 and 2-3.
+    assert_eq!(
+        PermutationDistance.distance(&vec![0, 1, 2, 3, 4], &vec![0, 3, 2, 1, 4]),
+        2.0
+    );
+}
+
+#[test]
+fn closure_is_a_distance() {
+    let distance = |lhs: &i32, rhs: &i32| (lhs - rhs).abs() as f64;
+    assert_eq!(distance.distance(&3, &-4), 7.0);
+}
@@ -0,0 +1,75 @@
+use watchmaker::*;
+
+fn absolute(lhs: &f64, rhs: &f64) -> f64 {
+    (lhs - rhs).abs()
+}
+
+#[test]
+fn measure_identical_population() {
+    let diversity = Diversity::measure(&[7.0, 7.0, 7.0, 7.0], &absolute);
+
+    assert_eq!(diversity.mean_distance(), 0.0);
+    assert_eq!(diversity.sampled_distance(), 0.0);
+    assert_eq!(diversity.entropy(), 0.0);
+    assert_eq!(diversity.unique(), 1);
+}
+
+#[test]
+fn measure_distinct_population() {
+    let diversity = Diversity::measure(&[0.0, 1.0, 2.0, 3.0], &absolute);
+
+    // Pairwise distances are 1, 2, 3, 1, 2 and 1.
+    assert_eq!(diversity.mean_distance(), 10.0 / 6.0);
+    assert_eq!(diversity.sampled_distance() >= 1.0, true);
+    assert_eq!(diversity.sampled_distance() <= 3.0, true);
+    assert_eq!(diversity.entropy(), 2.0);
+    assert_eq!(diversity.unique(), 4);
+}
+
+#[test]
+fn measure_entropy_of_repeated_genomes() {
+    let diversity = Diversity::measure(&[0.0, 0.0, 1.0, 1.0], &absolute);
+
+    assert_eq!(diversity.entropy(), 1.0);
+    assert_eq!(diversity.unique(), 2);
+}
+
+#[test]
+fn measure_single_genome() {
+    let diversity = Diversity::measure(&[1.0], &absolute);
+
+    assert_eq!(diversity, Diversity::new(0.0, 0.0, 0.0, 1));
+}
+
+#[test]
+fn measure_large_population_samples_distances() {
+    let population: Vec<f64> = (0..1_024).map(f64::from).collect();
+    let calls = std::cell::Cell::new(0);
+    let counting = |lhs: &f64, rhs: &f64| {
+        calls.set(calls.get() + 1);
+        absolute(lhs, rhs)
+    };
+
+    let diversity = Diversity::measure(&population, &counting);
+
+    assert_eq!(calls.get(), population.len());
+    assert_eq!(diversity.mean_distance(), diversity.sampled_distance());
+}
+
+#[test]
+fn measure_large_population_samples_genomes() {
+    let population: Vec<f64> = (0..1_024).map(f64::from).collect();
+
+    let diversity = Diversity::measure(&population, &absolute);
+
+    assert_eq!(diversity.unique(), 256);
+    assert_eq!(diversity.entropy(), 8.0);
+}
+
+#[test]
+fn measure_large_identical_population() {
+    let diversity = Diversity::measure(&[7.0; 1_024], &absolute);
+
+    assert_eq!(diversity.entropy(), 0.0);
+    assert_eq!(diversity.unique(), 1);
+}
@@ -0,0 +1,2 @@
+mod distance;
+mod diversity;
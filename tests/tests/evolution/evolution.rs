@@ -0,0 +1,90 @@
+use tests::{assert_between, PeakGenetic, RealGenetic};
+use watchmaker::*;
+
+fn settings(parents: usize, epoch_limit: usize, cost_target: f64) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(parents)
+        .epoch_limit(epoch_limit)
+        .cost_target(cost_target)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn comma_strategy_with_self_adaptation_minimises_sphere() {
+    let success = evolution_strategy(
+        Box::new(RealGenetic::sphere(5)),
+        &EvolutionSettingsBuilder::default()
+            .offspring(35)
+            .self_adaptive(true)
+            .learning_rate(1.0 / 5.0_f64.sqrt())
+            .recombination_probability(0.5)
+            .build()
+            .unwrap(),
+        None,
+        &settings(5, 5_000, 1e-8),
+    )
+    .unwrap();
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+}
+
+#[test]
+fn plus_strategy_finds_peak() {
+    let success = evolution_strategy(
+        Box::new(PeakGenetic::default()),
+        &EvolutionSettingsBuilder::default()
+            .offspring(10)
+            .survivor(Survivor::Plus)
+            .build()
+            .unwrap(),
+        None,
+        &settings(10, 1_000, 0.5),
+    )
+    .unwrap();
+
+    assert_between!(success.best_genome().0, 99.5, 100.5);
+}
+
+#[test]
+fn plus_strategy_never_loses_best_parent() {
+    let costs = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let recorded = costs.clone();
+    let success = evolution_strategy(
+        Box::new(RealGenetic::rastrigin(3)),
+        &EvolutionSettingsBuilder::default()
+            .offspring(2)
+            .survivor(Survivor::Plus)
+            .build()
+            .unwrap(),
+        Some(Box::new(move |snapshot| {
+            recorded.borrow_mut().push(snapshot.best_cost())
+        })),
+        &settings(4, 50, 0.0),
+    )
+    .unwrap();
+
+    let costs = costs.borrow();
+    assert_eq!(costs.len(), 50);
+    assert_eq!(costs.windows(2).all(|w| w[1] <= w[0]), true);
+    assert_eq!(success.best_cost(), costs[49]);
+}
+
+#[test]
+fn fails_when_comma_strategy_has_fewer_offspring_than_parents() {
+    let result = evolution_strategy(
+        Box::new(PeakGenetic::default()),
+        &EvolutionSettingsBuilder::default()
+            .offspring(4)
+            .build()
+            .unwrap(),
+        None,
+        &settings(5, 10, 0.0),
+    );
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::evolution_setting());
+}
@@ -0,0 +1,145 @@
+use tests::{PeakGenetic, PeakGenome, RealGenetic};
+use watchmaker::*;
+
+fn settings(epoch_limit: usize) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(16)
+        .epoch_limit(epoch_limit)
+        .cost_target(-1.0)
+        .build()
+        .unwrap()
+}
+
+fn run(genealogy: &Genealogy<PeakGenome>, epoch_limit: usize) -> Success<PeakGenome> {
+    search_observed(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        Observers::new().with(Box::new(genealogy.clone())),
+        &settings(epoch_limit),
+    )
+    .unwrap()
+}
+
+#[test]
+fn fails_when_retention_is_zero() {
+    assert_eq!(
+        Genealogy::<PeakGenome>::new(0).err(),
+        Some(Failure::genealogy_setting())
+    );
+}
+
+#[test]
+fn initial_population_has_no_parents() {
+    let genealogy = Genealogy::new(10).unwrap();
+
+    run(&genealogy, 1);
+
+    let ids = genealogy.population_ids();
+    assert_eq!(ids.len(), 16);
+    for id in ids {
+        let birth = genealogy.birth(id).unwrap();
+        assert_eq!(birth.epoch(), 1);
+        assert_eq!(birth.parents().is_empty(), true);
+    }
+}
+
+#[test]
+fn children_record_parents_from_previous_epoch() {
+    let genealogy = Genealogy::new(10).unwrap();
+
+    run(&genealogy, 2);
+
+    for id in genealogy.population_ids() {
+        let birth = genealogy.birth(id).unwrap();
+        if birth.epoch() == 2 {
+            assert_parents_born_in_first_epoch(&genealogy, &birth);
+        }
+    }
+}
+
+#[test]
+fn survivors_are_identified_after_reordering() {
+    let genealogy = Genealogy::new(10).unwrap();
+
+    search_observed(
+        Box::new(RealGenetic::sphere(2)),
+        Box::new(EvolutionStrategy::new(
+            EvolutionSettingsBuilder::default()
+                .offspring(32)
+                .survivor(Survivor::Plus)
+                .build()
+                .unwrap(),
+        )),
+        Observers::new().with(Box::new(genealogy.clone())),
+        &settings(5),
+    )
+    .unwrap();
+
+    for id in genealogy.population_ids() {
+        let birth = genealogy.birth(id).unwrap();
+        assert_eq!(birth.epoch() == 1 || !birth.parents().is_empty(), true);
+    }
+}
+
+fn assert_parents_born_in_first_epoch(genealogy: &Genealogy<PeakGenome>, birth: &Birth) {
+    assert_eq!(birth.parents().is_empty(), false);
+    for parent in birth.parents() {
+        assert_eq!(genealogy.birth(*parent).unwrap().epoch(), 1);
+    }
+}
+
+#[test]
+fn best_lineage_ends_with_best_genome() {
+    let genealogy = Genealogy::new(100).unwrap();
+
+    let success = run(&genealogy, 20);
+
+    let lineage = genealogy.best_lineage();
+    let best = genealogy.birth(genealogy.best_id().unwrap()).unwrap();
+    assert_eq!(best.cost(), success.best_cost());
+    assert_eq!(lineage.births().last().unwrap(), &best);
+    assert_eq!(lineage.births().iter().any(|b| b.epoch() == 1), true);
+}
+
+#[test]
+fn records_outside_retention_window_are_discarded() {
+    let genealogy = Genealogy::new(3).unwrap();
+
+    run(&genealogy, 20);
+
+    let keep = genealogy.population_ids();
+    for birth in genealogy.best_lineage().births() {
+        let retained = birth.epoch() >= 18
+            || keep.contains(&birth.id())
+            || genealogy.best_id() == Some(birth.id());
+        assert_eq!(retained, true);
+    }
+}
+
+#[test]
+fn lineage_exports_dot_and_json() {
+    let genealogy = Genealogy::new(100).unwrap();
+
+    run(&genealogy, 3);
+
+    let lineage = genealogy.best_lineage();
+    let dot = lineage.to_dot();
+    let json = lineage.to_json();
+    assert_eq!(dot.starts_with("digraph lineage {"), true);
+    assert_eq!(json.starts_with("{\"nodes\":["), true);
+    for birth in lineage.births() {
+        assert_eq!(dot.contains(&format!("    {} [label=", birth.id())), true);
+        assert_eq!(json.contains(&format!("{{\"id\":{},", birth.id())), true);
+        for parent in birth.parents() {
+            assert_eq!(dot.contains(&format!("{} -> {}", parent, birth.id())), true);
+        }
+    }
+}
+
+#[test]
+fn lineage_of_unknown_genome_is_empty() {
+    let genealogy = Genealogy::<PeakGenome>::new(10).unwrap();
+
+    assert_eq!(genealogy.lineage(42).births().is_empty(), true);
+    assert_eq!(genealogy.best_lineage().to_json(), "{\"nodes\":[]}");
+}
@@ -0,0 +1,281 @@
+use tests::{peak_distance, ConstrainedPeakGenetic, PeakGenetic, PeakGenome, LIMIT, TARGET};
+use watchmaker::*;
+
+fn settings() -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(100)
+        .epoch_limit(400)
+        .mutation_probability(0.5)
+        .cost_target(0.5)
+        .build()
+        .unwrap()
+}
+
+fn run<G>(genetic: Box<dyn Genetic<G> + Send + Sync>) -> Success<G>
+where
+    G: Clone + std::fmt::Debug + PartialEq + Send + Sync + 'static,
+{
+    search(
+        genetic,
+        Box::new(TournamentSelector::default()),
+        None,
+        &settings(),
+    )
+    .unwrap()
+}
+
+fn peak() -> Box<dyn Genetic<PeakGenome> + Send + Sync> {
+    Box::new(PeakGenetic::default())
+}
+
+/// The peak problem with a distance measure that the wrapped genetic operations do not have.
+struct DistancePeakGenetic {}
+
+impl Genetic<PeakGenome> for DistancePeakGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic::default().initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PeakGenetic::default().evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, genome: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().mutate(genome)
+    }
+
+    fn distance(&self) -> Option<&dyn Distance<PeakGenome>> {
+        Some(&peak_distance)
+    }
+}
+
+fn pair_cost(genome: &(PeakGenome, PeakGenome)) -> f64 {
+    (TARGET - genome.0 .0).abs() + (TARGET - genome.1 .0).abs()
+}
+
+#[test]
+fn pair_operations_apply_to_each_part() {
+    let genetic = PairGenetic::new(
+        peak(),
+        Box::new(ConstrainedPeakGenetic::default()),
+        pair_cost,
+    );
+    let lhs = (PeakGenome(0.0), PeakGenome(LIMIT + 10.0));
+    let rhs = (PeakGenome(20.0), PeakGenome(LIMIT + 30.0));
+
+    assert_eq!(
+        genetic.crossover(&lhs, &rhs),
+        (PeakGenome(10.0), PeakGenome(LIMIT + 20.0))
+    );
+    assert_eq!(genetic.violation(&lhs), 10.0);
+    assert_eq!(
+        genetic.evaluate(&lhs),
+        100.0 + (TARGET - LIMIT - 10.0).abs()
+    );
+
+    for _ in 0..20 {
+        let mutated = genetic.mutate(&lhs);
+        assert_eq!((mutated.0 != lhs.0) != (mutated.1 != lhs.1), true);
+    }
+}
+
+#[test]
+fn pair_distance_requires_both_parts() {
+    let without = PairGenetic::new(
+        Box::new(ConstrainedPeakGenetic::default()),
+        Box::new(DistancePeakGenetic {}),
+        pair_cost,
+    );
+    assert_eq!(without.distance().is_none(), true);
+
+    let with = PairGenetic::new(
+        Box::new(DistancePeakGenetic {}),
+        Box::new(DistancePeakGenetic {}),
+        pair_cost,
+    );
+    let lhs = (PeakGenome(0.0), PeakGenome(5.0));
+    let rhs = (PeakGenome(3.0), PeakGenome(1.0));
+    assert_eq!(with.distance().unwrap().distance(&lhs, &rhs), 7.0);
+}
+
+#[test]
+fn pair_search_optimizes_both_parts() {
+    let success = run(Box::new(PairGenetic::new(peak(), peak(), pair_cost)));
+
+    assert_eq!(success.best_cost() < 0.5, true);
+    assert_eq!((success.best_genome().0 .0 - TARGET).abs() < 0.5, true);
+    assert_eq!((success.best_genome().1 .0 - TARGET).abs() < 0.5, true);
+}
+
+/// A genome with a named field, represented by a [`PeakGenome`].
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    x: f64,
+}
+
+fn mapped() -> MappedGenetic<Point, PeakGenome> {
+    MappedGenetic::new(
+        Box::new(DistancePeakGenetic {}),
+        |point: &Point| PeakGenome(point.x),
+        |genome: PeakGenome| Point { x: genome.0 },
+    )
+}
+
+#[test]
+fn mapped_operations_convert_genomes() {
+    let genetic = mapped();
+    let lhs = Point { x: 0.0 };
+    let rhs = Point { x: 20.0 };
+
+    assert_eq!(genetic.evaluate(&lhs), TARGET);
+    assert_eq!(genetic.crossover(&lhs, &rhs), Point { x: 10.0 });
+    assert_eq!(genetic.distance().unwrap().distance(&lhs, &rhs), 20.0);
+    assert_eq!((genetic.mutate(&lhs).x - lhs.x).abs() <= 10.0, true);
+}
+
+/// The peak problem with a local search that moves straight to the target.
+struct ClimbingPeakGenetic {}
+
+impl Genetic<PeakGenome> for ClimbingPeakGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic::default().initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PeakGenetic::default().evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, genome: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().mutate(genome)
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<PeakGenome>> {
+        Some(self)
+    }
+}
+
+impl LocalSearch<PeakGenome> for ClimbingPeakGenetic {
+    fn improve(&self, _genome: &PeakGenome) -> PeakGenome {
+        PeakGenome(TARGET)
+    }
+}
+
+#[test]
+fn mapped_local_search_converts_genomes() {
+    let genetic = MappedGenetic::new(
+        Box::new(ClimbingPeakGenetic {}),
+        |point: &Point| PeakGenome(point.x),
+        |genome: PeakGenome| Point { x: genome.0 },
+    );
+
+    let improved = genetic.local_search().unwrap().improve(&Point { x: 0.0 });
+
+    assert_eq!(improved, Point { x: TARGET });
+    assert_eq!(mapped().local_search().is_none(), true);
+}
+
+#[test]
+fn mapped_search_finds_target() {
+    let success = run(Box::new(mapped()));
+
+    assert_eq!((success.best_genome().x - TARGET).abs() < 0.5, true);
+}
+
+fn vector_cost(genes: &[PeakGenome]) -> f64 {
+    genes.iter().map(|gene| (TARGET - gene.0).abs()).sum()
+}
+
+fn vector(mutation_rate: f64) -> VecGenetic<PeakGenome> {
+    VecGenetic::new(
+        Box::new(DistancePeakGenetic {}),
+        4,
+        mutation_rate,
+        vector_cost,
+    )
+    .unwrap()
+}
+
+#[test]
+fn vector_settings_are_validated() {
+    assert_eq!(VecGenetic::new(peak(), 0, 0.5, vector_cost).is_err(), true);
+    assert_eq!(VecGenetic::new(peak(), 4, -0.1, vector_cost).is_err(), true);
+    assert_eq!(VecGenetic::new(peak(), 4, 1.1, vector_cost).is_err(), true);
+    assert_eq!(
+        VecGenetic::new(peak(), 4, f64::NAN, vector_cost).is_err(),
+        true
+    );
+
+    let genetic = vector(0.25);
+    assert_eq!(genetic.length(), 4);
+    assert_eq!(genetic.mutation_rate(), 0.25);
+    assert_eq!(genetic.initialize().len(), 4);
+}
+
+#[test]
+fn vector_operations_apply_to_each_gene() {
+    let genetic = vector(1.0);
+    let lhs = vec![PeakGenome(0.0); 4];
+    let rhs = vec![PeakGenome(20.0); 4];
+
+    assert_eq!(genetic.crossover(&lhs, &rhs), vec![PeakGenome(10.0); 4]);
+    assert_eq!(genetic.distance().unwrap().distance(&lhs, &rhs), 80.0);
+    assert_eq!(
+        genetic
+            .mutate(&lhs)
+            .iter()
+            .zip(&lhs)
+            .all(|(mutated, original)| mutated != original),
+        true
+    );
+}
+
+#[test]
+fn vector_mutation_changes_at_least_one_gene() {
+    let genetic = vector(0.0);
+    let genes = vec![PeakGenome(0.0); 4];
+
+    for _ in 0..20 {
+        let changed = genetic
+            .mutate(&genes)
+            .iter()
+            .zip(&genes)
+            .filter(|(mutated, original)| mutated != original)
+            .count();
+        assert_eq!(changed, 1);
+    }
+}
+
+#[test]
+fn vector_search_optimizes_every_gene() {
+    let success = run(Box::new(vector(1.0)));
+
+    assert_eq!(success.best_cost() < 20.0, true);
+}
+
+#[test]
+fn vector_reports_each_gene_to_the_gene_operations() {
+    let operators = Operators::new(peak(), OperatorSelection::Weighted)
+        .unwrap()
+        .with_crossover("mean", 1.0, |lhs: &PeakGenome, rhs: &PeakGenome| {
+            PeakGenetic::default().crossover(lhs, rhs)
+        })
+        .unwrap();
+    let monitor = operators.monitor();
+
+    let success = run(Box::new(
+        VecGenetic::new(Box::new(operators), 4, 0.25, vector_cost).unwrap(),
+    ));
+
+    assert_eq!(
+        monitor.crossovers()[0].statistics().applications(),
+        4 * 100 * (success.epoch() - 1)
+    );
+}
@@ -1,9 +1,22 @@
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::module_inception,
+    clippy::needless_range_loop
+)]
+
+mod cma;
 mod common;
+mod differential;
+mod diversity;
+mod evolution;
+mod genealogy;
+mod genetic;
+mod niching;
+mod operators;
+mod regression;
 mod search;
 mod selector;
 mod settings;
-
-pub use common::*;
-pub use search::*;
-pub use selector::*;
-pub use settings::*;
+mod strategy;
+mod terminator;
+mod tree;
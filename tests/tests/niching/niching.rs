@@ -0,0 +1,145 @@
+use tests::*;
+use watchmaker::*;
+
+fn search_peaks(method: NichingMethod) -> Success<PeakGenome> {
+    search_peaks_of(Box::new(MultiPeakGenetic::default()), method)
+}
+
+fn search_peaks_of(
+    genetic: Box<dyn Genetic<PeakGenome> + Send + Sync>,
+    method: NichingMethod,
+) -> Success<PeakGenome> {
+    search_niching(
+        genetic,
+        Box::new(TournamentSelector::new(2).unwrap()),
+        Niching::new(method, 100.0, true, peak_distance).unwrap(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(256)
+            .mutation_probability(0.2)
+            .epoch_limit(100)
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+fn assert_every_peak_is_represented(success: &Success<PeakGenome>) {
+    for peak in PEAKS {
+        assert_eq!(
+            success
+                .representatives()
+                .iter()
+                .any(|(genome, cost)| (genome.0 - peak).abs() < 5.0 && *cost < 5.0),
+            true,
+            "no representative for peak {} in {:?}",
+            peak,
+            success.representatives()
+        );
+    }
+}
+
+#[test]
+fn fails_when_radius_is_not_positive() {
+    let result = Niching::new(
+        NichingMethod::DeterministicCrowding,
+        0.0,
+        false,
+        peak_distance,
+    );
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::niching_setting());
+}
+
+#[test]
+fn fails_when_clearing_capacity_is_zero() {
+    let result = Niching::new(
+        NichingMethod::Clearing { capacity: 0 },
+        1.0,
+        false,
+        peak_distance,
+    );
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::niching_setting());
+}
+
+#[test]
+fn representatives_are_empty_unless_requested() {
+    let success = search(
+        Box::new(MultiPeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .epoch_limit(4)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.representatives().is_empty(), true);
+}
+
+#[test]
+fn deterministic_crowding_finds_every_peak() {
+    assert_every_peak_is_represented(&search_peaks(NichingMethod::DeterministicCrowding));
+}
+
+#[test]
+fn probabilistic_crowding_finds_every_peak() {
+    assert_every_peak_is_represented(&search_peaks(NichingMethod::ProbabilisticCrowding));
+}
+
+#[test]
+fn clearing_finds_every_peak() {
+    assert_every_peak_is_represented(&search_peaks(NichingMethod::Clearing { capacity: 8 }));
+}
+
+#[test]
+fn sharing_finds_every_peak() {
+    assert_every_peak_is_represented(&search_peaks(NichingMethod::Sharing { alpha: 1.0 }));
+}
+
+/// The peaks of [`MultiPeakGenetic`], with an infinite cost outside them.
+struct BoundedPeakGenetic;
+
+impl Genetic<PeakGenome> for BoundedPeakGenetic {
+    fn initialize(&self) -> PeakGenome {
+        MultiPeakGenetic::default().initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        if (100.0..900.0).contains(&genome.0) {
+            MultiPeakGenetic::default().evaluate(genome)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        MultiPeakGenetic::default().crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        MultiPeakGenetic::default().mutate(original)
+    }
+}
+
+#[test]
+fn sharing_finds_every_peak_with_infinite_costs() {
+    let success = search_peaks_of(
+        Box::new(BoundedPeakGenetic),
+        NichingMethod::Sharing { alpha: 1.0 },
+    );
+    assert_every_peak_is_represented(&success);
+}
+
+#[test]
+fn clearing_finds_every_peak_with_infinite_costs() {
+    let success = search_peaks_of(
+        Box::new(BoundedPeakGenetic),
+        NichingMethod::Clearing { capacity: 8 },
+    );
+    assert_every_peak_is_represented(&success);
+}
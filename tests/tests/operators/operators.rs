@@ -0,0 +1,325 @@
+use tests::{PeakGenetic, PeakGenome, TARGET};
+use watchmaker::*;
+
+fn settings(mutation_probability: f64) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(16)
+        .epoch_limit(10)
+        .cost_target(f64::NEG_INFINITY)
+        .mutation_probability(mutation_probability)
+        .build()
+        .unwrap()
+}
+
+fn run(operators: Operators<PeakGenome>, settings: &SearchSettings) -> Success<PeakGenome> {
+    search_observed(
+        Box::new(operators),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        Observers::new(),
+        settings,
+    )
+    .unwrap()
+}
+
+fn operators(selection: OperatorSelection) -> Operators<PeakGenome> {
+    Operators::new(Box::new(PeakGenetic::default()), selection).unwrap()
+}
+
+fn copy(lhs: &PeakGenome, _rhs: &PeakGenome) -> PeakGenome {
+    lhs.clone()
+}
+
+fn mean(lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+    PeakGenetic::default().crossover(lhs, rhs)
+}
+
+fn nudge(genome: &PeakGenome) -> PeakGenome {
+    PeakGenetic::default().mutate(genome)
+}
+
+/// A mutation that always at least doubles the distance from the peak, so it never improves.
+fn worsen(genome: &PeakGenome) -> PeakGenome {
+    let distance = genome.0 - TARGET;
+    PeakGenome(TARGET + 2.0 * distance + distance.signum())
+}
+
+/// Run the peak problem with a mutation operator that can improve and one that cannot,
+/// returning the reports of the mutation operators.
+fn compete(selection: OperatorSelection) -> Vec<OperatorReport> {
+    let operators = operators(selection)
+        .with_crossover("copy", 1.0, copy)
+        .unwrap()
+        .with_mutation("worsen", 1.0, worsen)
+        .unwrap()
+        .with_mutation("nudge", 1.0, nudge)
+        .unwrap();
+    let monitor = operators.monitor();
+    run(operators, &settings(1.0));
+    monitor.mutations()
+}
+
+/// True if the improving mutation was applied at least twice as often as the other.
+fn favoured(mutations: &[OperatorReport]) -> bool {
+    mutations[1].statistics().applications() >= 2 * mutations[0].statistics().applications()
+}
+
+#[test]
+fn invalid_settings_are_rejected() {
+    let rejected = |selection| Operators::new(Box::new(PeakGenetic::default()), selection).is_err();
+
+    assert_eq!(
+        rejected(OperatorSelection::ProbabilityMatching {
+            adaptation_rate: 0.0,
+            min_probability: 0.1,
+        }),
+        true
+    );
+    assert_eq!(
+        rejected(OperatorSelection::AdaptivePursuit {
+            adaptation_rate: 0.5,
+            learning_rate: 1.5,
+            min_probability: 0.1,
+        }),
+        true
+    );
+    assert_eq!(
+        rejected(OperatorSelection::ProbabilityMatching {
+            adaptation_rate: 0.5,
+            min_probability: 1.0,
+        }),
+        true
+    );
+    assert_eq!(
+        rejected(OperatorSelection::UpperConfidenceBound { exploration: -1.0 }),
+        true
+    );
+    assert_eq!(rejected(OperatorSelection::Weighted), false);
+}
+
+#[test]
+fn invalid_weights_are_rejected() {
+    let weighted = || operators(OperatorSelection::Weighted);
+
+    assert_eq!(weighted().with_mutation("a", -1.0, nudge).is_err(), true);
+    assert_eq!(
+        weighted().with_mutation("a", f64::NAN, nudge).is_err(),
+        true
+    );
+    assert_eq!(weighted().with_crossover("a", 0.0, copy).is_err(), true);
+    assert_eq!(
+        weighted()
+            .with_crossover("a", 1.0, copy)
+            .unwrap()
+            .with_crossover("b", 0.0, mean)
+            .is_ok(),
+        true
+    );
+}
+
+#[test]
+fn weighted_probabilities_are_normalized_weights() {
+    let operators = operators(OperatorSelection::Weighted)
+        .with_crossover("copy", 1.0, copy)
+        .unwrap()
+        .with_crossover("mean", 3.0, mean)
+        .unwrap();
+    let monitor = operators.monitor();
+
+    let crossovers = monitor.crossovers();
+    assert_eq!(crossovers.len(), 2);
+    assert_eq!(crossovers[0].name(), "copy");
+    assert_eq!(crossovers[0].probability(), 0.25);
+    assert_eq!(crossovers[1].name(), "mean");
+    assert_eq!(crossovers[1].probability(), 0.75);
+    assert_eq!(monitor.mutations().is_empty(), true);
+}
+
+#[test]
+fn every_child_is_credited_to_its_operators() {
+    let operators = operators(OperatorSelection::Weighted)
+        .with_crossover("mean", 1.0, mean)
+        .unwrap()
+        .with_crossover("copy", 0.0, copy)
+        .unwrap();
+    let monitor = operators.monitor();
+
+    let success = run(operators, &settings(1.0));
+
+    let crossovers = monitor.crossovers();
+    assert_eq!(crossovers[0].statistics().applications(), 16 * 9);
+    assert_eq!(
+        crossovers[0].statistics().improvements(),
+        success.operators().crossover().improvements()
+    );
+    assert_eq!(crossovers[1].statistics().applications(), 0);
+}
+
+/// The peak problem starting from a single genome, with a local search that rounds genomes, so
+/// the population is full of duplicates and children are changed after they are created.
+struct Rounded;
+
+impl Genetic<PeakGenome> for Rounded {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenome(0.0)
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PeakGenetic::default().evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        copy(lhs, rhs)
+    }
+
+    fn mutate(&self, genome: &PeakGenome) -> PeakGenome {
+        genome.clone()
+    }
+
+    fn local_search(&self) -> Option<&dyn LocalSearch<PeakGenome>> {
+        Some(self)
+    }
+}
+
+impl LocalSearch<PeakGenome> for Rounded {
+    fn improve(&self, genome: &PeakGenome) -> PeakGenome {
+        PeakGenome(genome.0.round())
+    }
+}
+
+#[test]
+fn duplicate_and_improved_children_are_credited_to_their_operators() {
+    let operators = Operators::new(Box::new(Rounded), OperatorSelection::Weighted)
+        .unwrap()
+        .with_crossover("copy", 1.0, copy)
+        .unwrap()
+        .with_crossover("mean", 1.0, mean)
+        .unwrap()
+        .with_mutation("same", 1.0, PeakGenome::clone)
+        .unwrap()
+        .with_mutation("nudge", 1.0, nudge)
+        .unwrap();
+    let monitor = operators.monitor();
+    let settings = SearchSettingsBuilder::from(&settings(1.0))
+        .memetic(Some(MemeticSettings::default()))
+        .build()
+        .unwrap();
+
+    run(operators, &settings);
+
+    let applications = |reports: Vec<OperatorReport>| {
+        reports
+            .iter()
+            .map(|report| report.statistics().applications())
+            .sum::<usize>()
+    };
+    assert_eq!(applications(monitor.crossovers()), 16 * 9);
+    assert_eq!(applications(monitor.mutations()), 16 * 9);
+}
+
+#[test]
+fn reported_children_discard_no_choices() {
+    let operators = operators(OperatorSelection::Weighted)
+        .with_mutation("nudge", 1.0, nudge)
+        .unwrap();
+    let monitor = operators.monitor();
+
+    run(operators, &settings(1.0));
+
+    assert_eq!(monitor.discarded(), 0);
+}
+
+#[test]
+fn choices_for_unreported_children_are_discarded() {
+    let operators = operators(OperatorSelection::Weighted)
+        .with_mutation("nudge", 1.0, nudge)
+        .unwrap();
+    let monitor = operators.monitor();
+    let genome = PeakGenome(0.0);
+
+    for _ in 0..5_000 {
+        operators.mutate(&genome);
+    }
+
+    assert_eq!(monitor.discarded(), 4_096);
+    assert_eq!(monitor.mutations()[0].statistics().applications(), 0);
+}
+
+#[test]
+fn wrapped_operators_are_used_when_none_are_registered() {
+    let operators = operators(OperatorSelection::Weighted)
+        .with_mutation("nudge", 1.0, nudge)
+        .unwrap();
+    let monitor = operators.monitor();
+
+    let success = run(operators, &settings(1.0));
+
+    assert_eq!(success.operators().crossover().applications(), 16 * 9);
+    assert_eq!(monitor.crossovers().is_empty(), true);
+    assert_eq!(monitor.mutations()[0].statistics().applications(), 16 * 9);
+}
+
+#[test]
+fn probability_matching_favours_the_improving_operator() {
+    let mutations = compete(OperatorSelection::ProbabilityMatching {
+        adaptation_rate: 0.3,
+        min_probability: 0.05,
+    });
+
+    assert_eq!(mutations[0].statistics().improvements(), 0);
+    assert_eq!(mutations[0].probability() >= 0.05, true);
+    assert_eq!(favoured(&mutations), true);
+}
+
+#[test]
+fn adaptive_pursuit_favours_the_improving_operator() {
+    let mutations = compete(OperatorSelection::AdaptivePursuit {
+        adaptation_rate: 0.3,
+        learning_rate: 0.3,
+        min_probability: 0.05,
+    });
+
+    assert_eq!(mutations[0].statistics().improvements(), 0);
+    assert_eq!(mutations[0].probability() >= 0.05, true);
+    assert_eq!(favoured(&mutations), true);
+}
+
+#[test]
+fn upper_confidence_bound_favours_the_improving_operator() {
+    let mutations = compete(OperatorSelection::UpperConfidenceBound { exploration: 0.1 });
+
+    assert_eq!(mutations[0].statistics().improvements(), 0);
+    assert_eq!(favoured(&mutations), true);
+}
+
+#[test]
+fn upper_confidence_bound_tries_every_operator_in_the_first_generation() {
+    let operators = operators(OperatorSelection::UpperConfidenceBound { exploration: 0.1 })
+        .with_mutation("worsen", 1.0, worsen)
+        .unwrap()
+        .with_mutation("nudge", 1.0, nudge)
+        .unwrap()
+        .with_mutation("same", 1.0, PeakGenome::clone)
+        .unwrap();
+    let monitor = operators.monitor();
+
+    run(
+        operators,
+        &SearchSettingsBuilder::from(&settings(1.0))
+            .epoch_limit(2)
+            .build()
+            .unwrap(),
+    );
+
+    for mutation in monitor.mutations() {
+        assert_eq!(mutation.statistics().applications() > 0, true);
+    }
+}
+
+#[test]
+fn weighted_selection_does_not_adapt() {
+    let mutations = compete(OperatorSelection::Weighted);
+
+    assert_eq!(mutations[0].probability(), 0.5);
+    assert_eq!(mutations[1].probability(), 0.5);
+    assert_eq!(mutations[0].statistics().applications() > 0, true);
+}
@@ -0,0 +1,37 @@
+use watchmaker::*;
+
+#[test]
+fn from_csv_splits_inputs_from_output() {
+    let csv = "x, y, z\n1, 2, 3\n4, 5, 6\n";
+
+    let dataset = Dataset::from_csv(csv.as_bytes(), "y").unwrap();
+
+    assert_eq!(dataset.input_names(), &["x", "z"]);
+    assert_eq!(dataset.output_name(), "y");
+    assert_eq!(dataset.inputs(), &[vec![1.0, 3.0], vec![4.0, 6.0]]);
+    assert_eq!(dataset.outputs(), &[2.0, 5.0]);
+}
+
+#[test]
+fn fails_when_output_column_is_missing() {
+    let result = Dataset::from_csv("x,y\n1,2\n".as_bytes(), "z");
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::dataset());
+}
+
+#[test]
+fn fails_when_value_is_not_numeric() {
+    let result = Dataset::from_csv("x,y\n1,two\n".as_bytes(), "y");
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::csv());
+}
+
+#[test]
+fn fails_when_there_are_no_rows() {
+    let result = Dataset::from_csv("x,y\n".as_bytes(), "y");
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::dataset());
+}
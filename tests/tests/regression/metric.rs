@@ -0,0 +1,30 @@
+use watchmaker::*;
+
+#[test]
+fn mean_squared_and_absolute_errors() {
+    let predicted = [1.0, 2.0, 5.0];
+    let actual = [1.0, 4.0, 4.0];
+
+    assert_eq!(
+        Metric::MeanSquaredError.cost(&predicted, &actual),
+        5.0 / 3.0
+    );
+    assert_eq!(Metric::MeanAbsoluteError.cost(&predicted, &actual), 1.0);
+}
+
+#[test]
+fn r_squared_is_one_for_perfect_fit() {
+    let actual = [1.0, 2.0, 3.0];
+
+    let cost = Metric::RSquared.cost(&actual, &actual);
+
+    assert_eq!(cost, 0.0);
+    assert_eq!(Metric::RSquared.value(cost), 1.0);
+}
+
+#[test]
+fn non_finite_predictions_have_maximum_cost() {
+    let cost = Metric::MeanSquaredError.cost(&[f64::NAN, 1.0], &[1.0, 1.0]);
+
+    assert_eq!(cost, f64::MAX);
+}
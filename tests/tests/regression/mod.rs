@@ -0,0 +1,3 @@
+mod dataset;
+mod metric;
+mod symbolic_regression;
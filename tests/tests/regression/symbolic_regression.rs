@@ -0,0 +1,105 @@
+use tests::*;
+use watchmaker::*;
+
+fn make_regression(operators: &[Operator]) -> SymbolicRegression {
+    let mut csv = String::from("x,y\n");
+    for i in -10..=10 {
+        let x = i as f64 / 5.0;
+        csv.push_str(&format!("{},{}\n", x, polynomial(x)));
+    }
+    SymbolicRegression::new(
+        Dataset::from_csv(csv.as_bytes(), "y").unwrap(),
+        operators,
+        Metric::MeanSquaredError,
+        TreeSettings::default(),
+    )
+    .unwrap()
+}
+
+fn node(operator: usize, arity: usize) -> Node<f64> {
+    Node::Function {
+        index: operator,
+        arity,
+        kind: 0,
+    }
+}
+
+fn x() -> Node<f64> {
+    Node::Terminal {
+        index: 0,
+        kind: 0,
+        value: None,
+    }
+}
+
+fn constant(value: f64) -> Node<f64> {
+    Node::Terminal {
+        index: 1,
+        kind: 0,
+        value: Some(value),
+    }
+}
+
+#[test]
+fn infix_adds_only_necessary_parentheses() {
+    let regression = make_regression(&Operator::arithmetic());
+    // (* (- x 0.5) (/ x (- x 1)))
+    let tree = Tree::new(vec![
+        node(2, 2),
+        node(1, 2),
+        x(),
+        constant(0.5),
+        node(3, 2),
+        x(),
+        node(1, 2),
+        x(),
+        constant(1.0),
+    ]);
+
+    assert_eq!(regression.infix(&tree), "(x - 0.5) * x / (x - 1)");
+}
+
+#[test]
+fn latex_uses_fractions_and_grouping() {
+    let regression = make_regression(&[Operator::Divide, Operator::Square, Operator::Add]);
+    // (square (/ x (+ x 2)))
+    let tree = Tree::new(vec![
+        node(1, 1),
+        node(0, 2),
+        x(),
+        node(2, 2),
+        x(),
+        constant(2.0),
+    ]);
+
+    assert_eq!(
+        regression.latex(&tree),
+        "\\left(\\frac{x}{x + 2}\\right)^{2}"
+    );
+}
+
+#[test]
+fn search_fits_polynomial() {
+    let regression = make_regression(&Operator::arithmetic());
+
+    let result = regression.search(
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(512)
+            .mutation_probability(0.2)
+            .cost_target(1e-9)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.is_ok(), true);
+    let success = result.unwrap();
+    assert!(regression.score(success.best_genome()) < 0.1);
+    assert_eq!(
+        regression
+            .predict(success.best_genome(), &[0.0])
+            .is_finite(),
+        true
+    );
+}
@@ -0,0 +1,154 @@
+use tests::{PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+/// The peak problem, with a fixed cost for genomes above a threshold.
+struct CostGenetic {
+    threshold: f64,
+    cost: f64,
+    offset: f64,
+}
+
+impl CostGenetic {
+    fn new(threshold: f64, cost: f64) -> Self {
+        Self {
+            threshold,
+            cost,
+            offset: 0.0,
+        }
+    }
+}
+
+impl Genetic<PeakGenome> for CostGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic::default().initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        if genome.0 > self.threshold {
+            self.cost
+        } else {
+            PeakGenetic::default().evaluate(genome) + self.offset
+        }
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().mutate(original)
+    }
+}
+
+fn settings(nan_policy: NanPolicy) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(64)
+        .epoch_limit(50)
+        .cost_target(-f64::INFINITY)
+        .nan_policy(nan_policy)
+        .build()
+        .unwrap()
+}
+
+fn run(genetic: CostGenetic, settings: &SearchSettings) -> Result<Success<PeakGenome>, Failure> {
+    search(
+        Box::new(genetic),
+        Box::new(TournamentSelector::default()),
+        None,
+        settings,
+    )
+}
+
+#[test]
+fn default_nan_policy_is_worst() {
+    assert_eq!(SearchSettings::default().nan_policy(), NanPolicy::Worst);
+}
+
+#[test]
+fn nan_costs_are_worst() {
+    let success = run(
+        CostGenetic::new(500.0, f64::NAN),
+        &settings(NanPolicy::Worst),
+    )
+    .unwrap();
+
+    assert_eq!(success.best_cost() < 100.0, true);
+    assert_eq!(success.best_genome().0 > 500.0, false);
+    assert_eq!(success.worst_cost().is_nan(), false);
+}
+
+#[test]
+fn nan_costs_are_rejected() {
+    let result = run(
+        CostGenetic::new(500.0, f64::NAN),
+        &settings(NanPolicy::Reject),
+    );
+
+    assert_eq!(result.err(), Some(Failure::nan_cost()));
+}
+
+#[test]
+fn nan_costs_are_rejected_by_step_wise_search() {
+    let result = Search::new(
+        Box::new(CostGenetic::new(f64::NEG_INFINITY, f64::NAN)),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        None,
+        &settings(NanPolicy::Reject),
+    );
+
+    assert_eq!(result.err(), Some(Failure::nan_cost()));
+}
+
+#[test]
+fn nan_costs_are_worst_when_maximising() {
+    let settings = SearchSettingsBuilder::from(&settings(NanPolicy::Worst))
+        .objective(Objective::Maximise)
+        .cost_target(f64::INFINITY)
+        .build()
+        .unwrap();
+
+    let success = run(CostGenetic::new(500.0, f64::NAN), &settings).unwrap();
+
+    assert_eq!(success.best_cost().is_nan(), false);
+    assert_eq!(success.best_cost() > 100.0, true);
+}
+
+#[test]
+fn infinite_costs_do_not_stop_search() {
+    let success = run(
+        CostGenetic::new(500.0, f64::INFINITY),
+        &settings(NanPolicy::Worst),
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::Epoch(50));
+    assert_eq!(success.best_cost() < 100.0, true);
+}
+
+#[test]
+fn only_infinite_costs_are_reported() {
+    let success = run(
+        CostGenetic::new(f64::NEG_INFINITY, f64::INFINITY),
+        &settings(NanPolicy::Worst),
+    )
+    .unwrap();
+
+    assert_eq!(success.best_cost(), f64::INFINITY);
+    assert_eq!(success.mean_cost(), f64::INFINITY);
+    assert_eq!(success.worst_cost(), f64::INFINITY);
+}
+
+#[test]
+fn negative_costs_are_reported() {
+    let genetic = CostGenetic {
+        threshold: f64::INFINITY,
+        cost: 0.0,
+        offset: -10_000.0,
+    };
+
+    let success = run(genetic, &settings(NanPolicy::Worst)).unwrap();
+
+    assert_eq!(success.best_cost() < -9_900.0, true);
+    assert_eq!(success.worst_cost() < 0.0, true);
+    assert_eq!(success.worst_cost() >= success.mean_cost(), true);
+}
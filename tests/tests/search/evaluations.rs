@@ -0,0 +1,76 @@
+use tests::{PeakGenetic, RealGenetic};
+use watchmaker::*;
+
+fn settings(population_size: usize, evaluation_limit: usize) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(population_size)
+        .evaluation_limit(evaluation_limit)
+        .cost_target(-1.0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn evaluation_limit_terminates_search() {
+    let success = search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &settings(10, 50),
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::EvaluationLimit(50));
+    assert_eq!(success.evaluations(), 50);
+    assert_eq!(success.epoch(), 5);
+}
+
+#[test]
+fn evaluation_limit_may_be_exceeded_by_one_epoch() {
+    let success = search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &settings(10, 45),
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::EvaluationLimit(50));
+}
+
+#[test]
+fn evaluations_are_counted_for_every_strategy() {
+    let success = differential_evolution(
+        Box::new(RealGenetic::sphere(2)),
+        &DifferentialSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(20)
+            .epoch_limit(7)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::Epoch(7));
+    assert_eq!(success.evaluations(), 140);
+}
+
+#[test]
+fn progress_reports_evaluations() {
+    let evaluations = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let recorded = evaluations.clone();
+
+    search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(move |snapshot| {
+            recorded.borrow_mut().push(snapshot.evaluations())
+        })),
+        &settings(4, 12),
+    )
+    .unwrap();
+
+    assert_eq!(*evaluations.borrow(), vec![4, 8, 12]);
+}
@@ -0,0 +1,111 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tests::{PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+fn settings(size: usize, min_distance: f64) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(64)
+        .epoch_limit(50)
+        .cost_target(-1.0)
+        .hall_of_fame(Some(
+            HallOfFameSettingsBuilder::default()
+                .size(size)
+                .min_distance(min_distance)
+                .build()
+                .unwrap(),
+        ))
+        .build()
+        .unwrap()
+}
+
+fn run(settings: &SearchSettings) -> Success<PeakGenome> {
+    search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        settings,
+    )
+    .unwrap()
+}
+
+#[test]
+fn hall_of_fame_is_empty_by_default() {
+    let success = run(&SearchSettingsBuilder::default()
+        .population_size(64)
+        .epoch_limit(10)
+        .cost_target(-1.0)
+        .build()
+        .unwrap());
+
+    assert_eq!(success.hall_of_fame().is_empty(), true);
+}
+
+#[test]
+fn hall_of_fame_keeps_best_distinct_genomes_best_first() {
+    let success = run(&settings(5, 0.0));
+    let hall_of_fame = success.hall_of_fame();
+
+    assert_eq!(hall_of_fame.len(), 5);
+    assert_eq!(hall_of_fame[0].genome(), success.best_genome());
+    assert_eq!(hall_of_fame[0].cost(), success.best_cost());
+    for pair in hall_of_fame.windows(2) {
+        assert_eq!(pair[0].cost() <= pair[1].cost(), true);
+        assert_eq!(pair[0].genome() != pair[1].genome(), true);
+    }
+    for entry in hall_of_fame {
+        assert_eq!(entry.epoch() <= success.epoch(), true);
+    }
+}
+
+#[test]
+fn hall_of_fame_uses_distance_for_distinct_genomes() {
+    let success = run(&settings(4, 10.0));
+    let hall_of_fame = success.hall_of_fame();
+
+    assert_eq!(hall_of_fame.is_empty(), false);
+    assert_eq!(hall_of_fame.len() <= 4, true);
+    for (i, lhs) in hall_of_fame.iter().enumerate() {
+        for rhs in &hall_of_fame[i + 1..] {
+            assert_eq!((lhs.genome().0 - rhs.genome().0).abs() >= 10.0, true);
+        }
+    }
+}
+
+#[test]
+fn hall_of_fame_is_readable_from_progress() {
+    let sizes = Rc::new(RefCell::new(Vec::new()));
+    let recorded = sizes.clone();
+
+    search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(move |snapshot| {
+            assert_eq!(snapshot.hall_of_fame()[0].cost(), snapshot.best_cost());
+            recorded
+                .borrow_mut()
+                .push(snapshot.to_owned_snapshot().hall_of_fame().len())
+        })),
+        &settings(3, 0.0),
+    )
+    .unwrap();
+
+    assert_eq!(sizes.borrow().iter().all(|size| *size == 3), true);
+}
+
+#[test]
+fn hall_of_fame_reports_scores_when_maximising() {
+    let settings = SearchSettingsBuilder::from(&settings(3, 0.0))
+        .objective(Objective::Maximise)
+        .cost_target(f64::INFINITY)
+        .build()
+        .unwrap();
+
+    let success = run(&settings);
+    let hall_of_fame = success.hall_of_fame();
+
+    assert_eq!(hall_of_fame[0].cost(), success.best_cost());
+    for pair in hall_of_fame.windows(2) {
+        assert_eq!(pair[0].cost() >= pair[1].cost(), true);
+    }
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+use tests::{TspGenetic, TspGenome};
+use watchmaker::*;
+
+/// A tour of the Berlin 52 problem that 2-opt cannot shorten is typically within 10% of the
+/// best known tour of 7542, while a random tour is several times longer.
+const LOCAL_OPTIMUM: f64 = 10_000.0;
+
+fn search_tsp(memetic: MemeticSettings) -> Success<TspGenome> {
+    search(
+        Box::new(TspGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(16)
+            .epoch_limit(5)
+            .memetic(Some(memetic))
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn lamarckian_search_keeps_improved_genomes() {
+    let success = search_tsp(MemeticSettings::default());
+
+    assert_eq!(success.best_cost() < LOCAL_OPTIMUM, true);
+    assert_eq!(
+        TspGenetic::default().evaluate(success.best_genome()),
+        success.best_cost()
+    );
+    assert_eq!(success.local_search_time() > Duration::ZERO, true);
+}
+
+#[test]
+fn baldwinian_search_reports_the_cost_of_the_best_genome() {
+    let success = search_tsp(
+        MemeticSettingsBuilder::default()
+            .inheritance(Inheritance::Baldwinian)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(
+        TspGenetic::default().evaluate(success.best_genome()),
+        success.best_cost()
+    );
+    assert_eq!(success.local_search_time() > Duration::ZERO, true);
+}
+
+#[test]
+fn baldwinian_search_reports_the_cost_of_each_genome() {
+    let genetic = TspGenetic::default();
+    let success = search(
+        Box::new(TspGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(16)
+            .epoch_limit(5)
+            .memetic(Some(
+                MemeticSettingsBuilder::default()
+                    .inheritance(Inheritance::Baldwinian)
+                    .build()
+                    .unwrap(),
+            ))
+            .hall_of_fame(Some(HallOfFameSettings::default()))
+            .keep_population(true)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.hall_of_fame().is_empty(), false);
+    for entry in success.hall_of_fame() {
+        assert_eq!(genetic.evaluate(entry.genome()), entry.cost());
+    }
+    for (genome, cost) in success.population() {
+        assert_eq!(genetic.evaluate(genome), *cost);
+    }
+    let costs: Vec<f64> = success.population().iter().map(|(_, cost)| *cost).collect();
+    let mean = costs.iter().sum::<f64>() / costs.len() as f64;
+    assert_eq!((success.mean_cost() - mean).abs() < 1e-6, true);
+    assert_eq!(
+        success.worst_cost(),
+        costs.iter().copied().fold(f64::MIN, f64::max)
+    );
+}
+
+#[test]
+fn local_search_does_not_run_with_zero_probability() {
+    let success = search_tsp(
+        MemeticSettingsBuilder::default()
+            .probability(0.0)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(success.local_search_time(), Duration::ZERO);
+    assert_eq!(success.best_cost() > LOCAL_OPTIMUM, true);
+}
+
+#[test]
+fn local_search_does_not_run_without_memetic_settings() {
+    let success = search(
+        Box::new(TspGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(16)
+            .epoch_limit(5)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.local_search_time(), Duration::ZERO);
+}
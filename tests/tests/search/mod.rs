@@ -1 +1,12 @@
+mod costs;
+mod evaluations;
+mod hall_of_fame;
+mod memetic;
+mod objective;
+mod observer;
+mod operators;
+mod population;
+mod progress_channel;
 mod search;
+mod spawn;
+mod stepwise;
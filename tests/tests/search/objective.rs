@@ -0,0 +1,152 @@
+use rand::Rng;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tests::{assert_between, PeakGenome};
+use watchmaker::*;
+
+/// A score to maximise, which peaks at 200 when the genome is 100.
+struct ScoreGenetic {}
+
+impl Genetic<PeakGenome> for ScoreGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenome(rand::thread_rng().gen_range(0.0..200.0))
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        200.0 - (100.0 - genome.0).abs()
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenome((lhs.0 + rhs.0) / 2.0)
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        PeakGenome(original.0 + rand::thread_rng().gen_range(-10.0..10.0))
+    }
+}
+
+fn maximise(cost_target: f64, epoch_limit: usize) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .objective(Objective::Maximise)
+        .population_size(64)
+        .cost_target(cost_target)
+        .epoch_limit(epoch_limit)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn default_objective_is_minimise() {
+    assert_eq!(SearchSettings::default().objective(), Objective::Minimise);
+}
+
+#[test]
+fn is_better_respects_objective() {
+    assert_eq!(Objective::Minimise.is_better(1.0, 2.0), true);
+    assert_eq!(Objective::Minimise.is_better(2.0, 1.0), false);
+    assert_eq!(Objective::Maximise.is_better(2.0, 1.0), true);
+    assert_eq!(Objective::Maximise.is_better(1.0, 1.0), false);
+}
+
+#[test]
+fn maximising_search_reaches_cost_target() {
+    let success = search(
+        Box::new(ScoreGenetic {}),
+        Box::new(TournamentSelector::default()),
+        None,
+        &maximise(199.9, 1_024),
+    )
+    .unwrap();
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+    assert_eq!(success.best_cost() >= 199.9, true);
+    assert_between!(success.best_genome().0, 99.0, 101.0);
+    assert_eq!(success.worst_cost() <= success.mean_cost(), true);
+    assert_eq!(success.mean_cost() <= success.best_cost(), true);
+}
+
+#[test]
+fn maximising_progress_reports_scores() {
+    let scores = Rc::new(RefCell::new(Vec::new()));
+    let recorded = scores.clone();
+
+    search(
+        Box::new(ScoreGenetic {}),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(move |snapshot| {
+            recorded.borrow_mut().push(snapshot.best_cost())
+        })),
+        &maximise(f64::INFINITY, 20),
+    )
+    .unwrap();
+
+    let scores = scores.borrow();
+    assert_eq!(scores.len(), 20);
+    for pair in scores.windows(2) {
+        assert_eq!(pair[0] <= pair[1], true);
+    }
+    assert_between!(scores[0], 100.0, 200.0);
+}
+
+#[test]
+fn maximising_step_wise_search_reports_scores() {
+    let search = Search::new(
+        Box::new(ScoreGenetic {}),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        None,
+        &maximise(f64::INFINITY, 20),
+    )
+    .unwrap();
+
+    for cost in search.costs() {
+        assert_between!(cost, 100.0, search.best_cost());
+    }
+}
+
+#[test]
+fn maximising_differential_evolution() {
+    let success = differential_evolution(
+        Box::new(NegatedSphere {}),
+        &DifferentialSettings::default(),
+        None,
+        &SearchSettingsBuilder::default()
+            .objective(Objective::Maximise)
+            .population_size(30)
+            .epoch_limit(2_000)
+            .cost_target(-1e-6)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+    assert_between!(success.best_cost(), -1e-6, 0.0);
+}
+
+/// The negated sphere function, which is maximised at the origin.
+struct NegatedSphere {}
+
+impl Genetic<Vec<f64>> for NegatedSphere {
+    fn initialize(&self) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..3).map(|_| rng.gen_range(-5.0..5.0)).collect()
+    }
+
+    fn evaluate(&self, genome: &Vec<f64>) -> f64 {
+        -genome.iter().map(|x| x * x).sum::<f64>()
+    }
+
+    fn crossover(&self, lhs: &Vec<f64>, _rhs: &Vec<f64>) -> Vec<f64> {
+        lhs.clone()
+    }
+
+    fn mutate(&self, genome: &Vec<f64>) -> Vec<f64> {
+        genome.clone()
+    }
+}
@@ -0,0 +1,161 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tests::{PeakGenetic, PeakGenome, RealGenetic};
+use watchmaker::*;
+
+/// Record the name of every hook called, with its epoch where there is one.
+#[derive(Clone, Default)]
+struct Recorder {
+    events: Rc<RefCell<Vec<String>>>,
+}
+
+impl Recorder {
+    fn record(&self, event: String) {
+        self.events.borrow_mut().push(event);
+    }
+
+    fn events(&self) -> Vec<String> {
+        self.events.borrow().clone()
+    }
+}
+
+impl<G> Observer<G> for Recorder {
+    fn search_started(&mut self, _settings: &SearchSettings) {
+        self.record("started".to_string());
+    }
+
+    fn population_initialized(&mut self, population: &[G]) {
+        self.record(format!("initialized {}", population.len()));
+    }
+
+    fn evaluated(&mut self, epoch: usize, _population: &[G], costs: &[f64], _violations: &[f64]) {
+        self.record(format!("evaluated {} {}", epoch, costs.len()));
+    }
+
+    fn selected(&mut self, epoch: usize, partner_indices: &[usize]) {
+        self.record(format!("selected {} {}", epoch, partner_indices.len()));
+    }
+
+    fn child_created(
+        &mut self,
+        epoch: usize,
+        _child: &G,
+        _parents: (usize, usize),
+        _mutated: bool,
+    ) {
+        self.record(format!("child {}", epoch));
+    }
+
+    fn epoch_ended(&mut self, snapshot: &ProgressSnapshot<G>) {
+        self.record(format!("ended {}", snapshot.epoch()));
+    }
+
+    fn search_ended(&mut self, success: &Success<G>) {
+        self.record(format!("finished {}", success.epoch()));
+    }
+}
+
+fn settings(population_size: usize, epoch_limit: usize) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(population_size)
+        .epoch_limit(epoch_limit)
+        .cost_target(-1.0)
+        .build()
+        .unwrap()
+}
+
+fn generational() -> Box<Generational<PeakGenome>> {
+    Box::new(Generational::new(Box::new(TournamentSelector::default())))
+}
+
+#[test]
+fn observer_sees_every_phase_in_order() {
+    let recorder = Recorder::default();
+
+    search_observed(
+        Box::new(PeakGenetic::default()),
+        generational(),
+        Observers::new().with(Box::new(recorder.clone())),
+        &settings(2, 2),
+    )
+    .unwrap();
+
+    assert_eq!(
+        recorder.events(),
+        vec![
+            "started",
+            "initialized 2",
+            "evaluated 1 2",
+            "ended 1",
+            "selected 2 2",
+            "child 2",
+            "child 2",
+            "evaluated 2 2",
+            "ended 2",
+            "finished 2",
+        ]
+    );
+}
+
+#[test]
+fn several_observers_are_each_notified() {
+    let first = Recorder::default();
+    let second = Recorder::default();
+    let observers = Observers::new()
+        .with(Box::new(first.clone()))
+        .with(Box::new(second.clone()));
+    assert_eq!(observers.len(), 2);
+
+    search_observed(
+        Box::new(PeakGenetic::default()),
+        generational(),
+        observers,
+        &settings(4, 5),
+    )
+    .unwrap();
+
+    assert_eq!(first.events().is_empty(), false);
+    assert_eq!(first.events(), second.events());
+}
+
+#[test]
+fn step_wise_search_notifies_observers() {
+    let recorder = Recorder::default();
+    let mut search = Search::with_observers(
+        Box::new(PeakGenetic::default()),
+        generational(),
+        Observers::new().with(Box::new(recorder.clone())),
+        &settings(2, 100),
+    )
+    .unwrap();
+    search.step().unwrap();
+
+    search.finish();
+
+    assert_eq!(recorder.events().last().unwrap(), "finished 2");
+}
+
+#[test]
+fn evolution_strategy_reports_children() {
+    let recorder = Recorder::default();
+
+    search_observed(
+        Box::new(RealGenetic::sphere(2)),
+        Box::new(EvolutionStrategy::new(
+            EvolutionSettingsBuilder::default()
+                .offspring(6)
+                .build()
+                .unwrap(),
+        )),
+        Observers::new().with(Box::new(recorder.clone())),
+        &settings(3, 2),
+    )
+    .unwrap();
+
+    let children = recorder
+        .events()
+        .iter()
+        .filter(|event| *event == "child 2")
+        .count();
+    assert_eq!(children, 6);
+}
@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tests::{PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+fn settings(mutation_probability: f64, objective: Objective) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(16)
+        .epoch_limit(10)
+        .cost_target(match objective {
+            Objective::Minimise => f64::NEG_INFINITY,
+            Objective::Maximise => f64::INFINITY,
+        })
+        .mutation_probability(mutation_probability)
+        .objective(objective)
+        .build()
+        .unwrap()
+}
+
+fn run(
+    genetic: Box<dyn Genetic<PeakGenome> + Send + Sync>,
+    observers: Observers<PeakGenome>,
+    settings: &SearchSettings,
+) -> Success<PeakGenome> {
+    search_observed(
+        genetic,
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        observers,
+        settings,
+    )
+    .unwrap()
+}
+
+/// Record the outcome of every child evaluated.
+#[derive(Clone, Default)]
+struct Outcomes {
+    outcomes: Rc<RefCell<Vec<ChildOutcome>>>,
+}
+
+impl Observer<PeakGenome> for Outcomes {
+    fn child_evaluated(&mut self, _epoch: usize, _child: &PeakGenome, outcome: &ChildOutcome) {
+        self.outcomes.borrow_mut().push(*outcome);
+    }
+}
+
+/// The peak problem, with a crossover that copies the first parent.
+struct CopyingGenetic {}
+
+impl Genetic<PeakGenome> for CopyingGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic::default().initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PeakGenetic::default().evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, _rhs: &PeakGenome) -> PeakGenome {
+        lhs.clone()
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().mutate(original)
+    }
+}
+
+/// The peak problem, with a mutation that creates a genome with an infinite cost.
+struct InfiniteMutationGenetic {}
+
+impl Genetic<PeakGenome> for InfiniteMutationGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic::default().initialize()
+    }
+
+    fn evaluate(&self, genome: &PeakGenome) -> f64 {
+        PeakGenetic::default().evaluate(genome)
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenetic::default().crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, _original: &PeakGenome) -> PeakGenome {
+        PeakGenome(f64::INFINITY)
+    }
+}
+
+#[test]
+fn every_child_is_created_by_crossover() {
+    let success = run(
+        Box::new(PeakGenetic::default()),
+        Observers::new(),
+        &settings(0.0, Objective::Minimise),
+    );
+
+    assert_eq!(success.operators().crossover().applications(), 16 * 9);
+    assert_eq!(success.operators().mutation().applications(), 0);
+    assert_eq!(success.operators().mutation().mean_delta(), 0.0);
+}
+
+#[test]
+fn mutated_children_count_for_both_operators() {
+    let success = run(
+        Box::new(PeakGenetic::default()),
+        Observers::new(),
+        &settings(1.0, Objective::Minimise),
+    );
+
+    assert_eq!(
+        success.operators().crossover(),
+        success.operators().mutation()
+    );
+}
+
+#[test]
+fn crossover_that_copies_a_parent_never_improves() {
+    let success = run(
+        Box::new(CopyingGenetic {}),
+        Observers::new(),
+        &settings(0.0, Objective::Minimise),
+    );
+
+    let crossover = success.operators().crossover();
+    assert_eq!(crossover.applications(), 16 * 9);
+    assert_eq!(crossover.improvements(), 0);
+    assert_eq!(crossover.mean_delta() >= 0.0, true);
+}
+
+#[test]
+fn infinite_child_costs_are_left_out_of_mean_delta() {
+    let success = run(
+        Box::new(InfiniteMutationGenetic {}),
+        Observers::new(),
+        &settings(0.5, Objective::Minimise),
+    );
+
+    let operators = success.operators();
+    assert_eq!(operators.mutation().applications() > 0, true);
+    assert_eq!(operators.mutation().mean_delta(), 0.0);
+    assert_eq!(operators.crossover().mean_delta().is_finite(), true);
+}
+
+#[test]
+fn snapshots_report_operators_of_each_epoch() {
+    let applications = Rc::new(RefCell::new(Vec::new()));
+    let recorded = applications.clone();
+
+    search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(move |snapshot| {
+            recorded
+                .borrow_mut()
+                .push(snapshot.operators().crossover().applications())
+        })),
+        &settings(0.5, Objective::Minimise),
+    )
+    .unwrap();
+
+    let applications = applications.borrow();
+    assert_eq!(applications[0], 0);
+    assert_eq!(applications[1..].iter().all(|a| *a == 16), true);
+}
+
+#[test]
+fn statistics_match_child_outcomes() {
+    for objective in [Objective::Minimise, Objective::Maximise] {
+        let outcomes = Outcomes::default();
+
+        let success = run(
+            Box::new(PeakGenetic::default()),
+            Observers::new().with(Box::new(outcomes.clone())),
+            &settings(0.5, objective),
+        );
+
+        let outcomes = outcomes.outcomes.borrow();
+        let mutation = success.operators().mutation();
+        let mutated: Vec<&ChildOutcome> = outcomes.iter().filter(|o| o.mutated()).collect();
+        let improvements = mutated
+            .iter()
+            .filter(|o| objective.is_better(o.cost(), o.parent_cost()))
+            .count();
+        let total_delta: f64 = mutated.iter().map(|o| o.cost() - o.parent_cost()).sum();
+        assert_eq!(outcomes.len(), 16 * 9);
+        assert_eq!(mutation.applications(), mutated.len());
+        assert_eq!(mutation.improvements(), improvements);
+        assert_eq!(
+            (mutation.mean_delta() - total_delta / mutated.len() as f64).abs() < 1e-9,
+            true
+        );
+    }
+}
+
+#[test]
+fn evolution_strategy_without_recombination_only_mutates() {
+    let success = evolution_strategy(
+        Box::new(PeakGenetic::default()),
+        &EvolutionSettingsBuilder::default()
+            .offspring(8)
+            .recombination_probability(0.0)
+            .build()
+            .unwrap(),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(4)
+            .epoch_limit(5)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(success.operators().crossover().applications(), 0);
+    assert_eq!(success.operators().mutation().applications(), 8 * 4);
+}
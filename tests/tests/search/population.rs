@@ -0,0 +1,98 @@
+use tests::{PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+fn settings(keep_population: bool) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(32)
+        .epoch_limit(20)
+        .cost_target(-1.0)
+        .keep_population(keep_population)
+        .build()
+        .unwrap()
+}
+
+fn run(
+    genetic: Box<dyn Genetic<PeakGenome> + Send + Sync>,
+    settings: &SearchSettings,
+) -> Success<PeakGenome> {
+    search(
+        genetic,
+        Box::new(TournamentSelector::default()),
+        None,
+        settings,
+    )
+    .unwrap()
+}
+
+#[test]
+fn population_is_not_kept_by_default() {
+    let success = run(Box::new(PeakGenetic::default()), &settings(false));
+
+    assert_eq!(success.population().is_empty(), true);
+}
+
+#[test]
+fn final_population_is_kept_best_first() {
+    let success = run(Box::new(PeakGenetic::default()), &settings(true));
+    let population = success.population();
+
+    assert_eq!(population.len(), 32);
+    assert_eq!(population[0].1 >= success.best_cost(), true);
+    assert_eq!(population[31].1, success.worst_cost());
+    for pair in population.windows(2) {
+        assert_eq!(pair[0].1 <= pair[1].1, true);
+    }
+    for (genome, cost) in population {
+        assert_eq!(PeakGenetic::default().evaluate(genome), *cost);
+    }
+}
+
+#[test]
+fn final_population_is_oriented_when_maximising() {
+    let settings = SearchSettingsBuilder::from(&settings(true))
+        .objective(Objective::Maximise)
+        .cost_target(f64::INFINITY)
+        .build()
+        .unwrap();
+
+    let success = run(Box::new(PeakGenetic::default()), &settings);
+    let population = success.population();
+
+    assert_eq!(population[31].1, success.worst_cost());
+    for pair in population.windows(2) {
+        assert_eq!(pair[0].1 >= pair[1].1, true);
+    }
+}
+
+#[test]
+fn seeded_genetic_initializes_seeds_first() {
+    let genetic = SeededGenetic::new(
+        Box::new(PeakGenetic::default()),
+        vec![PeakGenome(1.0), PeakGenome(2.0)],
+    );
+
+    assert_eq!(genetic.initialize(), PeakGenome(1.0));
+    assert_eq!(genetic.initialize(), PeakGenome(2.0));
+    assert_eq!(genetic.initialize() == PeakGenome(1.0), false);
+}
+
+#[test]
+fn search_continues_from_final_population() {
+    let first = run(Box::new(PeakGenetic::default()), &settings(true));
+    let best_final_cost = first.population()[0].1;
+    let seeds = first
+        .into_population()
+        .into_iter()
+        .map(|(genome, _)| genome)
+        .collect();
+
+    let second = run(
+        Box::new(SeededGenetic::new(Box::new(PeakGenetic::default()), seeds)),
+        &SearchSettingsBuilder::from(&settings(true))
+            .epoch_limit(1)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(second.best_cost() <= best_final_cost, true);
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+use tests::{PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+fn settings(epoch_limit: usize) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(16)
+        .epoch_limit(epoch_limit)
+        .cost_target(-1.0)
+        .build()
+        .unwrap()
+}
+
+fn search_peak(progress: ProgressSender<PeakGenome>, epoch_limit: usize) -> Success<PeakGenome> {
+    search(
+        Box::new(PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        Some(progress.into_progress()),
+        &settings(epoch_limit),
+    )
+    .unwrap()
+}
+
+#[test]
+fn owned_snapshot_copies_snapshot() {
+    let genome = PeakGenome(1.0);
+    let snapshot = ProgressSnapshot::new(3, Duration::from_secs(2), 0.5, &genome);
+
+    let owned = snapshot.to_owned_snapshot();
+
+    assert_eq!(owned.epoch(), 3);
+    assert_eq!(owned.elapsed(), Duration::from_secs(2));
+    assert_eq!(owned.best_cost(), 0.5);
+    assert_eq!(owned.best_genome(), &genome);
+    assert_eq!(OwnedProgressSnapshot::from(snapshot), owned);
+}
+
+#[test]
+fn channel_sends_every_n_epochs() {
+    let (sender, receiver) = progress_channel(ProgressRate::Epochs(10));
+
+    search_peak(sender, 50);
+
+    let epochs: Vec<usize> = receiver.map(|snapshot| snapshot.epoch()).collect();
+    assert_eq!(epochs, vec![10, 20, 30, 40, 50]);
+}
+
+#[test]
+fn channel_sends_first_epoch_within_interval() {
+    let (sender, receiver) = progress_channel(ProgressRate::Interval(Duration::from_secs(3_600)));
+
+    search_peak(sender, 50);
+
+    let epochs: Vec<usize> = receiver.map(|snapshot| snapshot.epoch()).collect();
+    assert_eq!(epochs, vec![1]);
+}
+
+#[test]
+fn latest_returns_most_recent_snapshot() {
+    let (sender, receiver) = progress_channel(ProgressRate::default());
+
+    let success = search_peak(sender, 20);
+
+    let latest = receiver.latest().unwrap();
+    assert_eq!(latest.epoch(), 20);
+    assert_eq!(latest.best_cost(), success.best_cost());
+    assert_eq!(receiver.try_next(), None);
+}
+
+#[test]
+fn channel_delivers_progress_from_spawned_search() {
+    let (sender, receiver) = progress_channel(ProgressRate::default());
+
+    let handle = spawn(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        Some(sender),
+        &settings(30),
+    );
+
+    let snapshots: Vec<OwnedProgressSnapshot<PeakGenome>> = receiver.collect();
+    let success = handle.join().unwrap();
+    assert_eq!(snapshots.len(), 30);
+    assert_eq!(snapshots.last().unwrap().best_cost(), success.best_cost());
+}
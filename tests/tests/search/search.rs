@@ -1,6 +1,7 @@
 use rand::Rng;
 use tests::assert_between;
 use tests::round;
+use tests::{ConstrainedPeakGenetic, LIMIT};
 use watchmaker::*;
 
 #[test]
@@ -52,3 +53,199 @@ impl Genetic<PeakGenome> for PeakGenetic {
         PeakGenome(original.0 + rand::thread_rng().gen_range(-10.0..10.0))
     }
 }
+
+#[test]
+fn search_with_feasibility_rules_finds_feasible_result() {
+    let result = search(
+        Box::new(ConstrainedPeakGenetic::default()),
+        Box::new(
+            ConstraintSelector::new(
+                Box::new(TournamentSelector::default()),
+                Constraint::Feasibility,
+            )
+            .unwrap(),
+        ),
+        None,
+        &SearchSettingsBuilder::default()
+            .epoch_limit(64)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.is_ok(), true);
+
+    let success = result.as_ref().ok().unwrap();
+    assert_eq!(success.feasible(), true);
+    assert_eq!(success.best_violation(), 0.0);
+    assert_between!(success.best_genome().0, LIMIT - 1.0, LIMIT);
+}
+
+#[test]
+fn search_applies_repair_to_new_genomes() {
+    let result = search(
+        Box::new(ConstrainedPeakGenetic { repair: true }),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .epoch_limit(8)
+            .build()
+            .unwrap(),
+    );
+
+    let success = result.as_ref().ok().unwrap();
+    assert_eq!(success.feasible(), true);
+    assert_between!(success.best_genome().0, LIMIT - 1.0, LIMIT);
+}
+
+#[test]
+fn search_reports_diversity_when_genetic_has_distance() {
+    let diversities = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let recorded = diversities.clone();
+    let result = search(
+        Box::new(tests::PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(move |snapshot| {
+            recorded.borrow_mut().push(snapshot.diversity())
+        })),
+        &SearchSettingsBuilder::default()
+            .epoch_limit(10)
+            .cost_target(0.0)
+            .build()
+            .unwrap(),
+    );
+
+    let success = result.ok().unwrap();
+    let diversities = diversities.borrow();
+    assert_eq!(diversities.len(), 10);
+    assert_eq!(diversities.iter().all(|d| d.is_some()), true);
+    let first = diversities[0].unwrap();
+    assert_eq!(first.mean_distance() > 0.0, true);
+    assert_eq!(first.unique() > 1, true);
+    assert_eq!(success.diversity(), *diversities.last().unwrap());
+}
+
+#[test]
+fn search_omits_diversity_without_distance() {
+    let result = search(
+        Box::new(PeakGenetic::new()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .epoch_limit(2)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.ok().unwrap().diversity(), None);
+}
+
+#[test]
+fn search_restarts_when_stagnant_and_keeps_epoch_limit() {
+    let result = search(
+        Box::new(tests::PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(16)
+            .cost_target(-1.0)
+            .epoch_limit(200)
+            .restart(Some(
+                RestartSettingsBuilder::default()
+                    .stagnation(3)
+                    .max_population_size(64)
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap(),
+    );
+
+    let success = result.ok().unwrap();
+    assert_eq!(success.reason(), Reason::Epoch(200));
+    assert_eq!(success.restarts() > 0, true);
+    assert_between!(success.best_cost(), 0.0, 1.0);
+}
+
+/// A problem where every genome has the same cost, so the search never improves.
+struct FlatGenetic {}
+
+impl Genetic<PeakGenome> for FlatGenetic {
+    fn initialize(&self) -> PeakGenome {
+        PeakGenetic::new().initialize()
+    }
+
+    fn evaluate(&self, _genome: &PeakGenome) -> f64 {
+        1.0
+    }
+
+    fn crossover(&self, lhs: &PeakGenome, rhs: &PeakGenome) -> PeakGenome {
+        PeakGenetic::new().crossover(lhs, rhs)
+    }
+
+    fn mutate(&self, original: &PeakGenome) -> PeakGenome {
+        PeakGenetic::new().mutate(original)
+    }
+}
+
+#[test]
+fn search_counts_stagnation_from_the_last_restart() {
+    let success = search(
+        Box::new(FlatGenetic {}),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(16)
+            .epoch_limit(30)
+            .keep_population(true)
+            .restart(Some(
+                RestartSettingsBuilder::default()
+                    .stagnation(5)
+                    .population_growth(2.0)
+                    .max_population_size(65_536)
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    // The first restart follows 5 epochs without improvement after the first epoch, and each
+    // later restart follows 5 more, creating epochs 7, 12, 17, 22 and 27.
+    assert_eq!(success.restarts(), 5);
+    assert_eq!(success.population().len(), 16 * 32);
+}
+
+#[test]
+fn search_restarts_when_diversity_collapses() {
+    let sizes = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let recorded = sizes.clone();
+    let result = search(
+        Box::new(tests::PeakGenetic::default()),
+        Box::new(TournamentSelector::default()),
+        Some(Box::new(move |snapshot| {
+            recorded
+                .borrow_mut()
+                .push(snapshot.diversity().unwrap().unique())
+        })),
+        &SearchSettingsBuilder::default()
+            .population_size(4)
+            .cost_target(-1.0)
+            .epoch_limit(5)
+            .restart(Some(
+                RestartSettingsBuilder::default()
+                    .min_diversity(f64::MAX)
+                    .max_population_size(32)
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap(),
+    );
+
+    // Every epoch restarts, doubling the population up to the maximum size.
+    assert_eq!(result.ok().unwrap().restarts(), 4);
+    let sizes = sizes.borrow();
+    assert_eq!(sizes.len(), 5);
+    assert_eq!(sizes[4] > 16, true);
+}
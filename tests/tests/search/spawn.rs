@@ -0,0 +1,142 @@
+use std::thread::sleep;
+use std::time::Duration;
+use tests::{assert_between, PeakGenetic, PeakGenome, RealGenetic};
+use watchmaker::*;
+
+fn spawn_peak(settings: &SearchSettings) -> SearchHandle<PeakGenome> {
+    spawn(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        None,
+        settings,
+    )
+}
+
+fn endless() -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(16)
+        .epoch_limit(usize::MAX)
+        .cost_target(-1.0)
+        .time_limit(Duration::from_secs(60))
+        .build()
+        .unwrap()
+}
+
+fn wait_for_epoch(handle: &SearchHandle<PeakGenome>, epoch: usize) {
+    while handle.epoch() < epoch {
+        sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn spawned_search_joins_with_result() {
+    let handle = spawn_peak(&SearchSettings::default());
+
+    let success = handle.join().unwrap();
+
+    assert_between!(success.best_cost(), 0.0, 0.1);
+}
+
+#[test]
+fn handle_reports_best_genome() {
+    let handle = spawn_peak(&endless());
+    wait_for_epoch(&handle, 1);
+
+    let best_cost = handle.best_cost().unwrap();
+    assert_eq!(handle.best_genome().is_some(), true);
+
+    handle.cancel();
+    let success = handle.join().unwrap();
+    assert_eq!(success.best_cost() <= best_cost, true);
+}
+
+#[test]
+fn cancel_stops_search() {
+    let handle = spawn_peak(&endless());
+    wait_for_epoch(&handle, 2);
+
+    handle.cancel();
+    let success = handle.join().unwrap();
+
+    assert_eq!(success.reason(), Reason::StopRequested);
+}
+
+#[test]
+fn pause_and_resume_search() {
+    let handle = spawn_peak(&endless());
+    wait_for_epoch(&handle, 2);
+
+    handle.pause();
+    sleep(Duration::from_millis(50));
+    let paused = handle.epoch();
+    sleep(Duration::from_millis(50));
+    assert_eq!(handle.epoch(), paused);
+    assert_eq!(handle.is_finished(), false);
+
+    handle.resume();
+    wait_for_epoch(&handle, paused + 2);
+    handle.cancel();
+    assert_eq!(handle.join().unwrap().reason(), Reason::StopRequested);
+}
+
+#[test]
+fn cancel_paused_search() {
+    let handle = spawn_peak(&endless());
+    wait_for_epoch(&handle, 1);
+
+    handle.pause();
+    handle.cancel();
+
+    assert_eq!(handle.join().unwrap().reason(), Reason::StopRequested);
+}
+
+#[test]
+fn join_resumes_paused_search() {
+    let handle = spawn_peak(
+        &SearchSettingsBuilder::default()
+            .population_size(16)
+            .epoch_limit(10)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    );
+    wait_for_epoch(&handle, 1);
+
+    handle.pause();
+
+    assert_eq!(handle.join().unwrap().reason(), Reason::Epoch(10));
+}
+
+#[test]
+fn dropping_handle_cancels_search() {
+    let (sender, receiver) = progress_channel(ProgressRate::default());
+    let handle = spawn(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        Some(sender),
+        &endless(),
+    );
+    wait_for_epoch(&handle, 1);
+    let start = std::time::Instant::now();
+
+    drop(handle);
+
+    // The receiver ends when the search thread finishes and drops the sender.
+    receiver.count();
+    assert_eq!(start.elapsed() < Duration::from_secs(30), true);
+}
+
+#[test]
+fn spawned_search_returns_strategy_failure() {
+    let handle = spawn(
+        Box::new(RealGenetic::sphere(3)),
+        Box::new(CmaEs::new(CmaSettings::default())),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(1)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(handle.join().err(), Some(Failure::cma_setting()));
+}
@@ -0,0 +1,147 @@
+use tests::{assert_between, PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+fn make_search(settings: &SearchSettings) -> Search<PeakGenome> {
+    Search::new(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        None,
+        settings,
+    )
+    .unwrap()
+}
+
+#[test]
+fn new_search_evaluates_initial_population() {
+    let search = make_search(&SearchSettings::default());
+
+    assert_eq!(search.epoch(), 1);
+    assert_eq!(
+        search.population().len(),
+        SearchSettings::default().population_size()
+    );
+    assert_eq!(search.costs().len(), search.population().len());
+    assert_eq!(search.violations().len(), search.population().len());
+    assert_eq!(
+        search.costs().iter().all(|c| search.best_cost() <= *c),
+        true
+    );
+}
+
+#[test]
+fn step_advances_one_epoch() {
+    let mut search = make_search(&SearchSettings::default());
+
+    for epoch in 2..10 {
+        search.step().unwrap();
+        assert_eq!(search.epoch(), epoch);
+        assert_eq!(search.snapshot().epoch(), epoch);
+    }
+}
+
+#[test]
+fn step_returns_reason_at_epoch_limit() {
+    let mut search = make_search(
+        &SearchSettingsBuilder::default()
+            .epoch_limit(5)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    );
+
+    let reasons: Vec<Option<Reason>> = (0..4).map(|_| search.step().unwrap()).collect();
+
+    assert_eq!(reasons, vec![None, None, None, Some(Reason::Epoch(5))]);
+    assert_eq!(search.finish().reason(), Reason::Epoch(5));
+}
+
+#[test]
+fn run_finishes_search() {
+    let success = make_search(&SearchSettings::default()).run().unwrap();
+
+    assert_between!(success.best_cost(), 0.0, 0.1);
+}
+
+#[test]
+fn finish_before_termination_is_stop_requested() {
+    let mut search = make_search(
+        &SearchSettingsBuilder::default()
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    );
+    search.step().unwrap();
+    let best_cost = search.best_cost();
+
+    let success = search.finish();
+
+    assert_eq!(success.reason(), Reason::StopRequested);
+    assert_eq!(success.epoch(), 2);
+    assert_eq!(success.best_cost(), best_cost);
+}
+
+#[test]
+fn inject_replaces_worst_genome() {
+    let mut search = make_search(
+        &SearchSettingsBuilder::default()
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    );
+
+    search.inject(vec![PeakGenome(100.0)]).unwrap();
+
+    assert_eq!(search.population().contains(&PeakGenome(100.0)), true);
+    assert_eq!(search.best_cost(), 0.0);
+    assert_eq!(search.best_genome(), &PeakGenome(100.0));
+}
+
+#[test]
+fn set_settings_applies_from_next_epoch() {
+    let mut search = make_search(
+        &SearchSettingsBuilder::default()
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    );
+    search.step().unwrap();
+
+    search.set_settings(
+        &SearchSettingsBuilder::default()
+            .epoch_limit(3)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(search.settings().epoch_limit(), 3);
+    assert_eq!(search.step().unwrap(), Some(Reason::Epoch(3)));
+}
+
+#[test]
+fn set_settings_keeps_cma_population_size() {
+    let settings = SearchSettingsBuilder::default()
+        .population_size(8)
+        .cost_target(-1.0)
+        .build()
+        .unwrap();
+    let mut search = Search::new(
+        Box::new(tests::RealGenetic::sphere(3)),
+        Box::new(CmaEs::new(CmaSettings::default())),
+        None,
+        &settings,
+    )
+    .unwrap();
+
+    search.set_settings(
+        &SearchSettingsBuilder::from(&settings)
+            .population_size(32)
+            .build()
+            .unwrap(),
+    );
+    search.step().unwrap();
+    search.step().unwrap();
+
+    assert_eq!(search.population().len(), 8);
+    assert_eq!(search.costs().len(), 8);
+}
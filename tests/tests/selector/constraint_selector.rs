@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use watchmaker::*;
+
+fn make_selector(constraint: Constraint) -> Result<ConstraintSelector<u8>, Failure> {
+    ConstraintSelector::new(Box::new(TournamentSelector::new(4).unwrap()), constraint)
+}
+
+#[test]
+fn fails_when_static_penalty_is_negative() {
+    let result = make_selector(Constraint::StaticPenalty { coefficient: -1.0 });
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::constraint_setting());
+}
+
+#[test]
+fn fails_when_adaptive_window_is_zero() {
+    let result = make_selector(Constraint::AdaptivePenalty {
+        initial: 1.0,
+        increase: 2.0,
+        decrease: 2.0,
+        window: 0,
+    });
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::constraint_setting());
+}
+
+#[test]
+fn fails_when_penalty_is_infinite() {
+    let rejected = |constraint| make_selector(constraint).err();
+
+    assert_eq!(
+        rejected(Constraint::StaticPenalty {
+            coefficient: f64::INFINITY
+        }),
+        Some(Failure::constraint_setting())
+    );
+    assert_eq!(
+        rejected(Constraint::DynamicPenalty {
+            coefficient: 1.0,
+            exponent: f64::INFINITY
+        }),
+        Some(Failure::constraint_setting())
+    );
+    assert_eq!(
+        rejected(Constraint::AdaptivePenalty {
+            initial: f64::INFINITY,
+            increase: 2.0,
+            decrease: 2.0,
+            window: 1,
+        }),
+        Some(Failure::constraint_setting())
+    );
+}
+
+#[test]
+fn overflowing_penalty_keeps_feasible_costs() {
+    const ITERATIONS: usize = 1_024;
+
+    let mut s = make_selector(Constraint::DynamicPenalty {
+        coefficient: 1e200,
+        exponent: 2.0,
+    })
+    .unwrap();
+    let population = make_vec(4, || 0);
+    let costs = vec![0.0, 1.0, 2.0, 3.0];
+    let violations = vec![1.0, 0.0, 0.0, 0.0];
+
+    let mut results: HashMap<usize, usize> = HashMap::new();
+    for _ in 0..ITERATIONS {
+        let mut partner_indices = make_vec(4, || 0);
+        s.select_constrained(&population, &costs, &violations, &mut partner_indices);
+        for i in &partner_indices {
+            *results.entry(*i).or_insert(0) += 1;
+        }
+    }
+
+    assert_eq!(s.penalty(), f64::INFINITY);
+    assert_eq!(
+        results.get(&1).unwrap_or(&0) > results.get(&0).unwrap_or(&0),
+        true
+    );
+}
+
+#[test]
+fn feasibility_rules_prefer_feasible_genomes_over_lower_cost() {
+    const ITERATIONS: usize = 1_024;
+
+    let mut s = make_selector(Constraint::Feasibility).unwrap();
+    let population = make_vec(4, || 0);
+    let costs = vec![0.0, 1.0, 2.0, 3.0];
+    let violations = vec![1.0, 0.0, 0.0, 0.0];
+
+    let mut results: HashMap<usize, usize> = HashMap::new();
+    for _ in 0..ITERATIONS {
+        let mut partner_indices = make_vec(4, || 0);
+        s.select_constrained(&population, &costs, &violations, &mut partner_indices);
+        for i in &partner_indices {
+            *results.entry(*i).or_insert(0) += 1;
+        }
+    }
+
+    let at_0 = *results.get(&0).unwrap_or(&0);
+    let at_1 = *results.get(&1).unwrap_or(&0);
+    let at_3 = *results.get(&3).unwrap_or(&0);
+
+    assert!(at_1 > at_3);
+    assert!(at_3 > at_0);
+}
+
+#[test]
+fn dynamic_penalty_grows_with_epochs() {
+    let mut s = make_selector(Constraint::DynamicPenalty {
+        coefficient: 0.5,
+        exponent: 2.0,
+    })
+    .unwrap();
+    let population = make_vec(4, || 0);
+    let costs = make_vec(4, || 0.0);
+    let violations = make_vec(4, || 1.0);
+    let mut partner_indices = make_vec(4, || 0);
+
+    s.select_constrained(&population, &costs, &violations, &mut partner_indices);
+    assert_eq!(s.penalty(), 0.25);
+    s.select_constrained(&population, &costs, &violations, &mut partner_indices);
+    assert_eq!(s.penalty(), 1.0);
+}
+
+#[test]
+fn adaptive_penalty_increases_while_best_is_infeasible_and_decreases_while_feasible() {
+    let mut s = make_selector(Constraint::AdaptivePenalty {
+        initial: 1.0,
+        increase: 4.0,
+        decrease: 2.0,
+        window: 2,
+    })
+    .unwrap();
+    let population = make_vec(4, || 0);
+    let costs = make_vec(4, || 0.0);
+    let mut partner_indices = make_vec(4, || 0);
+
+    for _ in 0..2 {
+        s.select_constrained(&population, &costs, &[1.0; 4], &mut partner_indices);
+    }
+    assert_eq!(s.penalty(), 4.0);
+
+    for _ in 0..2 {
+        s.select_constrained(&population, &costs, &[0.0; 4], &mut partner_indices);
+    }
+    assert_eq!(s.penalty(), 2.0);
+}
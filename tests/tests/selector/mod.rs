@@ -1,2 +1,3 @@
+mod constraint_selector;
 mod torus_selector;
 mod tournament_selector;
@@ -122,3 +122,45 @@ fn end_of_torus_candidates_are_selected_within_radius() {
         }
     }
 }
+
+#[test]
+fn when_costs_are_infinite_candidates_are_not_biased_to_first() {
+    const ITERATIONS: usize = 256;
+
+    let mut s: TorusSelector<u8> = TorusSelector::new(4, 8).unwrap();
+    let population = make_vec(16, || 0);
+    let costs = make_vec(16, || f64::INFINITY);
+
+    let mut selected_first = 0;
+    for _ in 0..ITERATIONS {
+        let mut partner_indices = make_vec(16, || 0);
+        s.select(&population, &costs, &mut partner_indices);
+        selected_first += partner_indices.iter().filter(|i| **i == 0).count();
+    }
+
+    assert_between!(selected_first, ITERATIONS / 2, ITERATIONS * 2);
+}
+
+#[test]
+fn nan_costs_are_never_preferred() {
+    const ITERATIONS: usize = 256;
+
+    let mut s: TorusSelector<u8> = TorusSelector::new(4, 8).unwrap();
+    let population = make_vec(16, || 0);
+    let costs = (0..16)
+        .map(|x| if x % 2 == 0 { f64::NAN } else { x as f64 })
+        .collect::<Vec<f64>>();
+
+    let mut selected_nan = 0;
+    for _ in 0..ITERATIONS {
+        let mut partner_indices = make_vec(16, || 0);
+        s.select(&population, &costs, &mut partner_indices);
+        selected_nan += partner_indices
+            .iter()
+            .filter(|i| costs[**i].is_nan())
+            .count();
+    }
+
+    // A NaN cost is only selected when every candidate in the tournament has a NaN cost.
+    assert_between!(selected_nan, 0, ITERATIONS * 16 / 8);
+}
@@ -65,3 +65,45 @@ fn when_costs_are_not_uniform_candidates_are_selected_according_to_distribution(
     assert!(at_0 > at_7);
     assert!(at_7 > at_15);
 }
+
+#[test]
+fn when_costs_are_infinite_candidates_are_not_biased_to_first() {
+    const ITERATIONS: usize = 256;
+
+    let mut s: TournamentSelector<u8> = TournamentSelector::new(4).unwrap();
+    let population = make_vec(16, || 0);
+    let costs = make_vec(16, || f64::INFINITY);
+
+    let mut selected_first = 0;
+    for _ in 0..ITERATIONS {
+        let mut partner_indices = make_vec(16, || 0);
+        s.select(&population, &costs, &mut partner_indices);
+        selected_first += partner_indices.iter().filter(|i| **i == 0).count();
+    }
+
+    assert_between!(selected_first, ITERATIONS / 2, ITERATIONS * 2);
+}
+
+#[test]
+fn nan_costs_are_never_preferred() {
+    const ITERATIONS: usize = 256;
+
+    let mut s: TournamentSelector<u8> = TournamentSelector::new(4).unwrap();
+    let population = make_vec(16, || 0);
+    let costs = (0..16)
+        .map(|x| if x % 2 == 0 { f64::NAN } else { x as f64 })
+        .collect::<Vec<f64>>();
+
+    let mut selected_nan = 0;
+    for _ in 0..ITERATIONS {
+        let mut partner_indices = make_vec(16, || 0);
+        s.select(&population, &costs, &mut partner_indices);
+        selected_nan += partner_indices
+            .iter()
+            .filter(|i| costs[**i].is_nan())
+            .count();
+    }
+
+    // A NaN cost is only selected when every candidate in the tournament has a NaN cost.
+    assert_between!(selected_nan, 0, ITERATIONS * 16 / 8);
+}
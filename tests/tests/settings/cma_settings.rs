@@ -0,0 +1,17 @@
+use watchmaker::{CmaSettingsBuilder, Failure};
+
+#[test]
+fn fails_when_initial_step_size_is_not_positive() {
+    let result = CmaSettingsBuilder::default().initial_step_size(0.0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::cma_setting());
+}
+
+#[test]
+fn fails_when_parent_fraction_is_out_of_range() {
+    let result = CmaSettingsBuilder::default().parent_fraction(1.5).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::cma_setting());
+}
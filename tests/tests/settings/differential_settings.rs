@@ -0,0 +1,31 @@
+use watchmaker::{DifferentialSettingsBuilder, Failure};
+
+#[test]
+fn fails_when_scale_factor_is_not_positive() {
+    let result = DifferentialSettingsBuilder::default()
+        .scale_factor(0.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::differential_setting());
+}
+
+#[test]
+fn fails_when_scale_factor_is_too_large() {
+    let result = DifferentialSettingsBuilder::default()
+        .scale_factor(2.5)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::differential_setting());
+}
+
+#[test]
+fn fails_when_crossover_rate_is_out_of_range() {
+    let result = DifferentialSettingsBuilder::default()
+        .crossover_rate(-0.1)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::differential_setting());
+}
@@ -0,0 +1,39 @@
+use watchmaker::{EvolutionSettingsBuilder, Failure};
+
+#[test]
+fn fails_when_offspring_is_zero() {
+    let result = EvolutionSettingsBuilder::default().offspring(0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::evolution_setting());
+}
+
+#[test]
+fn fails_when_initial_step_size_is_not_positive() {
+    let result = EvolutionSettingsBuilder::default()
+        .initial_step_size(0.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::evolution_setting());
+}
+
+#[test]
+fn fails_when_learning_rate_is_negative() {
+    let result = EvolutionSettingsBuilder::default()
+        .learning_rate(-1.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::evolution_setting());
+}
+
+#[test]
+fn fails_when_recombination_probability_is_out_of_range() {
+    let result = EvolutionSettingsBuilder::default()
+        .recombination_probability(2.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::evolution_setting());
+}
@@ -0,0 +1,36 @@
+use watchmaker::{Failure, HallOfFameSettings, HallOfFameSettingsBuilder, SearchSettingsBuilder};
+
+#[test]
+fn fails_when_size_is_zero() {
+    let result = HallOfFameSettingsBuilder::default().size(0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::hall_of_fame_setting());
+}
+
+#[test]
+fn fails_when_min_distance_is_negative() {
+    let result = HallOfFameSettingsBuilder::default()
+        .min_distance(-1.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::hall_of_fame_setting());
+}
+
+#[test]
+fn search_settings_have_no_hall_of_fame_by_default() {
+    let settings = SearchSettingsBuilder::default().build().unwrap();
+
+    assert_eq!(settings.hall_of_fame(), None);
+}
+
+#[test]
+fn search_settings_keep_hall_of_fame_settings() {
+    let settings = SearchSettingsBuilder::default()
+        .hall_of_fame(Some(HallOfFameSettings::default()))
+        .build()
+        .unwrap();
+
+    assert_eq!(settings.hall_of_fame(), Some(HallOfFameSettings::default()));
+}
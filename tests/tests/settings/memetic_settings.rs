@@ -0,0 +1,38 @@
+use watchmaker::{Failure, Inheritance, MemeticSettingsBuilder, SearchSettingsBuilder};
+
+#[test]
+fn fails_when_interval_is_zero() {
+    let result = MemeticSettingsBuilder::default().interval(0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::memetic_setting());
+}
+
+#[test]
+fn fails_when_probability_is_out_of_range() {
+    let result = MemeticSettingsBuilder::default().probability(1.5).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::memetic_setting());
+}
+
+#[test]
+fn search_settings_keep_memetic_settings() {
+    let memetic = MemeticSettingsBuilder::default()
+        .inheritance(Inheritance::Baldwinian)
+        .interval(4)
+        .probability(0.5)
+        .build()
+        .unwrap();
+
+    let settings = SearchSettingsBuilder::default()
+        .memetic(Some(memetic))
+        .build()
+        .unwrap();
+
+    assert_eq!(settings.memetic(), Some(memetic));
+    assert_eq!(
+        SearchSettingsBuilder::default().build().unwrap().memetic(),
+        None
+    );
+}
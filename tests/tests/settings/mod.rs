@@ -1 +1,8 @@
+mod cma_settings;
+mod differential_settings;
+mod evolution_settings;
+mod hall_of_fame_settings;
+mod memetic_settings;
+mod restart_settings;
 mod settings;
+mod tree_settings;
@@ -0,0 +1,56 @@
+use watchmaker::{Failure, RestartSettings, RestartSettingsBuilder, SearchSettingsBuilder};
+
+#[test]
+fn fails_when_stagnation_is_zero() {
+    let result = RestartSettingsBuilder::default().stagnation(0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::restart_setting());
+}
+
+#[test]
+fn fails_when_min_diversity_is_negative() {
+    let result = RestartSettingsBuilder::default()
+        .min_diversity(-1.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::restart_setting());
+}
+
+#[test]
+fn fails_when_population_shrinks() {
+    let result = RestartSettingsBuilder::default()
+        .population_growth(0.5)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::restart_setting());
+}
+
+#[test]
+fn fails_when_max_population_size_is_zero() {
+    let result = RestartSettingsBuilder::default()
+        .max_population_size(0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::restart_setting());
+}
+
+#[test]
+fn search_settings_have_no_restarts_by_default() {
+    let settings = SearchSettingsBuilder::default().build().unwrap();
+
+    assert_eq!(settings.restart(), None);
+}
+
+#[test]
+fn search_settings_keep_restart_settings() {
+    let settings = SearchSettingsBuilder::default()
+        .restart(Some(RestartSettings::default()))
+        .build()
+        .unwrap();
+
+    assert_eq!(settings.restart(), Some(RestartSettings::default()));
+}
@@ -36,3 +36,11 @@ fn fails_when_time_limit_is_zero() {
     assert_eq!(result.is_err(), true);
     assert_eq!(result.err().unwrap(), Failure::time_limit());
 }
+
+#[test]
+fn fails_when_evaluation_limit_is_zero() {
+    let result = SearchSettingsBuilder::default().evaluation_limit(0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::evaluation_limit());
+}
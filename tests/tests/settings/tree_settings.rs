@@ -0,0 +1,81 @@
+use watchmaker::{Failure, TreeSettingsBuilder};
+
+#[test]
+fn fails_when_init_depths_are_reversed() {
+    let result = TreeSettingsBuilder::default()
+        .min_init_depth(4)
+        .max_init_depth(3)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::tree_depth());
+}
+
+#[test]
+fn fails_when_init_depth_exceeds_max_depth() {
+    let result = TreeSettingsBuilder::default()
+        .max_init_depth(8)
+        .max_depth(7)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::tree_depth());
+}
+
+#[test]
+fn fails_when_max_size_is_zero() {
+    let result = TreeSettingsBuilder::default().max_size(0).build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::tree_size());
+}
+
+#[test]
+fn fails_when_parsimony_coefficient_is_negative() {
+    let result = TreeSettingsBuilder::default()
+        .parsimony_coefficient(-0.1)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::parsimony_coefficient());
+}
+
+#[test]
+fn fails_when_all_mutation_weights_are_zero() {
+    let result = TreeSettingsBuilder::default()
+        .point_mutation_weight(0.0)
+        .subtree_mutation_weight(0.0)
+        .hoist_mutation_weight(0.0)
+        .build();
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::mutation_weights());
+}
+
+#[test]
+fn fails_when_parsimony_coefficient_is_infinite() {
+    let result = TreeSettingsBuilder::default()
+        .parsimony_coefficient(f64::INFINITY)
+        .build();
+
+    assert_eq!(result.err(), Some(Failure::parsimony_coefficient()));
+}
+
+#[test]
+fn fails_when_a_mutation_weight_is_infinite() {
+    let result = TreeSettingsBuilder::default()
+        .point_mutation_weight(f64::INFINITY)
+        .build();
+
+    assert_eq!(result.err(), Some(Failure::mutation_weights()));
+}
+
+#[test]
+fn fails_when_mutation_weights_overflow() {
+    let result = TreeSettingsBuilder::default()
+        .point_mutation_weight(f64::MAX)
+        .subtree_mutation_weight(f64::MAX)
+        .build();
+
+    assert_eq!(result.err(), Some(Failure::mutation_weights()));
+}
@@ -0,0 +1,165 @@
+use std::sync::{Arc, Mutex};
+use tests::{assert_between, PeakGenetic, PeakGenome, RealGenetic};
+use watchmaker::*;
+
+/// A (1 + 1) hill climber, to check that a strategy defined outside the crate can be searched.
+struct HillClimber {
+    population: Vec<PeakGenome>,
+    costs: Vec<f64>,
+    violations: Vec<f64>,
+}
+
+impl HillClimber {
+    fn new() -> Self {
+        Self {
+            population: Vec::new(),
+            costs: Vec::new(),
+            violations: Vec::new(),
+        }
+    }
+}
+
+impl Strategy<PeakGenome> for HillClimber {
+    fn initialize(
+        &mut self,
+        genetic: &(dyn Genetic<PeakGenome> + Send + Sync),
+        _settings: &SearchSettings,
+    ) -> Result<(), Failure> {
+        let genome = genetic.initialize();
+        self.costs = vec![genetic.evaluate(&genome)];
+        self.violations = vec![0.0];
+        self.population = vec![genome];
+        Ok(())
+    }
+
+    fn step(
+        &mut self,
+        genetic: &(dyn Genetic<PeakGenome> + Send + Sync),
+        _settings: &SearchSettings,
+        _snapshot: &ProgressSnapshot<PeakGenome>,
+        _observer: &mut dyn Observer<PeakGenome>,
+    ) {
+        let candidate = genetic.mutate(&self.population[0]);
+        let cost = genetic.evaluate(&candidate);
+        if cost <= self.costs[0] {
+            self.population[0] = candidate;
+            self.costs[0] = cost;
+        }
+    }
+
+    fn population(&self) -> &[PeakGenome] {
+        &self.population
+    }
+
+    fn costs(&self) -> &[f64] {
+        &self.costs
+    }
+
+    fn violations(&self) -> &[f64] {
+        &self.violations
+    }
+
+    fn replace(&mut self, index: usize, genome: PeakGenome, cost: f64, violation: f64) {
+        self.population[index] = genome;
+        self.costs[index] = cost;
+        self.violations[index] = violation;
+    }
+}
+
+#[test]
+fn search_with_custom_strategy_finds_result() {
+    let success = search_with(
+        Box::new(PeakGenetic::default()),
+        Box::new(HillClimber::new()),
+        None,
+        &SearchSettings::default(),
+    )
+    .unwrap();
+
+    assert_between!(success.best_cost(), 0.0, 0.1);
+    assert_between!(success.best_genome().0, 99.0, 101.0);
+}
+
+#[test]
+fn search_with_generational_strategy_finds_result() {
+    let success = search_with(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        None,
+        &SearchSettings::default(),
+    )
+    .unwrap();
+
+    assert_between!(success.best_cost(), 0.0, 0.1);
+}
+
+#[test]
+fn search_with_differential_evolution_finds_result() {
+    let success = search_with(
+        Box::new(RealGenetic::sphere(3)),
+        Box::new(DifferentialEvolution::new(DifferentialSettings::default())),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(30)
+            .epoch_limit(2_000)
+            .cost_target(1e-6)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        success.reason(),
+        Reason::CostTargetReached(success.best_cost())
+    );
+}
+
+#[test]
+fn strategy_failure_is_returned_from_search() {
+    let result = search_with(
+        Box::new(RealGenetic::sphere(3)),
+        Box::new(CmaEs::new(CmaSettings::default())),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(1)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.err(), Some(Failure::cma_setting()));
+}
+
+#[test]
+fn progress_reports_stagnation() {
+    let snapshots = Arc::new(Mutex::new(Vec::new()));
+    let reported = snapshots.clone();
+    search_with(
+        Box::new(PeakGenetic::default()),
+        Box::new(HillClimber::new()),
+        Some(Box::new(move |snapshot: ProgressSnapshot<PeakGenome>| {
+            reported
+                .lock()
+                .unwrap()
+                .push((snapshot.best_cost(), snapshot.stagnation()));
+        })),
+        &SearchSettingsBuilder::default()
+            .epoch_limit(50)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    )
+    .unwrap();
+
+    let snapshots = snapshots.lock().unwrap();
+    assert_eq!(snapshots.len(), 50);
+    assert_eq!(snapshots[0].1, 0);
+    for pair in snapshots.windows(2) {
+        let (previous_cost, previous_stagnation) = pair[0];
+        let (cost, stagnation) = pair[1];
+        if cost < previous_cost {
+            assert_eq!(stagnation, 0);
+        } else {
+            assert_eq!(stagnation, previous_stagnation + 1);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::time::{Duration, Instant};
+use tests::{PeakGenetic, PeakGenome};
+use watchmaker::*;
+
+fn make_search(population_size: usize) -> Search<PeakGenome> {
+    Search::new(
+        Box::new(PeakGenetic::default()),
+        Box::new(Generational::new(Box::new(TournamentSelector::default()))),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(population_size)
+            .cost_target(-1.0)
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+}
+
+fn snapshot(epoch: usize, best_cost: f64, genome: &PeakGenome) -> ProgressSnapshot<'_, PeakGenome> {
+    ProgressSnapshot::new(epoch, Duration::ZERO, best_cost, genome)
+}
+
+#[test]
+fn epoch_limit_terminates_search() {
+    let success = make_search(8)
+        .with_terminator(Box::new(EpochLimit::new(7).unwrap()))
+        .run()
+        .unwrap();
+
+    assert_eq!(success.reason(), Reason::Epoch(7));
+    assert_eq!(success.epoch(), 7);
+}
+
+#[test]
+fn epoch_limit_must_be_positive() {
+    assert_eq!(EpochLimit::new(0).err(), Some(Failure::epoch_limit()));
+}
+
+#[test]
+fn evaluation_budget_terminates_search() {
+    let success = make_search(10)
+        .with_terminator(Box::new(EvaluationBudget::new(35)))
+        .run()
+        .unwrap();
+
+    assert_eq!(success.reason(), Reason::EvaluationLimit(40));
+    assert_eq!(success.epoch(), 4);
+}
+
+#[test]
+fn snapshot_counts_evaluations() {
+    let mut search = make_search(8);
+    assert_eq!(search.snapshot().evaluations(), 8);
+
+    search.step().unwrap();
+    search.step().unwrap();
+
+    assert_eq!(search.snapshot().evaluations(), 24);
+}
+
+#[test]
+fn deadline_in_the_past_terminates_first_epoch() {
+    let search = make_search(8).with_terminator(Box::new(Deadline::new(Instant::now())));
+
+    assert_eq!(
+        matches!(search.reason(), Some(Reason::DeadlineReached(_))),
+        true
+    );
+}
+
+#[test]
+fn diversity_below_threshold_terminates_search() {
+    let search = make_search(8).with_terminator(Box::new(DiversityBelow::new(f64::MAX)));
+
+    assert_eq!(
+        matches!(search.reason(), Some(Reason::DiversityCollapsed(_))),
+        true
+    );
+}
+
+#[test]
+fn diversity_below_needs_diversity() {
+    let genome = PeakGenome(0.0);
+
+    let reason = DiversityBelow::new(f64::MAX).terminate(&snapshot(1, 1.0, &genome));
+
+    assert_eq!(reason, None);
+}
+
+#[test]
+fn improvement_below_waits_for_window() {
+    let genome = PeakGenome(0.0);
+    let mut terminator = ImprovementBelow::new(0.5, 2).unwrap();
+
+    let reasons: Vec<Option<Reason>> = [10.0, 9.0, 8.0, 7.8, 7.7]
+        .iter()
+        .enumerate()
+        .map(|(i, cost)| terminator.terminate(&snapshot(i + 1, *cost, &genome)))
+        .collect();
+
+    assert_eq!(reasons[..3], [None, None, None]);
+    assert_eq!(
+        matches!(reasons[3], Some(Reason::InsufficientImprovement(_))),
+        false
+    );
+    assert_eq!(
+        matches!(reasons[4], Some(Reason::InsufficientImprovement(_))),
+        true
+    );
+}
+
+#[test]
+fn improvement_below_rejects_invalid_settings() {
+    assert_eq!(
+        ImprovementBelow::new(-1.0, 5).err(),
+        Some(Failure::terminator_setting())
+    );
+    assert_eq!(
+        ImprovementBelow::new(1.0, 0).err(),
+        Some(Failure::terminator_setting())
+    );
+}
+
+#[test]
+fn any_of_returns_first_reason() {
+    let genome = PeakGenome(0.0);
+    let mut terminator: AnyOf<PeakGenome> = AnyOf::new(vec![
+        Box::new(EvaluationBudget::new(usize::MAX)),
+        Box::new(EpochLimit::new(3).unwrap()),
+        Box::new(EpochLimit::new(2).unwrap()),
+    ]);
+
+    assert_eq!(terminator.terminate(&snapshot(1, 1.0, &genome)), None);
+    assert_eq!(
+        terminator.terminate(&snapshot(3, 1.0, &genome)),
+        Some(Reason::Epoch(3))
+    );
+}
+
+#[test]
+fn all_of_needs_every_reason() {
+    let genome = PeakGenome(0.0);
+    let mut terminator: AllOf<PeakGenome> = AllOf::new(vec![
+        Box::new(EpochLimit::new(2).unwrap()),
+        Box::new(|snapshot: &ProgressSnapshot<PeakGenome>| {
+            (snapshot.best_cost() < 1.0).then_some(Reason::Custom("good enough"))
+        }),
+    ]);
+
+    assert_eq!(terminator.terminate(&snapshot(3, 2.0, &genome)), None);
+    assert_eq!(
+        terminator.terminate(&snapshot(4, 0.5, &genome)),
+        Some(Reason::Epoch(4))
+    );
+}
+
+#[test]
+fn empty_all_of_never_terminates() {
+    let genome = PeakGenome(0.0);
+    let mut terminator: AllOf<PeakGenome> = AllOf::new(Vec::new());
+
+    assert_eq!(terminator.terminate(&snapshot(1, 0.0, &genome)), None);
+}
+
+#[test]
+fn custom_terminator_reports_message() {
+    let success = make_search(8)
+        .with_terminator(Box::new(|snapshot: &ProgressSnapshot<PeakGenome>| {
+            (snapshot.epoch() == 5).then_some(Reason::Custom("five epochs"))
+        }))
+        .run()
+        .unwrap();
+
+    assert_eq!(success.reason(), Reason::Custom("five epochs"));
+}
+
+fn settings(epoch_limit: usize) -> SearchSettings {
+    SearchSettingsBuilder::default()
+        .population_size(8)
+        .epoch_limit(epoch_limit)
+        .cost_target(-1.0)
+        .build()
+        .unwrap()
+}
+
+fn strategy() -> Box<dyn Strategy<PeakGenome> + Send + Sync> {
+    Box::new(Generational::new(Box::new(TournamentSelector::default())))
+}
+
+#[test]
+fn limits_follow_settings_in_order() {
+    let genome = PeakGenome(0.0);
+    let settings = SearchSettingsBuilder::default()
+        .epoch_limit(10)
+        .cost_target(0.5)
+        .evaluation_limit(100)
+        .build()
+        .unwrap();
+    let mut terminator: AnyOf<PeakGenome> = AnyOf::limits(&settings);
+
+    assert_eq!(terminator.terminate(&snapshot(2, 1.0, &genome)), None);
+    assert_eq!(
+        terminator.terminate(&snapshot(2, 0.25, &genome)),
+        Some(Reason::CostTargetReached(0.25))
+    );
+    assert_eq!(
+        terminator.terminate(&snapshot(10, 0.25, &genome)),
+        Some(Reason::Epoch(10))
+    );
+}
+
+#[test]
+fn search_until_replaces_limits() {
+    let success = search_until(
+        Box::new(PeakGenetic::default()),
+        strategy(),
+        Observers::new(),
+        Box::new(|snapshot: &ProgressSnapshot<PeakGenome>| {
+            (snapshot.epoch() == 5).then_some(Reason::Custom("five epochs"))
+        }),
+        &settings(3),
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::Custom("five epochs"));
+    assert_eq!(success.epoch(), 5);
+}
+
+#[test]
+fn search_until_keeps_limits_combined_with_any_of() {
+    let settings = settings(3);
+    let success = search_until(
+        Box::new(PeakGenetic::default()),
+        strategy(),
+        Observers::new(),
+        Box::new(AnyOf::new(vec![
+            Box::new(AnyOf::limits(&settings)),
+            Box::new(EpochLimit::new(5).unwrap()),
+        ])),
+        &settings,
+    )
+    .unwrap();
+
+    assert_eq!(success.reason(), Reason::Epoch(3));
+}
+
+#[test]
+fn spawn_until_replaces_limits() {
+    let handle = spawn_until(
+        Box::new(PeakGenetic::default()),
+        strategy(),
+        None,
+        Box::new(EpochLimit::new(4).unwrap()),
+        &settings(100),
+    );
+
+    let success = handle.join().unwrap();
+
+    assert_eq!(success.reason(), Reason::Epoch(4));
+    assert_eq!(success.epoch(), 4);
+}
@@ -0,0 +1,3 @@
+mod primitive;
+mod tree;
+mod tree_genetic;
@@ -0,0 +1,100 @@
+use tests::*;
+use watchmaker::*;
+
+#[test]
+fn fails_when_a_kind_has_no_terminal() {
+    let result = PrimitiveSet::typed(
+        0,
+        vec![Function::typed("len", 0, &[1], |a: &[f64]| a[0])],
+        vec![Terminal::variable("x", 0)],
+    );
+
+    assert_eq!(result.is_err(), true);
+    assert_eq!(result.err().unwrap(), Failure::primitive_set());
+}
+
+#[test]
+fn interpret_applies_functions_to_arguments_in_order() {
+    let primitives = make_arithmetic_primitives();
+    // (- x 1)
+    let tree = Tree::new(vec![
+        Node::Function {
+            index: 1,
+            arity: 2,
+            kind: 0,
+        },
+        Node::Terminal {
+            index: 0,
+            kind: 0,
+            value: None,
+        },
+        Node::Terminal {
+            index: 1,
+            kind: 0,
+            value: None,
+        },
+    ]);
+
+    assert_eq!(primitives.interpret(&tree, &[5.0]), 4.0);
+    assert_eq!(primitives.format(&tree), "(- x 1)");
+}
+
+#[test]
+fn full_trees_have_exact_depth() {
+    let primitives = make_arithmetic_primitives();
+
+    for depth in 0..6 {
+        let tree = primitives.full(depth);
+        assert_eq!(tree.depth(), depth);
+        assert_eq!(tree.size(), 2_usize.pow(depth as u32 + 1) - 1);
+    }
+}
+
+#[test]
+fn grown_trees_do_not_exceed_depth() {
+    let primitives = make_arithmetic_primitives();
+
+    for _ in 0..100 {
+        assert!(primitives.grow(4).depth() <= 4);
+    }
+}
+
+#[test]
+fn typed_trees_only_combine_matching_kinds() {
+    let primitives = PrimitiveSet::typed(
+        0,
+        vec![
+            Function::typed(
+                "if",
+                0,
+                &[1, 0, 0],
+                |a: &[f64]| {
+                    if a[0] != 0.0 {
+                        a[1]
+                    } else {
+                        a[2]
+                    }
+                },
+            ),
+            Function::typed("<", 1, &[0, 0], |a: &[f64]| (a[0] < a[1]) as u8 as f64),
+        ],
+        vec![
+            Terminal::variable("x", 0),
+            Terminal::constant("true", 1.0).of_kind(1),
+        ],
+    )
+    .unwrap();
+
+    for _ in 0..100 {
+        let tree = primitives.full(3);
+        for (i, node) in tree.nodes().iter().enumerate() {
+            if let Node::Function { index, .. } = node {
+                let mut child = i + 1;
+                for kind in primitives.functions()[*index].arguments() {
+                    assert_eq!(tree.nodes()[child].kind(), *kind);
+                    child = tree.subtree_end(child);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use watchmaker::*;
+
+fn add() -> Node<f64> {
+    Node::Function {
+        index: 0,
+        arity: 2,
+        kind: 0,
+    }
+}
+
+fn x() -> Node<f64> {
+    Node::Terminal {
+        index: 0,
+        kind: 0,
+        value: None,
+    }
+}
+
+fn one() -> Node<f64> {
+    Node::Terminal {
+        index: 1,
+        kind: 0,
+        value: None,
+    }
+}
+
+#[test]
+fn single_terminal_has_depth_zero_and_size_one() {
+    let tree = Tree::new(vec![x()]);
+
+    assert_eq!(tree.depth(), 0);
+    assert_eq!(tree.size(), 1);
+}
+
+#[test]
+fn depth_and_size_are_computed_from_prefix_order() {
+    // (+ (+ x 1) x)
+    let tree = Tree::new(vec![add(), add(), x(), one(), x()]);
+
+    assert_eq!(tree.depth(), 2);
+    assert_eq!(tree.size(), 5);
+    assert_eq!(tree.depths(), vec![0, 1, 2, 2, 1]);
+}
+
+#[test]
+fn subtree_spans_all_descendants() {
+    let tree = Tree::new(vec![add(), add(), x(), one(), x()]);
+
+    assert_eq!(tree.subtree_end(1), 4);
+    assert_eq!(tree.subtree(1), &[add(), x(), one()]);
+    assert_eq!(tree.subtree(4), &[x()]);
+}
+
+#[test]
+fn replace_substitutes_whole_subtree() {
+    let tree = Tree::new(vec![add(), add(), x(), one(), x()]);
+
+    let result = tree.replace(1, &[one()]);
+
+    assert_eq!(result, Tree::new(vec![add(), one(), x()]));
+}
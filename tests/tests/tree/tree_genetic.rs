@@ -0,0 +1,80 @@
+use tests::*;
+use watchmaker::*;
+
+#[test]
+fn initialize_respects_init_depths() {
+    let settings = TreeSettingsBuilder::default()
+        .min_init_depth(1)
+        .max_init_depth(3)
+        .build()
+        .unwrap();
+    let genetic = make_polynomial_genetic(settings);
+
+    for _ in 0..100 {
+        assert_between!(genetic.initialize().depth(), 0, 3);
+    }
+}
+
+#[test]
+fn offspring_respect_depth_and_size_limits() {
+    let settings = TreeSettingsBuilder::default()
+        .max_init_depth(4)
+        .max_depth(5)
+        .max_size(24)
+        .build()
+        .unwrap();
+    let genetic = make_polynomial_genetic(settings);
+
+    for _ in 0..200 {
+        let lhs = genetic.initialize();
+        let rhs = genetic.initialize();
+        let cross = genetic.crossover(&lhs, &rhs);
+        let mutant = genetic.mutate(&cross);
+        assert_eq!(genetic.within_limits(&cross) || cross == lhs, true);
+        assert_eq!(genetic.within_limits(&mutant) || mutant == cross, true);
+    }
+}
+
+#[test]
+fn hoist_mutation_never_grows_tree() {
+    let genetic = make_polynomial_genetic(TreeSettings::default());
+
+    for _ in 0..100 {
+        let tree = genetic.initialize();
+        assert!(genetic.hoist_mutation(&tree).size() <= tree.size());
+    }
+}
+
+#[test]
+fn parsimony_pressure_adds_cost_per_node() {
+    let plain = make_polynomial_genetic(TreeSettings::default());
+    let parsimonious = make_polynomial_genetic(
+        TreeSettingsBuilder::default()
+            .parsimony_coefficient(0.5)
+            .build()
+            .unwrap(),
+    );
+    let tree = plain.primitives().full(2);
+
+    let difference = parsimonious.evaluate(&tree) - plain.evaluate(&tree);
+
+    assert_between!(difference, 3.5 - 1e-9, 3.5 + 1e-9);
+}
+
+#[test]
+fn search_finds_polynomial() {
+    let result = search(
+        Box::new(make_polynomial_genetic(TreeSettings::default())),
+        Box::new(TournamentSelector::default()),
+        None,
+        &SearchSettingsBuilder::default()
+            .population_size(512)
+            .mutation_probability(0.2)
+            .cost_target(1e-6)
+            .build()
+            .unwrap(),
+    );
+
+    assert_eq!(result.is_ok(), true);
+    assert!(result.unwrap().best_cost() < 1.0);
+}